@@ -47,7 +47,7 @@ impl<'a> Iterator for Arcs<'a> {
                 Some(root.second_arc())
             }
             Some(offset) => {
-                let mut result = 0;
+                let mut result: Arc = 0;
                 let mut arc_bytes = 0;
 
                 // TODO(tarcieri): consolidate this with `ObjectIdentifier::from_bytes`?
@@ -55,11 +55,8 @@ impl<'a> Iterator for Arcs<'a> {
                     match self.oid.as_bytes().get(offset + arc_bytes).cloned() {
                         Some(byte) => {
                             arc_bytes += 1;
-                            assert!(
-                                arc_bytes < 4 || byte & 0b11110000 == 0,
-                                "OID arc overflowed"
-                            );
-                            result = result << 7 | (byte & 0b1111111) as Arc;
+                            result = result.checked_mul(0x80).expect("OID arc overflowed")
+                                | (byte & 0b1111111) as Arc;
 
                             if byte & 0b10000000 == 0 {
                                 self.cursor = Some(offset + arc_bytes);
@@ -59,7 +59,7 @@ impl Encoder {
                 let nbytes = base128_len(arc);
 
                 const_assert!(
-                    self.cursor + nbytes + 1 < ObjectIdentifier::MAX_LENGTH,
+                    self.cursor + nbytes < ObjectIdentifier::MAX_LENGTH,
                     "OID too long (exceeded max DER bytes)"
                 );
 
@@ -84,7 +84,7 @@ impl Encoder {
     const fn encode_base128_byte(mut self, mut n: u32, i: usize, continued: bool) -> Self {
         let mask = if continued { 0b10000000 } else { 0 };
 
-        if n > 0x80 {
+        if n >= 0x80 {
             self.bytes[self.cursor + i] = (n & 0b1111111) as u8 | mask;
             n >>= 7;
 
@@ -103,7 +103,7 @@ const fn base128_len(arc: Arc) -> usize {
         0..=0x7f => 0,
         0x80..=0x3fff => 1,
         0x4000..=0x1fffff => 2,
-        0x200000..=0x1fffffff => 3,
+        0x200000..=0xfffffff => 3,
         _ => 4,
     }
 }
@@ -115,7 +115,7 @@ pub(crate) fn write_base128(bytes: &mut [u8], mut n: Arc) -> Result<usize> {
     let mut i = nbytes;
     let mut mask = 0;
 
-    while n > 0x80 {
+    while n >= 0x80 {
         let byte = bytes.get_mut(i).ok_or(Error)?;
         *byte = (n & 0b1111111 | mask) as u8;
         n >>= 7;
@@ -123,7 +123,7 @@ pub(crate) fn write_base128(bytes: &mut [u8], mut n: Arc) -> Result<usize> {
         mask = 0b10000000;
     }
 
-    bytes[0] = (n | mask) as u8;
+    *bytes.get_mut(0).ok_or(Error)? = (n | mask) as u8;
 
     Ok(nbytes + 1)
 }
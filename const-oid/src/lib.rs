@@ -78,7 +78,13 @@ pub use crate::{
 };
 
 use crate::arcs::RootArcs;
-use core::{convert::TryFrom, fmt, str::FromStr};
+use core::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 /// Object identifier (OID).
 ///
@@ -93,9 +99,16 @@ use core::{convert::TryFrom, fmt, str::FromStr};
 /// - The OID MUST have at least 3 arcs
 /// - The first arc MUST be within the range 0-2
 /// - The second arc MUST be within the range 0-39
-/// - The BER/DER encoding of the OID MUST be shorter than
-///   [`ObjectIdentifier::max_len`]
-#[derive(Copy, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+/// - Each arc MUST fit in an [`Arc`], i.e. a `u32`
+/// - The BER/DER encoding of the OID MUST be at most
+///   [`ObjectIdentifier::MAX_LENGTH`] bytes long
+///
+/// OIDs exceeding these limits (e.g. with too many arcs, or an arc greater
+/// than `u32::MAX`) are rejected with an [`Error`] rather than truncated.
+///
+/// The [`Ord`] and [`Hash`] impls operate on the BER/DER serialization
+/// returned by [`ObjectIdentifier::as_bytes`].
+#[derive(Copy, Clone, Eq, PartialEq)]
 pub struct ObjectIdentifier {
     /// Array containing BER/DER-serialized bytes (no header)
     bytes: [u8; Self::MAX_LENGTH],
@@ -172,26 +185,24 @@ impl ObjectIdentifier {
             .and_then(RootArcs::try_from)?;
 
         // Validate lower arcs are well-formed
-        let mut arc_offset = 1;
-        let mut arc_bytes = 0;
+        let mut arc: Option<Arc> = None;
 
         // TODO(tarcieri): consolidate this with `Arcs::next`?
-        while arc_offset < len {
-            match ber_bytes.get(arc_offset + arc_bytes).cloned() {
-                Some(byte) => {
-                    arc_bytes += 1;
-
-                    if arc_bytes == 4 && byte & 0b11110000 != 0 {
-                        // Overflowed `Arc` (u32)
-                        return Err(Error);
-                    }
-
-                    if byte & 0b10000000 == 0 {
-                        arc_offset += arc_bytes;
-                        arc_bytes = 0;
-                    }
+        for (i, &byte) in ber_bytes.iter().enumerate().skip(1) {
+            arc = match arc {
+                // Arcs must be minimally encoded, i.e. without leading zeroes
+                None if byte == 0b10000000 => return Err(Error),
+                None => Some(Arc::from(byte & 0b1111111)),
+                // Overflowed `Arc` (u32)
+                Some(arc) => {
+                    Some(arc.checked_mul(0x80).ok_or(Error)? | Arc::from(byte & 0b1111111))
                 }
-                None => return Err(Error), // truncated OID
+            };
+
+            if byte & 0b10000000 == 0 {
+                arc = None;
+            } else if i == len - 1 {
+                return Err(Error); // truncated OID
             }
         }
 
@@ -226,6 +237,24 @@ impl ObjectIdentifier {
     }
 }
 
+impl Hash for ObjectIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl Ord for ObjectIdentifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl PartialOrd for ObjectIdentifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl AsRef<[u8]> for ObjectIdentifier {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -237,8 +266,8 @@ impl FromStr for ObjectIdentifier {
 
     fn from_str(string: &str) -> Result<Self> {
         let mut split = string.split('.');
-        let first_arc = split.next().and_then(|s| s.parse().ok()).ok_or(Error)?;
-        let second_arc = split.next().and_then(|s| s.parse().ok()).ok_or(Error)?;
+        let first_arc = parse_arc(split.next())?;
+        let second_arc = parse_arc(split.next())?;
 
         let mut bytes = [0u8; Self::MAX_LENGTH];
         bytes[0] = RootArcs::new(first_arc, second_arc)?.into();
@@ -246,7 +275,7 @@ impl FromStr for ObjectIdentifier {
         let mut offset = 1;
 
         for s in split {
-            let arc = s.parse().map_err(|_| Error)?;
+            let arc = parse_arc(Some(s))?;
             offset += encoder::write_base128(&mut bytes[offset..], arc)?;
         }
 
@@ -262,6 +291,19 @@ impl FromStr for ObjectIdentifier {
     }
 }
 
+/// Parse a single decimal arc of an OID string.
+///
+/// Unlike [`str::parse`], this rejects empty arcs (i.e. leading, trailing or
+/// consecutive dots), whitespace, and signs, so only digits are accepted.
+fn parse_arc(s: Option<&str>) -> Result<Arc> {
+    match s {
+        Some(s) if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => {
+            s.parse().map_err(|_| Error)
+        }
+        _ => Err(Error),
+    }
+}
+
 impl TryFrom<&[u8]> for ObjectIdentifier {
     type Error = Error;
 
@@ -46,11 +46,24 @@ impl Parser {
             }
             [byte @ b'0'..=b'9', remaining @ ..] => {
                 let digit = byte.saturating_sub(b'0');
-                self.current_arc = self.current_arc * 10 + digit as Arc;
+                let arc = match self.current_arc.checked_mul(10) {
+                    Some(arc) => arc.checked_add(digit as Arc),
+                    None => None,
+                };
+
+                const_assert!(arc.is_some(), "OID arc overflowed");
+                self.current_arc = match arc {
+                    Some(arc) => arc,
+                    None => 0,
+                };
                 self.parse_bytes(remaining)
             }
             [b'.', remaining @ ..] => {
                 const_assert!(!remaining.is_empty(), "invalid trailing '.' in OID");
+                const_assert!(
+                    !matches!(remaining, [b'.', ..]),
+                    "invalid repeated '.' in OID"
+                );
                 self.encoder = self.encoder.encode(self.current_arc);
                 self.current_arc = 0;
                 self.parse_bytes(remaining)
@@ -96,4 +109,16 @@ mod tests {
     fn reject_trailing_dot() {
         Parser::parse("1.23.");
     }
+
+    #[test]
+    #[should_panic]
+    fn reject_overflowing_arc() {
+        Parser::parse("1.23.4294967296");
+    }
+
+    #[test]
+    #[should_panic]
+    fn reject_repeated_dot() {
+        Parser::parse("1.23..456");
+    }
 }
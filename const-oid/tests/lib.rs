@@ -5,7 +5,10 @@
 
 use const_oid::ObjectIdentifier;
 use hex_literal::hex;
-use std::string::ToString;
+use std::{
+    collections::{BTreeMap, HashMap},
+    string::ToString,
+};
 
 /// Example OID value with a root arc of `1`
 const EXAMPLE_OID_1: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
@@ -76,6 +79,36 @@ fn from_str() {
     assert!("1.40.840.10045.2.1".parse::<ObjectIdentifier>().is_err());
 }
 
+#[test]
+fn from_str_malformed() {
+    // Valid baseline
+    assert!("1.2.840.113549".parse::<ObjectIdentifier>().is_ok());
+
+    for malformed in &[
+        " 1.2.840.113549",
+        "1.2.840.113549 ",
+        "1.2. 840.113549",
+        ".1.2.840.113549",
+        "1.2.840.113549.",
+        "1.2..840.113549",
+        "1..2.840.113549",
+        "1.2.840.+113549",
+        "+1.2.840.113549",
+        "1.2.840.-113549",
+        "1.2.840.0x10",
+        "1.2.840.1a",
+        "1,2,840,113549",
+        "",
+        ".",
+    ] {
+        assert!(
+            malformed.parse::<ObjectIdentifier>().is_err(),
+            "accepted malformed OID {:?}",
+            malformed
+        );
+    }
+}
+
 #[test]
 fn try_from_u32_slice() {
     let oid1 = ObjectIdentifier::from_arcs(&[1, 2, 840, 10045, 2, 1]).unwrap();
@@ -98,12 +131,100 @@ fn try_from_u32_slice() {
     assert!(ObjectIdentifier::from_arcs(&[1, 40, 840, 10045, 3, 1, 7]).is_err());
 }
 
+#[test]
+fn arc_limits() {
+    // Largest arc which fits in an `Arc` (u32)
+    let oid = ObjectIdentifier::from_arcs(&[1, 2, u32::MAX, 128]).unwrap();
+    assert_eq!(oid.as_bytes(), &hex!("2A8FFFFFFF7F8100"));
+    assert_eq!(oid.arcs().collect::<Vec<_>>(), [1, 2, u32::MAX, 128]);
+    assert_eq!(ObjectIdentifier::from_bytes(oid.as_bytes()).unwrap(), oid);
+    assert_eq!(
+        "1.2.4294967295.128".parse::<ObjectIdentifier>().unwrap(),
+        oid
+    );
+    assert_eq!(oid.to_string(), "1.2.4294967295.128");
+
+    // Arc exceeding `u32::MAX`
+    assert!("1.2.4294967296".parse::<ObjectIdentifier>().is_err());
+    assert!(ObjectIdentifier::from_bytes(&hex!("2A9080808000")).is_err());
+
+    // Non-minimally encoded arc
+    assert!(ObjectIdentifier::from_bytes(&hex!("2A808080808000")).is_err());
+}
+
+#[test]
+fn length_limits() {
+    // Longest OID which fits in `ObjectIdentifier::MAX_LENGTH` bytes
+    let arcs = [1; ObjectIdentifier::MAX_LENGTH + 1];
+    let oid = ObjectIdentifier::from_arcs(&arcs).unwrap();
+    assert_eq!(oid.as_bytes().len(), ObjectIdentifier::MAX_LENGTH);
+    assert_eq!(oid.arcs().count(), arcs.len());
+
+    // OID with 100 arcs
+    let arcs = [1; 100];
+    assert!(ObjectIdentifier::from_arcs(&arcs).is_err());
+    assert!(vec!["1"; 100]
+        .join(".")
+        .parse::<ObjectIdentifier>()
+        .is_err());
+    assert!(ObjectIdentifier::from_bytes(&[1; 99]).is_err());
+}
+
 #[test]
 fn as_bytes() {
     assert_eq!(EXAMPLE_OID_1.as_bytes(), EXAMPLE_OID_1_BER);
     assert_eq!(EXAMPLE_OID_2.as_bytes(), EXAMPLE_OID_2_BER);
 }
 
+#[test]
+fn ord() {
+    let ec_public_key = ObjectIdentifier::new("1.2.840.10045.2.1");
+    let rsa_encryption = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+    let ed25519 = ObjectIdentifier::new("1.3.101.112");
+
+    assert!(rsa_encryption < ec_public_key);
+    assert!(ec_public_key < ed25519);
+    assert_eq!(
+        EXAMPLE_OID_1.cmp(&EXAMPLE_OID_1_STRING.parse().unwrap()),
+        core::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn btree_map_keys() {
+    let mut registry = BTreeMap::new();
+    registry.insert(
+        ObjectIdentifier::new("1.2.840.113549.1.1.1"),
+        "rsaEncryption",
+    );
+    registry.insert(ObjectIdentifier::new("1.2.840.10045.2.1"), "id-ecPublicKey");
+    registry.insert(ObjectIdentifier::new("1.3.101.112"), "id-Ed25519");
+    registry.insert(ObjectIdentifier::new("1.3.101.110"), "id-X25519");
+
+    let ed25519 = ObjectIdentifier::from_bytes(&hex!("2B6570")).unwrap();
+    assert_eq!(registry.get(&ed25519), Some(&"id-Ed25519"));
+    assert_eq!(registry.get(&EXAMPLE_OID_1), Some(&"id-ecPublicKey"));
+    assert_eq!(registry.get(&EXAMPLE_OID_2), None);
+
+    let names: Vec<_> = registry.values().copied().collect();
+    assert_eq!(
+        names,
+        ["rsaEncryption", "id-ecPublicKey", "id-X25519", "id-Ed25519"]
+    );
+}
+
+#[test]
+fn hash_map_keys() {
+    let mut registry = HashMap::new();
+    registry.insert(EXAMPLE_OID_1, "id-ecPublicKey");
+    registry.insert(EXAMPLE_OID_2, "id-aes256-GCM");
+
+    let oid1 = ObjectIdentifier::from_bytes(EXAMPLE_OID_1_BER).unwrap();
+    let oid2 = ObjectIdentifier::from_arcs(&[2, 16, 840, 1, 101, 3, 4, 1, 42]).unwrap();
+    assert_eq!(registry.get(&oid1), Some(&"id-ecPublicKey"));
+    assert_eq!(registry.get(&oid2), Some(&"id-aes256-GCM"));
+}
+
 #[test]
 #[should_panic]
 fn parse_empty() {
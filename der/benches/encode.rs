@@ -0,0 +1,156 @@
+//! Benchmarks for encoding nested constructed values.
+//!
+//! Compares encoding into a slice, which writes nested values in a single
+//! pass, with encoding to a [`Writer`], which computes the length of each
+//! nested value before writing it. The value being encoded is shaped like
+//! an RSA-4096 private key wrapped in several levels of `SEQUENCE`s.
+//!
+//! Run with `cargo bench -p der --bench encode`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use der::{
+    asn1::UIntBytes, Encodable, EncodeValue, Encoder, ErrorKind, Length, Result, Tag, Tagged,
+    Writer,
+};
+
+/// Size of the output buffer.
+const BUFFER_SIZE: usize = 4096;
+
+/// Bytes of the `INTEGER`s, which all have their highest bit clear.
+const INTEGER_BYTES: [u8; 512] = [0x5A; 512];
+
+/// `SEQUENCE` of `INTEGER`s shaped like an RSA-4096 private key: the
+/// version, modulus, public and private exponents, both primes, both CRT
+/// exponents and the CRT coefficient.
+struct Key<'a> {
+    integers: [UIntBytes<'a>; 9],
+}
+
+impl EncodeValue for Key<'_> {
+    fn value_len(&self) -> Result<Length> {
+        self.integers
+            .iter()
+            .try_fold(Length::ZERO, |len, int| len + int.encoded_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.integers.iter().try_for_each(|int| int.encode(encoder))
+    }
+}
+
+impl Tagged for Key<'_> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// Key wrapped in `depth` levels of nested `SEQUENCE`s.
+struct Nested<'a> {
+    depth: usize,
+    key: &'a Key<'a>,
+}
+
+impl EncodeValue for Nested<'_> {
+    fn value_len(&self) -> Result<Length> {
+        match self.depth {
+            0 => self.key.encoded_len(),
+            depth => Nested {
+                depth: depth - 1,
+                key: self.key,
+            }
+            .encoded_len(),
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self.depth {
+            0 => self.key.encode(encoder),
+            depth => Nested {
+                depth: depth - 1,
+                key: self.key,
+            }
+            .encode(encoder),
+        }
+    }
+}
+
+impl Tagged for Nested<'_> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// [`Writer`] which writes into a fixed-size buffer.
+struct BufWriter {
+    bytes: [u8; BUFFER_SIZE],
+    len: usize,
+}
+
+impl Writer for BufWriter {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        let end = self.len + slice.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(ErrorKind::Overlength)?
+            .copy_from_slice(slice);
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn encode_nested(c: &mut Criterion) {
+    let int = |size| UIntBytes::new(&INTEGER_BYTES[..size]).expect("invalid INTEGER");
+    let key = Key {
+        integers: [
+            int(1),
+            int(512),
+            int(3),
+            int(512),
+            int(256),
+            int(256),
+            int(256),
+            int(256),
+            int(256),
+        ],
+    };
+    let mut group = c.benchmark_group("encode nested SEQUENCE");
+
+    for &depth in &[0, 8, 32] {
+        let value = Nested { depth, key: &key };
+
+        // Both paths must produce the same encoding
+        let mut buf = [0u8; BUFFER_SIZE];
+        let mut writer = BufWriter {
+            bytes: [0u8; BUFFER_SIZE],
+            len: 0,
+        };
+        value.encode_to_writer(&mut writer).expect("encoding error");
+        assert_eq!(
+            value.encode_to_slice(&mut buf).expect("encoding error"),
+            &writer.bytes[..writer.len]
+        );
+
+        group.bench_with_input(BenchmarkId::new("slice", depth), &value, |b, value| {
+            b.iter(|| {
+                let mut buf = [0u8; BUFFER_SIZE];
+                black_box(value)
+                    .encode_to_slice(&mut buf)
+                    .expect("encoding error")
+                    .len()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("writer", depth), &value, |b, value| {
+            b.iter(|| {
+                let mut writer = BufWriter {
+                    bytes: [0u8; BUFFER_SIZE],
+                    len: 0,
+                };
+                black_box(value)
+                    .encode_to_writer(&mut writer)
+                    .expect("encoding error")
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, encode_nested);
+criterion_main!(benches);
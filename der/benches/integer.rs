@@ -0,0 +1,56 @@
+//! Benchmarks for decoding small `INTEGER`s.
+//!
+//! Compares the `u32` decoder, which uses a fast path for values encoded in
+//! 1-4 bytes, with the `i32` decoder, which decodes non-negative values using
+//! the general unsigned path (`uint::decode_to_array`) that `u32` used before
+//! the fast path was added, followed by a sign check.
+//!
+//! Run with `cargo bench -p der --bench integer`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use der::Decodable;
+
+/// Small `INTEGER`s typical of `version` and `keyLength` fields.
+const VECTORS: &[&[u8]] = &[
+    &[0x02, 0x01, 0x00],
+    &[0x02, 0x01, 0x01],
+    &[0x02, 0x01, 0x20],
+    &[0x02, 0x02, 0x08, 0x00],
+    &[0x02, 0x03, 0x01, 0x00, 0x01],
+];
+
+fn decode_small_integers(c: &mut Criterion) {
+    for vector in VECTORS {
+        assert_eq!(
+            u32::from_der(vector).ok(),
+            i32::from_der(vector).ok().map(|value| value as u32),
+            "{:02X?}",
+            vector
+        );
+    }
+
+    let mut group = c.benchmark_group("decode small INTEGER");
+
+    group.bench_function("u32 (fast path)", |b| {
+        b.iter(|| {
+            VECTORS
+                .iter()
+                .map(|der| u32::from_der(black_box(der)).expect("decoding error"))
+                .fold(0, u32::wrapping_add)
+        })
+    });
+
+    group.bench_function("i32 (general path)", |b| {
+        b.iter(|| {
+            VECTORS
+                .iter()
+                .map(|der| i32::from_der(black_box(der)).expect("decoding error"))
+                .fold(0, i32::wrapping_add)
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode_small_integers);
+criterion_main!(benches);
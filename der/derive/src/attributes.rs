@@ -7,12 +7,20 @@ use syn::{Attribute, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
 pub(crate) struct Asn1Attrs {
     /// Value of the `#[asn1(type = "...")]` attribute if provided
     pub asn1_type: Option<Asn1Type>,
+
+    /// Is the `#[asn1(skip)]` attribute present?
+    pub skip: bool,
+
+    /// Value of the `#[asn1(order = N)]` attribute if provided
+    pub order: Option<u32>,
 }
 
 impl Asn1Attrs {
     /// Parse attributes from a field or enum variant
     pub fn new(attrs: &[Attribute]) -> Self {
         let mut asn1_type = None;
+        let mut skip = false;
+        let mut order = None;
 
         for attr in attrs {
             if !attr.path.is_ident("asn1") {
@@ -38,6 +46,30 @@ impl Asn1Attrs {
 
                             asn1_type = Some(Asn1Type::new(&lit_str.value()));
                         }
+                        Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit: Lit::Int(lit_int),
+                            ..
+                        }))) if path.is_ident("order") => {
+                            // Parse the `order = N` attribute
+                            if let Some(n) = order {
+                                panic!("duplicate ASN.1 `order` attribute: {}", n);
+                            }
+
+                            order = Some(
+                                lit_int
+                                    .base10_parse()
+                                    .expect("error parsing ASN.1 `order` attribute"),
+                            );
+                        }
+                        Some(NestedMeta::Meta(Meta::Path(path))) if path.is_ident("skip") => {
+                            // Parse the `skip` attribute
+                            if skip {
+                                panic!("duplicate ASN.1 `skip` attribute");
+                            }
+
+                            skip = true;
+                        }
                         other => panic!("malformed `asn1` attribute: {:?}", other),
                     }
                 }
@@ -45,6 +77,10 @@ impl Asn1Attrs {
             }
         }
 
-        Self { asn1_type }
+        Self {
+            asn1_type,
+            skip,
+            order,
+        }
     }
 }
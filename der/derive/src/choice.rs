@@ -47,7 +47,23 @@ impl DeriveChoice {
         };
 
         for (variant_info, variant) in s.variants().iter().zip(&data.variants) {
-            let asn1_type = Asn1Attrs::new(&variant.attrs).asn1_type.unwrap_or_else(|| {
+            let attrs = Asn1Attrs::new(&variant.attrs);
+
+            if attrs.skip {
+                panic!(
+                    "#[asn1(skip)] is unsupported on enum variant: {}",
+                    variant.ident
+                );
+            }
+
+            if attrs.order.is_some() {
+                panic!(
+                    "#[asn1(order = ...)] is unsupported on enum variant: {}",
+                    variant.ident
+                );
+            }
+
+            let asn1_type = attrs.asn1_type.unwrap_or_else(|| {
                 panic!(
                     "no #[asn1(type=...)] specified for enum variant: {}",
                     variant.ident
@@ -90,15 +106,7 @@ impl DeriveChoice {
     fn derive_variant_decoder(&mut self, asn1_type: Asn1Type) {
         let tag = asn1_type.tag();
 
-        let decoder = match asn1_type {
-            Asn1Type::BitString => quote!(decoder.bit_string()),
-            Asn1Type::GeneralizedTime => quote!(decoder.generalized_time()),
-            Asn1Type::OctetString => quote!(decoder.octet_string()),
-            Asn1Type::PrintableString => quote!(decoder.printable_string()),
-            Asn1Type::UtcTime => quote!(decoder.utc_time()),
-            Asn1Type::Utf8String => quote!(decoder.utf8_string()),
-        };
-
+        let decoder = asn1_type.decoder();
         { quote!(#tag => Ok(#decoder?.try_into()?),) }.to_tokens(&mut self.decode_body);
     }
 
@@ -40,6 +40,7 @@
 //!
 //! - `BIT STRING`: performs an intermediate conversion to [`der::asn1::BitString`]
 //! - `GeneralizedTime`: performs an intermediate conversion to [`der::asn1::GeneralizedTime`]
+//! - `IA5String`: performs an intermediate conversion to [`der::asn1::Ia5String`]
 //! - `OCTET STRING`: performs an intermediate conversion to [`der::asn1::OctetString`]
 //! - `PrintableString`: performs an intermediate conversion to [`der::asn1::PrintableString`]
 //! - `UTCTime`: performs an intermediate conversion to [`der::asn1::UtcTime`]
@@ -48,9 +49,27 @@
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
+//! # `#[asn1(skip)]` attribute
+//!
+//! This attribute can be placed on struct fields which don't correspond to an
+//! ASN.1 field, e.g. `PhantomData` markers or cached values. Such fields are
+//! ignored when encoding, and populated using their [`Default`] impl when
+//! decoding.
+//!
+//! # `#[asn1(order = N)]` attribute
+//!
+//! By default fields are encoded and decoded in the order in which they're
+//! declared. This attribute can be placed on struct fields to encode and
+//! decode them in ascending order of `N` instead, e.g. to keep a logical
+//! layout of a struct which differs from its ASN.1 schema.
+//!
+//! If used, it must be placed on every field which isn't skipped, and no two
+//! fields may have the same `N`. This is checked at compile time.
+//!
 //! [`der`]: https://docs.rs/der/
 //! [`der::asn1::BitString`]: https://docs.rs/der/latest/der/asn1/struct.BitString.html
 //! [`der::asn1::GeneralizedTime`]: https://docs.rs/der/latest/der/asn1/struct.GeneralizedTime.html
+//! [`der::asn1::Ia5String`]: https://docs.rs/der/latest/der/asn1/struct.Ia5String.html
 //! [`der::asn1::OctetString`]: https://docs.rs/der/latest/der/asn1/struct.OctetString.html
 //! [`der::asn1::PrintableString`]: https://docs.rs/der/latest/der/asn1/struct.PrintableString.html
 //! [`der::asn1::UtcTime`]: https://docs.rs/der/latest/der/asn1/struct.UtcTime.html
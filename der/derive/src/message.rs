@@ -4,8 +4,8 @@
 use crate::{Asn1Attrs, Asn1Type};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{DataStruct, Field, Ident, Lifetime};
-use synstructure::Structure;
+use syn::{DataStruct, Ident, Lifetime};
+use synstructure::{AddBounds, Structure};
 
 /// Derive the `Message` trait for a struct
 pub(crate) struct DeriveMessage {
@@ -20,54 +20,78 @@ pub(crate) struct DeriveMessage {
 }
 
 impl DeriveMessage {
-    pub fn derive(s: Structure<'_>, data: &DataStruct, lifetime: Option<&Lifetime>) -> TokenStream {
+    pub fn derive(
+        mut s: Structure<'_>,
+        data: &DataStruct,
+        lifetime: Option<&Lifetime>,
+    ) -> TokenStream {
+        // Skipped fields don't need to impl any ASN.1 traits
+        s.filter(|binding| !Asn1Attrs::new(&binding.ast().attrs).skip)
+            .add_bounds(AddBounds::Fields);
+
         let mut state = Self {
             decode_fields: TokenStream::new(),
             decode_result: TokenStream::new(),
             encode_fields: TokenStream::new(),
         };
 
-        for field in &data.fields {
-            state.derive_field(field);
+        let mut fields = data
+            .fields
+            .iter()
+            .map(|field| {
+                let name = field
+                    .ident
+                    .as_ref()
+                    .cloned()
+                    .expect("no name on struct field i.e. tuple structs unsupported");
+
+                (name, Asn1Attrs::new(&field.attrs))
+            })
+            .collect::<Vec<_>>();
+
+        sort_fields(&mut fields);
+
+        for (name, attrs) in &fields {
+            state.derive_field(name, attrs);
         }
 
         state.finish(&s, lifetime)
     }
 
     /// Derive handling for a particular `#[field(...)]`
-    fn derive_field(&mut self, field: &Field) {
-        let name = field
-            .ident
-            .as_ref()
-            .cloned()
-            .expect("no name on struct field i.e. tuple structs unsupported");
-
-        let asn1_type = Asn1Attrs::new(&field.attrs).asn1_type;
-        self.derive_field_decoder(&name, asn1_type);
-        self.derive_field_encoder(&name, asn1_type);
+    fn derive_field(&mut self, name: &Ident, attrs: &Asn1Attrs) {
+        if attrs.skip {
+            if attrs.asn1_type.is_some() {
+                panic!("#[asn1(skip)] field can't have an ASN.1 type: {}", name);
+            }
+
+            if attrs.order.is_some() {
+                panic!("#[asn1(skip)] field can't have an ASN.1 order: {}", name);
+            }
+
+            self.derive_skipped_field(name);
+            return;
+        }
+
+        let asn1_type = attrs.asn1_type;
+        self.derive_field_decoder(name, asn1_type);
+        self.derive_field_encoder(name, asn1_type);
+    }
+
+    /// Derive code for a field excluded from encoding/decoding, which is
+    /// populated using its `Default` impl when decoding
+    fn derive_skipped_field(&mut self, name: &Ident) {
+        let field_result = quote!(#name: ::core::default::Default::default(),);
+        field_result.to_tokens(&mut self.decode_result);
     }
 
     /// Derive code for decoding a field of a message
     fn derive_field_decoder(&mut self, name: &Ident, asn1_type: Option<Asn1Type>) {
         let field_decoder = match asn1_type {
-            Some(Asn1Type::BitString) => quote! {
-                let #name = decoder.bit_string()?.try_into()?;
-            },
-            Some(Asn1Type::GeneralizedTime) => quote! {
-                let #name = decoder.generalized_time()?.try_into()?;
-            },
-            Some(Asn1Type::OctetString) => quote! {
-                let #name = decoder.octet_string()?.try_into()?;
-            },
-            Some(Asn1Type::PrintableString) => quote! {
-                let #name = decoder.printable_string()?.try_into()?;
-            },
-            Some(Asn1Type::UtcTime) => quote! {
-                let #name = decoder.utc_time()?.try_into()?;
-            },
-            Some(Asn1Type::Utf8String) => quote! {
-                let #name = decoder.utf8_string()?.try_into()?;
-            },
+            Some(ty) => {
+                let decoder = ty.decoder();
+                quote! { let #name = #decoder?.try_into()?; }
+            }
             None => quote! { let #name = decoder.decode()?; },
         };
         field_decoder.to_tokens(&mut self.decode_fields);
@@ -126,3 +150,34 @@ impl DeriveMessage {
         })
     }
 }
+
+/// Sort fields into the order given by their `#[asn1(order = N)]` attributes
+/// (if any), in which they're encoded and decoded.
+///
+/// Either all fields which aren't skipped must have an `order`, or none of
+/// them, and no two fields may have the same `order`.
+fn sort_fields(fields: &mut [(Ident, Asn1Attrs)]) {
+    if fields.iter().all(|(_, attrs)| attrs.order.is_none()) {
+        return;
+    }
+
+    for (name, attrs) in fields.iter() {
+        if !attrs.skip && attrs.order.is_none() {
+            panic!("missing ASN.1 `order` attribute on field: {}", name);
+        }
+    }
+
+    // Skipped fields aren't encoded, so their position is irrelevant
+    fields.sort_by_key(|(_, attrs)| attrs.order);
+
+    for pair in fields.windows(2) {
+        if let [(name1, attrs1), (name2, attrs2)] = pair {
+            if attrs1.order.is_some() && attrs1.order == attrs2.order {
+                panic!(
+                    "duplicate ASN.1 `order` attribute on fields {} and {}",
+                    name1, name2
+                );
+            }
+        }
+    }
+}
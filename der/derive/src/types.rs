@@ -14,6 +14,9 @@ pub(crate) enum Asn1Type {
     /// ASN.1 `GeneralizedTime`
     GeneralizedTime,
 
+    /// ASN.1 `IA5String`
+    Ia5String,
+
     /// ASN.1 `OCTET STRING`
     OctetString,
 
@@ -33,6 +36,7 @@ impl Asn1Type {
         match s {
             "BIT STRING" => Self::BitString,
             "GeneralizedTime" => Self::GeneralizedTime,
+            "IA5String" => Self::Ia5String,
             "OCTET STRING" => Self::OctetString,
             "PrintableString" => Self::PrintableString,
             "UTCTime" => Self::UtcTime,
@@ -46,6 +50,7 @@ impl Asn1Type {
         match self {
             Asn1Type::BitString => quote!(::der::Tag::BitString),
             Asn1Type::GeneralizedTime => quote!(::der::Tag::GeneralizedTime),
+            Asn1Type::Ia5String => quote!(::der::Tag::Ia5String),
             Asn1Type::OctetString => quote!(::der::Tag::OctetString),
             Asn1Type::PrintableString => quote!(::der::Tag::PrintableString),
             Asn1Type::UtcTime => quote!(::der::Tag::UtcTime),
@@ -53,15 +58,29 @@ impl Asn1Type {
         }
     }
 
+    /// Get the `der::Decoder` method call which decodes this ASN.1 type
+    pub fn decoder(&self) -> TokenStream {
+        match self {
+            Asn1Type::BitString => quote!(decoder.bit_string()),
+            Asn1Type::GeneralizedTime => quote!(decoder.generalized_time()),
+            Asn1Type::Ia5String => quote!(decoder.ia5_string()),
+            Asn1Type::OctetString => quote!(decoder.octet_string()),
+            Asn1Type::PrintableString => quote!(decoder.printable_string()),
+            Asn1Type::UtcTime => quote!(decoder.utc_time()),
+            Asn1Type::Utf8String => quote!(decoder.utf8_string()),
+        }
+    }
+
     /// Get a `der::Encoder` object for a particular ASN.1 type
     pub fn encoder(&self, binding: TokenStream) -> TokenStream {
         match self {
             Asn1Type::BitString => quote!(::der::asn1::BitString::new(#binding)),
             Asn1Type::GeneralizedTime => quote!(::der::asn1::GeneralizedTime::try_from(#binding)),
+            Asn1Type::Ia5String => quote!(::der::asn1::Ia5String::new(#binding)),
             Asn1Type::OctetString => quote!(::der::asn1::OctetString::new(#binding)),
             Asn1Type::PrintableString => quote!(::der::asn1::PrintableString::new(#binding)),
             Asn1Type::UtcTime => quote!(::der::asn1::UtcTime::try_from(#binding)),
-            Asn1Type::Utf8String => quote!(&::der::Utf8String::new(#binding)),
+            Asn1Type::Utf8String => quote!(::der::asn1::Utf8String::new(#binding)),
         }
     }
 }
@@ -71,6 +90,7 @@ impl fmt::Display for Asn1Type {
         f.write_str(match self {
             Asn1Type::BitString => "BIT STRING",
             Asn1Type::GeneralizedTime => "GeneralizedTime",
+            Asn1Type::Ia5String => "IA5String",
             Asn1Type::OctetString => "OCTET STRING",
             Asn1Type::PrintableString => "PrintableString",
             Asn1Type::UtcTime => "UTCTime",
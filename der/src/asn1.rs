@@ -22,20 +22,25 @@ mod utf8_string;
 pub use self::{
     any::Any,
     bit_string::BitString,
+    boolean::{Boolean, DefaultFalse},
     choice::Choice,
     context_specific::ContextSpecific,
     generalized_time::GeneralizedTime,
     ia5_string::Ia5String,
-    integer::bigint::UIntBytes,
+    integer::bigint::{IntBytes, UIntBytes},
     null::Null,
     octet_string::OctetString,
     printable_string::PrintableString,
-    sequence::{iter::SequenceIter, Sequence},
+    sequence::{iter::SequenceIter, EmptySequence, Sequence},
     set_of::{SetOf, SetOfRef, SetOfRefIter},
     utc_time::UtcTime,
     utf8_string::Utf8String,
 };
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use self::set_of::SetOfVec;
+
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
 pub use const_oid::ObjectIdentifier;
@@ -4,7 +4,11 @@ use crate::{
     asn1::*, ByteSlice, Choice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder,
     Error, ErrorKind, Header, Length, Result, Tag, Tagged,
 };
-use core::convert::{TryFrom, TryInto};
+use core::{
+    cmp::Ordering,
+    convert::{TryFrom, TryInto},
+    fmt,
+};
 
 #[cfg(feature = "oid")]
 use crate::asn1::ObjectIdentifier;
@@ -18,25 +22,60 @@ use crate::asn1::ObjectIdentifier;
 /// Nevertheless, this crate defines an [`Any`] type as it remains a familiar
 /// and useful concept which is still extensively used in things like
 /// PKI-related RFCs.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+///
+/// An [`Any`] decoded by a strict [`Decoder`] (see [`Decoder::new_strict`])
+/// remembers this, so its value is also decoded strictly. Likewise, it
+/// remembers where its value is located in the decoder's input, so the
+/// [`Span`]s of values nested within it are relative to the same input.
+/// Neither affects comparisons between [`Any`] values.
+///
+/// [`Span`]: crate::Span
+#[derive(Copy, Clone)]
 pub struct Any<'a> {
     /// Tag representing the type of the encoded value.
     tag: Tag,
 
     /// Inner value encoded as bytes.
     value: ByteSlice<'a>,
+
+    /// Was this value decoded by a strict decoder?
+    strict: bool,
+
+    /// Offset of the value relative to the input of the outermost decoder
+    /// it was decoded by.
+    offset: Length,
 }
 
 impl<'a> Any<'a> {
     /// Create a new [`Any`] from the provided [`Tag`] and byte slice.
     pub fn new(tag: Tag, bytes: &'a [u8]) -> Result<Self> {
         let value = ByteSlice::new(bytes).map_err(|_| ErrorKind::Length { tag })?;
-        Ok(Self { tag, value })
+        Ok(Self::from_tag_and_value(tag, value))
+    }
+
+    /// Create a new [`Any`] by encoding the value of `T` into the provided
+    /// buffer, using the tag of `T`.
+    ///
+    /// This is useful for assembling fields such as algorithm parameters
+    /// from any type which can be encoded. Returns an error if the encoded
+    /// value doesn't fit in the buffer.
+    pub fn from_tagged<T>(value: &T, buffer: &'a mut [u8]) -> Result<Self>
+    where
+        T: EncodeValue + Tagged,
+    {
+        let mut encoder = Encoder::new(buffer);
+        value.encode_value(&mut encoder)?;
+        Self::new(T::TAG, encoder.finish()?)
     }
 
     /// Infallible creation of an [`Any`] from a [`ByteSlice`].
     pub(crate) fn from_tag_and_value(tag: Tag, value: ByteSlice<'a>) -> Self {
-        Self { tag, value }
+        Self {
+            tag,
+            value,
+            strict: false,
+            offset: Length::ZERO,
+        }
     }
 
     /// Get the tag for this [`Any`] type.
@@ -55,8 +94,20 @@ impl<'a> Any<'a> {
         T: DecodeValue<'a> + Tagged,
     {
         self.tag.assert_eq(T::TAG)?;
-        let mut decoder = Decoder::new(self.value());
-        let result = T::decode_value(&mut decoder, self.value.len())?;
+        self.decode_nested(|decoder| T::decode_value(decoder, self.value.len()))
+    }
+
+    /// Decode the value of this [`Any`] using the provided closure, which is
+    /// run against a new [`Decoder`] and must consume the entire value.
+    ///
+    /// The decoder is strict if this [`Any`] was decoded by a strict decoder,
+    /// and tracks the offset of the value within the original input.
+    pub(crate) fn decode_nested<F, T>(self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let mut decoder = Decoder::new_nested(self.value(), self.strict, self.offset);
+        let result = f(&mut decoder)?;
         decoder.finish(result)
     }
 
@@ -123,7 +174,8 @@ impl<'a> Any<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Sequence::try_from(self)?.decode_nested(f)
+        self.tag.assert_eq(Tag::Sequence)?;
+        self.decode_nested(f)
     }
 
     /// Attempt to decode an ASN.1 `UTCTime`.
@@ -147,8 +199,44 @@ impl<'a> Decodable<'a> for Any<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Any<'a>> {
         let header = Header::decode(decoder)?;
         let tag = header.tag;
+        let offset = decoder.absolute_position()?;
         let value = ByteSlice::decode_value(decoder, header.length)?;
-        Ok(Self { tag, value })
+
+        Ok(Self {
+            tag,
+            value,
+            strict: decoder.is_strict(),
+            offset,
+        })
+    }
+}
+
+impl fmt::Debug for Any<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Any")
+            .field("tag", &self.tag)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Eq for Any<'_> {}
+
+impl PartialEq for Any<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tag == other.tag && self.value == other.value
+    }
+}
+
+impl PartialOrd for Any<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Any<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.tag, self.value).cmp(&(other.tag, other.value))
     }
 }
 
@@ -186,3 +274,42 @@ impl<'a> TryFrom<&'a [u8]> for Any<'a> {
         Any::from_der(bytes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Any;
+    use crate::Tag;
+
+    #[test]
+    fn new_with_pre_encoded_value() {
+        let any = Any::new(Tag::Integer, &[0x2A]).unwrap();
+        assert_eq!(any.tag(), Tag::Integer);
+        assert_eq!(any.decode_into::<u8>().unwrap(), 42);
+    }
+
+    #[cfg(feature = "oid")]
+    #[test]
+    fn from_tagged_oid() {
+        use crate::{asn1::ObjectIdentifier, Decodable, Encodable};
+        use hex_literal::hex;
+
+        // `id-ecPublicKey` parameters: `prime256v1` curve OID
+        let oid = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+
+        let mut value_buf = [0u8; 16];
+        let any = Any::from_tagged(&oid, &mut value_buf).unwrap();
+        assert_eq!(any.tag(), Tag::ObjectIdentifier);
+        assert_eq!(any.oid().unwrap(), oid);
+
+        let mut der_buf = [0u8; 16];
+        let der = any.encode_to_slice(&mut der_buf).unwrap();
+        assert_eq!(der, hex!("06082A8648CE3D030107"));
+        assert_eq!(Any::from_der(der).unwrap().oid().unwrap(), oid);
+    }
+
+    #[test]
+    fn from_tagged_buffer_too_small() {
+        let mut buf = [0u8; 1];
+        assert!(Any::from_tagged(&0x1234u16, &mut buf).is_err());
+    }
+}
@@ -1,8 +1,8 @@
 //! ASN.1 `BOOLEAN` support.
 
 use crate::{
-    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
-    Result, Tag, Tagged,
+    asn1::Any, ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error,
+    ErrorKind, Length, Result, Tag, Tagged,
 };
 use core::convert::{TryFrom, TryInto};
 
@@ -62,9 +62,129 @@ impl TryFrom<Any<'_>> for bool {
     }
 }
 
+/// ASN.1 `BOOLEAN` type.
+///
+/// Newtype wrapper around [`bool`] which always encodes as `0xFF` (`TRUE`)
+/// or `0x00` (`FALSE`) and rejects any other contents octet when decoding.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Boolean(bool);
+
+impl Boolean {
+    /// ASN.1 `FALSE`.
+    pub const FALSE: Self = Self(false);
+
+    /// ASN.1 `TRUE`.
+    pub const TRUE: Self = Self(true);
+
+    /// Create a new [`Boolean`] from a [`bool`].
+    pub const fn new(value: bool) -> Self {
+        Self(value)
+    }
+
+    /// Get the inner [`bool`] value.
+    pub const fn get(self) -> bool {
+        self.0
+    }
+}
+
+impl<'a> DecodeValue<'a> for Boolean {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        bool::decode_value(decoder, length).map(Self)
+    }
+}
+
+impl EncodeValue for Boolean {
+    fn value_len(&self) -> Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl Tagged for Boolean {
+    const TAG: Tag = Tag::Boolean;
+}
+
+impl From<bool> for Boolean {
+    fn from(value: bool) -> Boolean {
+        Boolean(value)
+    }
+}
+
+impl From<Boolean> for bool {
+    fn from(value: Boolean) -> bool {
+        value.0
+    }
+}
+
+impl From<Boolean> for Any<'static> {
+    fn from(value: Boolean) -> Any<'static> {
+        value.0.into()
+    }
+}
+
+impl TryFrom<Any<'_>> for Boolean {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Boolean> {
+        any.try_into()
+    }
+}
+
+/// ASN.1 `BOOLEAN DEFAULT FALSE` field.
+///
+/// DER requires fields equal to their `DEFAULT` value to be omitted, so
+/// `false` encodes as zero bytes, and an absent field decodes as `false`.
+/// An explicitly encoded `FALSE` is rejected as non-canonical.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DefaultFalse(pub bool);
+
+impl<'a> Decodable<'a> for DefaultFalse {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        match decoder.decode::<Option<bool>>()? {
+            Some(true) => Ok(DefaultFalse(true)),
+            Some(false) => Err(decoder.error(ErrorKind::Noncanonical { tag: Tag::Boolean })),
+            None => Ok(DefaultFalse(false)),
+        }
+    }
+}
+
+impl Encodable for DefaultFalse {
+    fn encoded_len(&self) -> Result<Length> {
+        if self.0 {
+            self.0.encoded_len()
+        } else {
+            Ok(Length::ZERO)
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        if self.0 {
+            self.0.encode(encoder)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<bool> for DefaultFalse {
+    fn from(value: bool) -> DefaultFalse {
+        DefaultFalse(value)
+    }
+}
+
+impl From<DefaultFalse> for bool {
+    fn from(value: DefaultFalse) -> bool {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Decodable, Encodable};
+    use super::{Boolean, DefaultFalse};
+    use crate::{Decodable, Decoder, Encodable, Encoder, ErrorKind, Tag};
 
     #[test]
     fn decode() {
@@ -89,4 +209,82 @@ mod tests {
     fn reject_non_canonical() {
         assert!(bool::from_der(&[0x01, 0x01, 0x01]).is_err());
     }
+
+    #[test]
+    fn boolean_encode() {
+        let mut buffer = [0u8; 3];
+        assert_eq!(
+            &[0x01, 0x01, 0xFF],
+            Boolean::TRUE.encode_to_slice(&mut buffer).unwrap()
+        );
+        assert_eq!(
+            &[0x01, 0x01, 0x00],
+            Boolean::new(false).encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn boolean_decode() {
+        assert_eq!(
+            Boolean::TRUE,
+            Boolean::from_der(&[0x01, 0x01, 0xFF]).unwrap()
+        );
+        assert_eq!(
+            Boolean::FALSE,
+            Boolean::from_der(&[0x01, 0x01, 0x00]).unwrap()
+        );
+    }
+
+    #[test]
+    fn boolean_reject_non_canonical() {
+        for &byte in &[0x01, 0x7F, 0xFE] {
+            let err = Boolean::from_der(&[0x01, 0x01, byte]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Boolean });
+        }
+    }
+
+    #[test]
+    fn default_false_encode() {
+        let mut buffer = [0u8; 3];
+        assert_eq!(DefaultFalse(false).encoded_len().unwrap(), 0u8.into());
+        assert_eq!(
+            DefaultFalse(false).encode_to_slice(&mut buffer).unwrap(),
+            &[]
+        );
+        assert_eq!(
+            &[0x01, 0x01, 0xFF],
+            DefaultFalse(true).encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn default_false_round_trip() {
+        for &value in &[false, true] {
+            let mut buffer = [0u8; 6];
+            let mut encoder = Encoder::new(&mut buffer);
+            encoder.encode(&DefaultFalse(value)).unwrap();
+            encoder.encode(&Tag::Null).unwrap();
+            encoder.byte(0).unwrap();
+            let der = encoder.finish().unwrap();
+
+            let mut decoder = Decoder::new(der);
+            assert_eq!(
+                decoder.decode::<DefaultFalse>().unwrap(),
+                DefaultFalse(value)
+            );
+            assert_eq!(decoder.decode::<Tag>().unwrap(), Tag::Null);
+        }
+
+        let mut decoder = Decoder::new(&[]);
+        assert_eq!(
+            decoder.decode::<DefaultFalse>().unwrap(),
+            DefaultFalse(false)
+        );
+    }
+
+    #[test]
+    fn default_false_reject_explicit_false() {
+        let err = DefaultFalse::from_der(&[0x01, 0x01, 0x00]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Boolean });
+    }
 }
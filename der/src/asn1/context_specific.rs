@@ -47,13 +47,17 @@ impl<T> ContextSpecific<T> {
         T: Decodable<'a>,
     {
         Self::decode_with(decoder, tag_number, |decoder| {
-            let any = Any::decode(decoder)?;
+            let header = Header::decode(decoder)?;
 
-            if !any.tag().is_constructed() {
-                return Err(any.tag().non_canonical_error());
+            if !header.tag.is_constructed() {
+                return Err(header.tag.non_canonical_error());
             }
 
-            Self::try_from(any)
+            Ok(Self {
+                tag_number,
+                tag_mode: TagMode::Explicit,
+                value: decoder.read_nested(header.length, T::decode)?,
+            })
         })
     }
 
@@ -77,7 +81,7 @@ impl<T> ContextSpecific<T> {
                 return Err(header.tag.non_canonical_error());
             }
 
-            let value = T::decode_value(decoder, header.length)?;
+            let value = decoder.decode_value(header)?;
 
             Ok(Self {
                 tag_number,
@@ -111,6 +115,25 @@ impl<T> ContextSpecific<T> {
         Ok(None)
     }
 
+    /// Apply an ASN.1 `DEFAULT` value to an `OPTIONAL` context-specific
+    /// field, e.g. one decoded as `Option<ContextSpecific<T>>`.
+    ///
+    /// Returns the field's value if present, or `default` if absent.
+    ///
+    /// DER requires fields whose value is equal to their `DEFAULT` to be
+    /// omitted, so an error is returned if the field is present and has a
+    /// value equal to `default`.
+    pub fn default_or(field: Option<Self>, default: T) -> Result<T>
+    where
+        T: PartialEq + Tagged,
+    {
+        match field {
+            Some(field) if field.value == default => Err(field.tag().non_canonical_error()),
+            Some(field) => Ok(field.value),
+            None => Ok(default),
+        }
+    }
+
     /// Get the tag for this field.
     pub fn tag(&self) -> Tag
     where
@@ -155,8 +178,11 @@ where
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        Header::new(self.tag(), self.value_len()?)?.encode(encoder)?;
-        self.encode_value(encoder)
+        encoder.tlv(
+            self.tag(),
+            || self.value_len(),
+            |encoder| self.encode_value(encoder),
+        )
     }
 }
 
@@ -193,7 +219,7 @@ where
             } => Ok(Self {
                 tag_number: number,
                 tag_mode: TagMode::default(),
-                value: T::from_der(any.value())?,
+                value: any.decode_nested(T::decode)?,
             }),
             tag => Err(tag.unexpected_error(None)),
         }
@@ -203,7 +229,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::ContextSpecific;
-    use crate::{asn1::BitString, Decodable, Decoder, Encodable, TagMode, TagNumber};
+    use crate::{
+        asn1::BitString, Decodable, Decoder, Encodable, ErrorKind, Tag, TagMode, TagNumber,
+    };
     use hex_literal::hex;
 
     // Public key data from `pkcs8` crate's `ed25519-pkcs8-v2.der`
@@ -295,4 +323,57 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn optional_context_specific_with_default() {
+        const DEFAULT: u8 = 1;
+
+        // Present with a non-default value
+        let mut decoder = Decoder::new(&hex!("A003020102"));
+        let field = decoder.decode::<Option<ContextSpecific<u8>>>().unwrap();
+        assert_eq!(field.map(|f| f.tag_number), Some(TagNumber::new(0)));
+        assert_eq!(ContextSpecific::default_or(field, DEFAULT).unwrap(), 2);
+
+        // Absent, followed by a non-context-specific field
+        let mut decoder = Decoder::new(&hex!("020103"));
+        let field = decoder.decode::<Option<ContextSpecific<u8>>>().unwrap();
+        assert_eq!(field, None);
+        assert_eq!(
+            ContextSpecific::default_or(field, DEFAULT).unwrap(),
+            DEFAULT
+        );
+
+        // Absent at the end of the message
+        let mut decoder = Decoder::new(&[]);
+        let field = decoder.decode::<Option<ContextSpecific<u8>>>().unwrap();
+        assert_eq!(
+            ContextSpecific::default_or(field, DEFAULT).unwrap(),
+            DEFAULT
+        );
+    }
+
+    #[test]
+    fn optional_context_specific_with_default_malformed() {
+        // Inner value is not an INTEGER
+        let mut decoder = Decoder::new(&hex!("A0020500"));
+        assert!(decoder.decode::<Option<ContextSpecific<u8>>>().is_err());
+
+        // Inner value is truncated
+        let mut decoder = Decoder::new(&hex!("A00302"));
+        assert!(decoder.decode::<Option<ContextSpecific<u8>>>().is_err());
+
+        // Present but explicitly encoding the DEFAULT value
+        let mut decoder = Decoder::new(&hex!("A003020101"));
+        let field = decoder.decode::<Option<ContextSpecific<u8>>>().unwrap();
+        let err = ContextSpecific::default_or(field, 1).err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::new(0)
+                }
+            }
+        );
+    }
 }
@@ -3,9 +3,10 @@
 use crate::{
     asn1::Any,
     datetime::{self, DateTime},
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+    ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, Header, Length,
+    Result, Tag, Tagged,
 };
-use core::{convert::TryFrom, time::Duration};
+use core::{convert::TryFrom, fmt, time::Duration};
 
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -46,6 +47,68 @@ impl GeneralizedTime {
         self.0
     }
 
+    /// Decode a [`GeneralizedTime`] which may include an explicit time zone
+    /// offset (i.e. `YYYYMMDDHHMMSS+hhmm` or `YYYYMMDDHHMMSS-hhmm`) in place
+    /// of `Z`, normalizing it to UTC.
+    ///
+    /// The seconds may also be omitted (i.e. `YYYYMMDDHHMMZ`), in which case
+    /// they default to zero.
+    ///
+    /// Such offsets and truncated times are permitted by BER but not DER, so
+    /// the [`Decodable`] impl for this type only accepts Z-normalized times
+    /// which include seconds.
+    pub fn decode_lenient(decoder: &mut Decoder<'_>) -> Result<Self> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+
+        match *ByteSlice::decode_value(decoder, header.length)?.as_bytes() {
+            [ref datetime @ .., b'Z'] => {
+                Self::decode_datetime_lenient(datetime).and_then(Self::new)
+            }
+            [ref datetime @ .., sign, hour1, hour2, min1, min2] => {
+                let local_time = Self::decode_datetime_lenient(datetime)?;
+                let offset = [sign, hour1, hour2, min1, min2];
+                datetime::apply_offset(Self::TAG, local_time, offset).and_then(Self::new)
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+
+    /// Decode the `YYYYMMDDHHMMSS` portion of a [`GeneralizedTime`] as a
+    /// [`Duration`] since `UNIX_EPOCH`.
+    fn decode_datetime(bytes: &[u8]) -> Result<Duration> {
+        match *bytes {
+            // RFC 5280 requires mandatory seconds
+            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+                let year = datetime::decode_decimal(Self::TAG, y1, y2)? * 100
+                    + datetime::decode_decimal(Self::TAG, y3, y4)?;
+                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
+                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
+                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
+                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
+                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
+
+                DateTime::new(year, month, day, hour, minute, second)
+                    .and_then(|dt| dt.unix_duration())
+                    .map_err(|_| Self::TAG.value_error())
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+
+    /// Decode the `YYYYMMDDHHMMSS` portion of a [`GeneralizedTime`], also
+    /// accepting `YYYYMMDDHHMM` with the seconds omitted.
+    fn decode_datetime_lenient(bytes: &[u8]) -> Result<Duration> {
+        match *bytes {
+            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2] => {
+                Self::decode_datetime(&[
+                    y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, b'0', b'0',
+                ])
+            }
+            _ => Self::decode_datetime(bytes),
+        }
+    }
+
     /// Instantiate from [`SystemTime`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -66,21 +129,8 @@ impl GeneralizedTime {
 impl DecodeValue<'_> for GeneralizedTime {
     fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
         match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, y1, y2)? * 100
-                    + datetime::decode_decimal(Self::TAG, y3, y4)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .and_then(|dt| dt.unix_duration())
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(Self::new)
-            }
+            // RFC 5280 requires Z-normalized time zone
+            [ref datetime @ .., b'Z'] => Self::decode_datetime(datetime).and_then(Self::new),
             _ => Err(Self::TAG.value_error()),
         }
     }
@@ -129,6 +179,14 @@ impl TryFrom<Any<'_>> for GeneralizedTime {
     }
 }
 
+impl fmt::Display for GeneralizedTime {
+    /// Render this time in ISO 8601 format, i.e. `YYYY-MM-DDThh:mm:ssZ`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = DateTime::from_unix_duration(self.0).map_err(|_| fmt::Error)?;
+        fmt::Display::fmt(&datetime, f)
+    }
+}
+
 impl Tagged for GeneralizedTime {
     const TAG: Tag = Tag::GeneralizedTime;
 }
@@ -163,8 +221,8 @@ impl Tagged for SystemTime {
 
 #[cfg(test)]
 mod tests {
-    use super::GeneralizedTime;
-    use crate::{Decodable, Encodable, Encoder};
+    use super::{DateTime, GeneralizedTime};
+    use crate::{Decodable, Decoder, Encodable, Encoder};
     use hex_literal::hex;
 
     #[test]
@@ -178,4 +236,74 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_iso8601() {
+        use alloc::string::ToString;
+        let example_bytes = hex!("18 0f 39 39 39 39 31 32 33 31 32 33 35 39 35 39 5a");
+        let generalized_time = GeneralizedTime::from_der(&example_bytes).unwrap();
+        assert_eq!(generalized_time.to_string(), "9999-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn decode_lenient_with_offset() {
+        // 1991-05-06T23:45:40+0500 == 1991-05-06T18:45:40Z
+        let der = b"\x18\x1319910506234540+0500";
+        let time = GeneralizedTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573540 - 5 * 3600);
+
+        // 1991-05-06T23:45:40-0800 == 1991-05-07T07:45:40Z (next day)
+        let der = b"\x18\x1319910506234540-0800";
+        let time = GeneralizedTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573540 + 8 * 3600);
+        assert_eq!(
+            DateTime::from_unix_duration(time.unix_duration()).unwrap(),
+            DateTime::new(1991, 5, 7, 7, 45, 40).unwrap()
+        );
+
+        // Z-normalized times are still accepted
+        let der = hex!("18 0f 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let time = GeneralizedTime::decode_lenient(&mut Decoder::new(&der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573540);
+    }
+
+    #[test]
+    fn decode_lenient_without_seconds() {
+        // 1991-05-06T23:45:00Z
+        let der = b"\x18\x0d199105062345Z";
+        let time = GeneralizedTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573500);
+
+        // Seconds may be omitted alongside an explicit offset
+        let der = b"\x18\x11199105062345+0500";
+        let time = GeneralizedTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573500 - 5 * 3600);
+    }
+
+    #[test]
+    fn reject_missing_seconds_by_default() {
+        let der = b"\x18\x0d199105062345Z";
+        assert!(GeneralizedTime::from_der(der).is_err());
+        assert!(GeneralizedTime::decode(&mut Decoder::new_strict(der)).is_err());
+
+        // Neither lenient nor strict decoding accepts partial seconds
+        let der = b"\x18\x0e1991050623454Z";
+        assert!(GeneralizedTime::decode_lenient(&mut Decoder::new(der)).is_err());
+    }
+
+    #[test]
+    fn reject_offset_by_default() {
+        let der = b"\x18\x1319910506234540+0500";
+        assert!(GeneralizedTime::from_der(der).is_err());
+
+        // Invalid offsets
+        for der in &[
+            &b"\x18\x1319910506234540*0500"[..],
+            &b"\x18\x1319910506234540+2400"[..],
+            &b"\x18\x1319910506234540+0060"[..],
+        ] {
+            assert!(GeneralizedTime::decode_lenient(&mut Decoder::new(der)).is_err());
+        }
+    }
 }
@@ -8,7 +8,10 @@ use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag,
     Tagged,
 };
-use core::convert::TryFrom;
+use core::{
+    convert::TryFrom,
+    num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8},
+};
 
 macro_rules! impl_int_encoding {
     ($($int:ty => $uint:ty),+) => {
@@ -20,7 +23,14 @@ macro_rules! impl_int_encoding {
                     let result = if is_highest_bit_set(bytes) {
                         <$uint>::from_be_bytes(int::decode_to_array(bytes)?) as $int
                     } else {
-                        Self::from_be_bytes(uint::decode_to_array(bytes)?)
+                        let result = Self::from_be_bytes(uint::decode_to_array(bytes)?);
+
+                        // Positive values which overflow into the sign bit
+                        if result < 0 {
+                            return Err(Self::TAG.value_error());
+                        }
+
+                        result
                     };
 
                     // Ensure we compute the same encoded length as the original any value
@@ -71,7 +81,11 @@ macro_rules! impl_uint_encoding {
             impl<'a> DecodeValue<'a> for $uint {
                 fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
                     let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
-                    let result = Self::from_be_bytes(uint::decode_to_array(bytes)?);
+
+                    let result = match uint::decode_small(bytes) {
+                        Some(value) => Self::try_from(value).map_err(|_| Self::TAG.value_error())?,
+                        None => Self::from_be_bytes(uint::decode_to_array(bytes)?),
+                    };
 
                     // Ensure we compute the same encoded length as the original any value
                     // if any.encoded_len()? != result.encoded_len()? {
@@ -107,8 +121,51 @@ macro_rules! impl_uint_encoding {
     };
 }
 
+/// Positive `INTEGER`s, which reject zero when decoded.
+macro_rules! impl_nonzero_encoding {
+    ($($nonzero:ty => $uint:ty),+) => {
+        $(
+            impl<'a> DecodeValue<'a> for $nonzero {
+                fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+                    <$uint>::decode_value(decoder, length)
+                        .and_then(|value| Self::new(value).ok_or_else(|| Self::TAG.value_error()))
+                }
+            }
+
+            impl EncodeValue for $nonzero {
+                fn value_len(&self) -> Result<Length> {
+                    self.get().value_len()
+                }
+
+                fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+                    self.get().encode_value(encoder)
+                }
+            }
+
+            impl Tagged for $nonzero {
+                const TAG: Tag = Tag::Integer;
+            }
+
+            impl TryFrom<Any<'_>> for $nonzero {
+                type Error = Error;
+
+                fn try_from(any: Any<'_>) -> Result<Self> {
+                    any.decode_into()
+                }
+            }
+        )+
+    };
+}
+
 impl_int_encoding!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
 impl_uint_encoding!(u8, u16, u32, u64, u128);
+impl_nonzero_encoding!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128
+);
 
 /// Is the highest bit of the first byte in the slice 1? (if present)
 #[inline]
@@ -121,7 +178,9 @@ fn is_highest_bit_set(bytes: &[u8]) -> bool {
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use crate::{Decodable, Encodable};
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
+    use core::num::{NonZeroU32, NonZeroU64};
+    use hex_literal::hex;
 
     // Vectors from Section 5.7 of:
     // https://luca.ntop.org/Teaching/Appunti/asn1.html
@@ -175,6 +234,34 @@ pub(crate) mod tests {
         assert_eq!(65535, u16::from_der(I65535_BYTES).unwrap());
     }
 
+    #[test]
+    fn decode_nonzero_u32() {
+        assert_eq!(
+            NonZeroU32::new(65535),
+            Some(NonZeroU32::from_der(I65535_BYTES).unwrap())
+        );
+
+        let err = NonZeroU32::from_der(I0_BYTES).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn decode_nonzero_u64() {
+        assert_eq!(
+            NonZeroU64::new(128),
+            Some(NonZeroU64::from_der(I128_BYTES).unwrap())
+        );
+        assert!(NonZeroU64::from_der(I0_BYTES).is_err());
+        assert!(NonZeroU64::from_der(INEG128_BYTES).is_err());
+    }
+
+    #[test]
+    fn encode_nonzero_u32() {
+        let mut buffer = [0u8; 5];
+        let value = NonZeroU32::new(65535).unwrap();
+        assert_eq!(I65535_BYTES, value.encode_to_slice(&mut buffer).unwrap());
+    }
+
     #[test]
     fn encode_i8() {
         let mut buffer = [0u8; 3];
@@ -234,6 +321,116 @@ pub(crate) mod tests {
         assert_eq!(I65535_BYTES, 65535u16.encode_to_slice(&mut buffer).unwrap());
     }
 
+    #[test]
+    fn i128_boundaries() {
+        let vectors: &[(i128, &[u8])] = &[
+            (0, I0_BYTES),
+            (-1, &[0x02, 0x01, 0xFF]),
+            (i128::MAX, &hex!("02 10 7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")),
+            (i128::MIN, &hex!("02 10 80000000000000000000000000000000")),
+            (
+                i128::MIN + 1,
+                &hex!("02 10 80000000000000000000000000000001"),
+            ),
+            (i64::MIN as i128 - 1, &hex!("02 09 FF7FFFFFFFFFFFFFFF")),
+        ];
+
+        let mut buffer = [0u8; 18];
+
+        for &(value, bytes) in vectors {
+            assert_eq!(bytes, value.encode_to_slice(&mut buffer).unwrap());
+            assert_eq!(value, i128::from_der(bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn u128_boundaries() {
+        let vectors: &[(u128, &[u8])] = &[
+            (0, I0_BYTES),
+            (u128::MAX, &hex!("02 11 00FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF")),
+            (
+                i128::MAX as u128 + 1,
+                &hex!("02 11 0080000000000000000000000000000000"),
+            ),
+            (
+                i128::MAX as u128,
+                &hex!("02 10 7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"),
+            ),
+        ];
+
+        let mut buffer = [0u8; 19];
+
+        for &(value, bytes) in vectors {
+            assert_eq!(bytes, value.encode_to_slice(&mut buffer).unwrap());
+            assert_eq!(value, u128::from_der(bytes).unwrap());
+        }
+    }
+
+    /// Values which don't fit in the target type must be rejected
+    #[test]
+    fn reject_overflow() {
+        // i128::MAX + 1
+        let bytes = hex!("02 11 0080000000000000000000000000000000");
+        assert!(i128::from_der(&bytes).is_err());
+
+        // i128::MIN - 1
+        let bytes = hex!("02 11 FF7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        assert!(i128::from_der(&bytes).is_err());
+
+        // u128::MAX + 1
+        let bytes = hex!("02 11 0100000000000000000000000000000000");
+        assert!(u128::from_der(&bytes).is_err());
+
+        assert!(i8::from_der(I128_BYTES).is_err());
+        assert!(i8::from_der(INEG129_BYTES).is_err());
+        assert!(u8::from_der(I256_BYTES).is_err());
+    }
+
+    /// The small integer fast path must agree with the general path on all
+    /// values and rejections.
+    #[test]
+    fn decode_small_matches_general_path() {
+        use super::uint;
+        use core::convert::TryFrom;
+
+        let suffixes: &[&[u8]] = &[&[], &[0xFF], &[0x00, 0x80], &[0x01, 0x02, 0x03]];
+
+        for b0 in 0..=255u8 {
+            for b1 in 0..=255u8 {
+                for suffix in suffixes {
+                    let mut der = [0u8; 7];
+                    der[2] = b0;
+                    der[3] = b1;
+                    der[4..(4 + suffix.len())].copy_from_slice(suffix);
+
+                    for len in 0..=(2 + suffix.len()) {
+                        let bytes = &der[2..(2 + len)];
+                        let general = uint::decode_to_array::<4>(bytes).map(u32::from_be_bytes);
+
+                        if let Some(value) = uint::decode_small(bytes) {
+                            assert_eq!(general, Ok(value), "{:02X?}", bytes);
+                        }
+
+                        let mut der = der;
+                        der[0] = 0x02;
+                        der[1] = len as u8;
+                        let der = &der[..(2 + len)];
+
+                        let general = general.map_err(|e| e.kind());
+                        assert_eq!(u32::from_der(der).map_err(|e| e.kind()), general);
+                        assert_eq!(
+                            u8::from_der(der).map_err(|e| e.kind()),
+                            general.and_then(|value| {
+                                u8::try_from(value)
+                                    .map_err(|_| ErrorKind::Value { tag: Tag::Integer })
+                            })
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     /// Integers must be encoded with a minimum number of octets
     #[test]
     fn reject_non_canonical() {
@@ -241,5 +438,7 @@ pub(crate) mod tests {
         assert!(i16::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(u8::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
         assert!(u16::from_der(&[0x02, 0x02, 0x00, 0x00]).is_err());
+        assert!(i16::from_der(&[0x02, 0x02, 0xFF, 0x80]).is_err());
+        assert!(i128::from_der(&[0x02, 0x02, 0xFF, 0xFF]).is_err());
     }
 }
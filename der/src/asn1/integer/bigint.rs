@@ -1,6 +1,6 @@
 //! "Big" ASN.1 `INTEGER` types.
 
-use super::uint;
+use super::{int, uint};
 use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
     Result, Tag, Tagged,
@@ -20,6 +20,12 @@ use {
 ///
 /// Intended for use cases like very large integers that are used in
 /// cryptographic applications (e.g. keys, signatures).
+///
+/// ASN.1 `INTEGER`s are signed, so DER requires a leading `0x00` byte when
+/// the most significant bit of an unsigned value is set (e.g. an RSA modulus).
+/// This is handled automatically: the leading `0x00` is added when encoding
+/// and stripped when decoding, so [`UIntBytes::as_bytes`] always returns the
+/// big endian value itself.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
 pub struct UIntBytes<'a> {
     /// Inner value
@@ -27,20 +33,49 @@ pub struct UIntBytes<'a> {
 }
 
 impl<'a> UIntBytes<'a> {
-    /// Create a new [`UIntBytes`] from a byte slice.
+    /// Create a new [`UIntBytes`] from a big endian byte slice.
+    ///
+    /// Any leading zeroes are stripped. Returns an error if the slice is empty.
     pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(ErrorKind::Length { tag: Self::TAG }.into());
+        }
+
         let inner = ByteSlice::new(uint::strip_leading_zeroes(bytes))
             .map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
 
         Ok(Self { inner })
     }
 
+    /// Create a new [`UIntBytes`] from a fixed-width big endian byte array
+    /// which is left-padded with zeroes.
+    ///
+    /// The leading zeroes are stripped as with [`UIntBytes::new`].
+    pub fn from_be_bytes_padded<const N: usize>(bytes: &'a [u8; N]) -> Result<Self> {
+        Self::new(bytes)
+    }
+
     /// Borrow the inner byte slice which contains the least significant bytes
     /// of a big endian integer value with all leading zeros stripped.
     pub fn as_bytes(&self) -> &'a [u8] {
         self.inner.as_bytes()
     }
 
+    /// Copy this value into a fixed-width big endian byte array, left-padded
+    /// with zeroes.
+    ///
+    /// Returns an error if the value doesn't fit into `N` bytes.
+    pub fn to_be_bytes_padded<const N: usize>(&self) -> Result<[u8; N]> {
+        let bytes = self.as_bytes();
+        let offset = N
+            .checked_sub(bytes.len())
+            .ok_or_else(|| Self::TAG.value_error())?;
+
+        let mut output = [0u8; N];
+        output[offset..].copy_from_slice(bytes);
+        Ok(output)
+    }
+
     /// Get the length of this [`UIntBytes`] in bytes.
     pub fn len(&self) -> Length {
         self.inner.len()
@@ -99,6 +134,97 @@ impl<'a> Tagged for UIntBytes<'a> {
     const TAG: Tag = Tag::Integer;
 }
 
+/// "Big" signed ASN.1 `INTEGER` type.
+///
+/// Provides direct access to the underlying big endian two's complement bytes
+/// which comprise a signed integer value, including the sign byte.
+///
+/// Intended for use cases like arbitrary-width signed integers which don't
+/// fit in an `i128`, e.g. certificate serial numbers.
+///
+/// DER requires integers to be minimally encoded, so a leading `0x00` byte
+/// is only permitted when the next byte has its highest bit set, and a
+/// leading `0xFF` byte only when it has its highest bit clear.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
+pub struct IntBytes<'a> {
+    /// Inner value
+    inner: ByteSlice<'a>,
+}
+
+impl<'a> IntBytes<'a> {
+    /// Create a new [`IntBytes`] from a big endian two's complement byte
+    /// slice.
+    ///
+    /// Any redundant leading sign bytes are stripped. Returns an error if the
+    /// slice is empty.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(ErrorKind::Length { tag: Self::TAG }.into());
+        }
+
+        let inner = ByteSlice::new(int::strip_redundant_sign_bytes(bytes))
+            .map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
+
+        Ok(Self { inner })
+    }
+
+    /// Borrow the inner byte slice which contains the minimal big endian
+    /// two's complement encoding of the integer value.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_bytes()
+    }
+
+    /// Get the length of this [`IntBytes`] in bytes.
+    pub fn len(&self) -> Length {
+        self.inner.len()
+    }
+
+    /// Is the inner byte slice empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Is this integer value negative?
+    pub fn is_negative(&self) -> bool {
+        super::is_highest_bit_set(self.as_bytes())
+    }
+}
+
+impl<'a> DecodeValue<'a> for IntBytes<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let bytes = ByteSlice::decode_value(decoder, length)?.as_bytes();
+        Self::new(int::decode_to_slice(bytes)?)
+    }
+}
+
+impl<'a> EncodeValue for IntBytes<'a> {
+    fn value_len(&self) -> Result<Length> {
+        Ok(self.len())
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.as_bytes())
+    }
+}
+
+impl<'a> From<&IntBytes<'a>> for IntBytes<'a> {
+    fn from(value: &IntBytes<'a>) -> IntBytes<'a> {
+        *value
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for IntBytes<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<IntBytes<'a>> {
+        any.decode_into()
+    }
+}
+
+impl<'a> Tagged for IntBytes<'a> {
+    const TAG: Tag = Tag::Integer;
+}
+
 #[cfg(feature = "bigint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
 impl<'a, const LIMBS: usize> TryFrom<Any<'a>> for UInt<LIMBS>
@@ -157,7 +283,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::UIntBytes;
+    use super::{IntBytes, UIntBytes};
     use crate::{
         asn1::{integer::tests::*, Any},
         Decodable, Encodable, Encoder, ErrorKind, Tag,
@@ -203,6 +329,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn encode_uint_bytes_with_high_bit_set() {
+        let modulus = [0x80, 0x01, 0x02, 0x03];
+        let uint = UIntBytes::new(&modulus).unwrap();
+        assert_eq!(uint.as_bytes(), &modulus);
+
+        let mut buf = [0u8; 8];
+        let der = uint.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, &[0x02, 0x05, 0x00, 0x80, 0x01, 0x02, 0x03]);
+        assert_eq!(UIntBytes::from_der(der).unwrap().as_bytes(), &modulus);
+
+        // Explicitly zero-prefixed input is encoded identically
+        let prefixed = UIntBytes::new(&[0x00, 0x80, 0x01, 0x02, 0x03]).unwrap();
+        assert_eq!(prefixed, uint);
+    }
+
+    #[test]
+    fn encode_uint_bytes_with_high_bit_clear() {
+        let modulus = [0x7F, 0x01, 0x02, 0x03];
+        let uint = UIntBytes::new(&modulus).unwrap();
+
+        let mut buf = [0u8; 8];
+        let der = uint.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, &[0x02, 0x04, 0x7F, 0x01, 0x02, 0x03]);
+        assert_eq!(UIntBytes::from_der(der).unwrap().as_bytes(), &modulus);
+    }
+
+    #[test]
+    fn uint_bytes_to_be_bytes_padded() {
+        let uint = UIntBytes::new(&[0x01, 0x02]).unwrap();
+        assert_eq!(uint.to_be_bytes_padded::<4>().unwrap(), [0, 0, 0x01, 0x02]);
+        assert_eq!(uint.to_be_bytes_padded::<2>().unwrap(), [0x01, 0x02]);
+
+        let err = uint.to_be_bytes_padded::<1>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn uint_bytes_from_be_bytes_padded() {
+        let padded = [0, 0, 0x80, 0x01];
+        let uint = UIntBytes::from_be_bytes_padded(&padded).unwrap();
+        assert_eq!(uint.as_bytes(), &[0x80, 0x01]);
+        assert_eq!(uint.to_be_bytes_padded::<4>().unwrap(), padded);
+
+        let unpadded = [0x7F, 0x01];
+        let uint = UIntBytes::from_be_bytes_padded(&unpadded).unwrap();
+        assert_eq!(uint.as_bytes(), &unpadded);
+        assert_eq!(
+            UIntBytes::from_be_bytes_padded(&[0u8; 4])
+                .unwrap()
+                .as_bytes(),
+            &[0]
+        );
+    }
+
+    #[test]
+    fn reject_empty_uint_bytes() {
+        let err = UIntBytes::new(&[]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Integer });
+    }
+
     #[test]
     fn reject_oversize_without_extra_zero() {
         let err = UIntBytes::try_from(Any::new(Tag::Integer, &[0x81]).unwrap())
@@ -211,4 +398,84 @@ mod tests {
 
         assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Integer });
     }
+
+    #[test]
+    fn decode_int_bytes() {
+        assert_eq!(&[0], IntBytes::from_der(I0_BYTES).unwrap().as_bytes());
+        assert_eq!(&[0x7F], IntBytes::from_der(I127_BYTES).unwrap().as_bytes());
+        assert_eq!(
+            &[0x00, 0x80],
+            IntBytes::from_der(I128_BYTES).unwrap().as_bytes()
+        );
+        assert_eq!(
+            &[0x80],
+            IntBytes::from_der(INEG128_BYTES).unwrap().as_bytes()
+        );
+        assert_eq!(
+            &[0xFF, 0x7F],
+            IntBytes::from_der(INEG129_BYTES).unwrap().as_bytes()
+        );
+    }
+
+    #[test]
+    fn encode_int_bytes() {
+        for &example in &[
+            I0_BYTES,
+            I127_BYTES,
+            I128_BYTES,
+            I256_BYTES,
+            INEG128_BYTES,
+            INEG129_BYTES,
+            INEG32768_BYTES,
+        ] {
+            let int = IntBytes::from_der(example).unwrap();
+
+            let mut buf = [0u8; 128];
+            let mut encoder = Encoder::new(&mut buf);
+            int.encode(&mut encoder).unwrap();
+
+            let result = encoder.finish().unwrap();
+            assert_eq!(example, result);
+        }
+    }
+
+    #[test]
+    fn int_bytes_negative_value() {
+        // -2^128, which doesn't fit in an `i128`
+        let mut der = [0u8; 19];
+        der[..3].copy_from_slice(&[0x02, 0x11, 0xFF]);
+        let int = IntBytes::from_der(&der).unwrap();
+        assert!(int.is_negative());
+        assert_eq!(int.as_bytes(), &der[2..]);
+
+        // Redundant leading sign bytes are stripped
+        let stripped = IntBytes::new(&[0xFF, 0xFF, 0x80, 0x00]).unwrap();
+        assert_eq!(stripped.as_bytes(), &[0x80, 0x00]);
+        assert!(stripped.is_negative());
+    }
+
+    #[test]
+    fn int_bytes_large_positive_value() {
+        // 2^135, which requires a leading zero to remain positive
+        let mut value = [0u8; 18];
+        value[1] = 0x80;
+        let int = IntBytes::new(&value).unwrap();
+        assert!(!int.is_negative());
+
+        let mut buf = [0u8; 32];
+        let der = int.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(&der[..4], &[0x02, 0x12, 0x00, 0x80]);
+        assert_eq!(IntBytes::from_der(der).unwrap(), int);
+    }
+
+    #[test]
+    fn reject_non_canonical_int_bytes() {
+        for &bytes in &[&[0x00, 0x7F][..], &[0xFF, 0x80][..], &[][..]] {
+            let err = IntBytes::try_from(Any::new(Tag::Integer, bytes).unwrap())
+                .err()
+                .unwrap();
+
+            assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Integer });
+        }
+    }
 }
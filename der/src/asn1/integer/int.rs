@@ -1,19 +1,55 @@
 //! Support for encoding negative integers
 
 use super::is_highest_bit_set;
-use crate::{Encoder, ErrorKind, Length, Result};
+use crate::{Encoder, Length, Result, Tag};
 use core::convert::TryFrom;
 
 /// Decode an unsigned integer of the specified size.
 ///
 /// Returns a byte array of the requested size containing a big endian integer.
 pub(super) fn decode_to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
-    let offset = N.checked_sub(bytes.len()).ok_or(ErrorKind::Truncated)?;
+    // A leading `0xFF` byte is redundant if the next byte has its highest bit
+    // set, i.e. the same value can be encoded with fewer octets.
+    if let [0xFF, byte, ..] = bytes {
+        if *byte >= 0x80 {
+            return Err(Tag::Integer.non_canonical_error());
+        }
+    }
+
+    let offset = N
+        .checked_sub(bytes.len())
+        .ok_or_else(|| Tag::Integer.value_error())?;
+
     let mut output = [0xFFu8; N];
     output[offset..].copy_from_slice(bytes);
     Ok(output)
 }
 
+/// Decode a signed integer into a big endian two's complement byte slice,
+/// ensuring it is minimally encoded.
+pub(super) fn decode_to_slice(bytes: &[u8]) -> Result<&[u8]> {
+    // A leading `0x00` (or `0xFF`) byte is redundant if the next byte has its
+    // highest bit clear (or set), i.e. it only repeats the sign bit.
+    match bytes {
+        [] => Err(Tag::Integer.non_canonical_error()),
+        [0x00, byte, ..] if *byte < 0x80 => Err(Tag::Integer.non_canonical_error()),
+        [0xFF, byte, ..] if *byte >= 0x80 => Err(Tag::Integer.non_canonical_error()),
+        _ => Ok(bytes),
+    }
+}
+
+/// Strip the redundant leading sign bytes (`0x00` for positive, `0xFF` for
+/// negative values) from the given big endian two's complement byte slice.
+pub(super) fn strip_redundant_sign_bytes(mut bytes: &[u8]) -> &[u8] {
+    loop {
+        match bytes {
+            [0x00, byte, ..] if *byte < 0x80 => bytes = &bytes[1..],
+            [0xFF, byte, ..] if *byte >= 0x80 => bytes = &bytes[1..],
+            _ => return bytes,
+        }
+    }
+}
+
 /// Encode the given big endian bytes representing an integer as ASN.1 DER.
 pub(super) fn encode_bytes(encoder: &mut Encoder<'_>, bytes: &[u8]) -> Result<()> {
     encoder.bytes(strip_leading_ones(bytes))
@@ -24,14 +24,37 @@ pub(super) fn decode_to_slice(bytes: &[u8]) -> Result<&[u8]> {
     }
 }
 
+/// Fast path for decoding the common case of a small unsigned integer
+/// encoded in 1-4 bytes, e.g. `version` and `keyLength` fields.
+///
+/// Only inputs whose first byte is neither a sign bit nor a leading zero
+/// (other than the single byte `0`) are handled, which are always minimally
+/// encoded and non-negative. Returns `None` for all other inputs, which must
+/// be decoded (or rejected) by the general path, so results are identical.
+#[inline]
+pub(super) fn decode_small(bytes: &[u8]) -> Option<u32> {
+    match *bytes {
+        [b0] if b0 < 0x80 => Some(b0 as u32),
+        [b0, ref rest @ ..] if (0x01..0x80).contains(&b0) && rest.len() <= 3 => Some(
+            rest.iter()
+                .fold(b0 as u32, |acc, &byte| (acc << 8) | byte as u32),
+        ),
+        _ => None,
+    }
+}
+
 /// Decode an unsigned integer into a byte array of the requested size
 /// containing a big endian integer.
 pub(super) fn decode_to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
     let input = decode_to_slice(bytes)?;
 
     // Input has leading zeroes removed, so we need to add them back
+    let offset = N
+        .checked_sub(input.len())
+        .ok_or_else(|| Tag::Integer.value_error())?;
+
     let mut output = [0u8; N];
-    output[N.saturating_sub(input.len())..].copy_from_slice(input);
+    output[offset..].copy_from_slice(input);
     Ok(output)
 }
 
@@ -7,9 +7,19 @@ use crate::{
 use core::convert::TryFrom;
 
 /// ASN.1 `NULL` type.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+///
+/// Equivalent to `()`, but provides a named type which can be used to make
+/// the intent clearer, e.g. for the parameters of an `AlgorithmIdentifier`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Null;
 
+impl Null {
+    /// Create a new [`Null`] value.
+    pub const fn new() -> Self {
+        Null
+    }
+}
+
 impl DecodeValue<'_> for Null {
     fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
         if length.is_zero() {
@@ -86,13 +96,35 @@ impl Tagged for () {
 #[cfg(test)]
 mod tests {
     use super::Null;
-    use crate::{Decodable, Encodable};
+    use crate::{asn1::Any, Decodable, Encodable, ErrorKind, Tag};
+    use core::convert::TryFrom;
 
     #[test]
     fn decode() {
         Null::from_der(&[0x05, 0x00]).unwrap();
     }
 
+    #[test]
+    fn round_trip() {
+        let mut buffer = [0u8; 2];
+        let der = Null::new().encode_to_slice(&mut buffer).unwrap();
+        assert_eq!(der, &[0x05, 0x00]);
+        assert_eq!(Null::from_der(der).unwrap(), Null::new());
+    }
+
+    #[test]
+    fn parameters_value() {
+        let any = Any::from(Null::new());
+        assert_eq!(any, Any::new(Tag::Null, &[]).unwrap());
+        assert_eq!(Null::try_from(any).unwrap(), Null);
+    }
+
+    #[test]
+    fn reject_non_empty_body() {
+        let err = Null::from_der(&[0x05, 0x01, 0x00]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Null });
+    }
+
     #[test]
     fn encode() {
         let mut buffer = [0u8; 2];
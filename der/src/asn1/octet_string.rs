@@ -1,10 +1,19 @@
 //! ASN.1 `OCTET STRING` support.
 
 use crate::{
-    asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, Length,
-    Result, Tag, Tagged,
+    asn1::Any, ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind,
+    Header, Length, Result, Tag, Tagged,
+};
+use core::{convert::TryFrom, slice::Chunks};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "std")]
+use {
+    core::convert::TryInto,
+    std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
-use core::convert::TryFrom;
 
 /// ASN.1 `OCTET STRING` type.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -35,8 +44,101 @@ impl<'a> OctetString<'a> {
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
     }
+
+    /// Iterate over the value of this `OCTET STRING` in chunks of at most
+    /// `chunk_size` bytes, borrowed directly from the backing buffer.
+    ///
+    /// The last chunk will be shorter if the length is not a multiple of
+    /// `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'a, u8> {
+        self.as_bytes().chunks(chunk_size)
+    }
+
+    /// Decode an [`OctetString`] which may use the BER constructed encoding,
+    /// copying its value into `buffer`.
+    ///
+    /// BER permits an `OCTET STRING` to be split into a number of primitive
+    /// segments enclosed in a constructed value with either a definite or an
+    /// indefinite length. The segments are concatenated into a contiguous
+    /// value, which is encoded as a single primitive `OCTET STRING` in
+    /// canonical DER. Primitive `OCTET STRING`s are also accepted.
+    ///
+    /// Returns [`ErrorKind::Overlength`] if `buffer` is too small to hold the
+    /// concatenated value.
+    pub fn decode_lenient<'o>(
+        decoder: &mut Decoder<'_>,
+        buffer: &'o mut [u8],
+    ) -> Result<OctetString<'o>> {
+        let mut len = 0;
+
+        match decoder.peek() {
+            Some(CONSTRUCTED_TAG) => {
+                decoder.byte()?;
+
+                if decoder.peek() == Some(INDEFINITE_LENGTH) {
+                    decoder.byte()?;
+
+                    // Segments are terminated by an end-of-contents marker
+                    while decoder.peek() != Some(0) {
+                        len = Self::decode_segment(decoder, buffer, len)?;
+                    }
+
+                    if decoder.bytes(2u8)? != [0, 0] {
+                        return Err(decoder.error(ErrorKind::Length { tag: Self::TAG }));
+                    }
+                } else {
+                    let length = Length::decode(decoder)?;
+                    let end = (decoder.position() + length)?;
+
+                    while decoder.position() < end {
+                        len = Self::decode_segment(decoder, buffer, len)?;
+                    }
+
+                    if decoder.position() != end {
+                        return Err(decoder.error(ErrorKind::Length { tag: Self::TAG }));
+                    }
+                }
+            }
+            _ => len = Self::decode_segment(decoder, buffer, len)?,
+        }
+
+        OctetString::new(&buffer[..len])
+    }
+
+    /// Decode a primitive `OCTET STRING` segment, copying its value into
+    /// `buffer` at the given offset and returning the offset of its end.
+    fn decode_segment(
+        decoder: &mut Decoder<'_>,
+        buffer: &mut [u8],
+        offset: usize,
+    ) -> Result<usize> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+
+        let segment = decoder.bytes(header.length)?;
+        let end = offset
+            .checked_add(segment.len())
+            .ok_or(ErrorKind::Overflow)?;
+
+        buffer
+            .get_mut(offset..end)
+            .ok_or(ErrorKind::Overlength)?
+            .copy_from_slice(segment);
+
+        Ok(end)
+    }
 }
 
+/// Identifier octet of a BER constructed `OCTET STRING`.
+const CONSTRUCTED_TAG: u8 = 0x24;
+
+/// Initial length octet of a BER indefinite length.
+const INDEFINITE_LENGTH: u8 = 0x80;
+
 impl AsRef<[u8]> for OctetString<'_> {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -90,3 +192,349 @@ impl<'a> From<OctetString<'a>> for &'a [u8] {
 impl<'a> Tagged for OctetString<'a> {
     const TAG: Tag = Tag::OctetString;
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl DecodeValue<'_> for Vec<u8> {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        OctetString::decode_value(decoder, length)
+            .map(|octet_string| octet_string.as_bytes().to_vec())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for Vec<u8> {
+    fn value_len(&self) -> Result<Length> {
+        OctetString::new(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetString::new(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<Any<'_>> for Vec<u8> {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<Vec<u8>> {
+        OctetString::try_from(any).map(|octet_string| octet_string.as_bytes().to_vec())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for Vec<u8> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+/// Decoding always produces [`Cow::Owned`], so the result can outlive the input.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl DecodeValue<'_> for Cow<'_, [u8]> {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        Vec::decode_value(decoder, length).map(Cow::Owned)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for Cow<'_, [u8]> {
+    fn value_len(&self) -> Result<Length> {
+        OctetString::new(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetString::new(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for Cow<'_, [u8]> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+/// IPv4 address encoded as a 4-byte `OCTET STRING`, e.g. an `iPAddress`
+/// `GeneralName` as described in [RFC 5280 Section 4.2.1.6].
+///
+/// [RFC 5280 Section 4.2.1.6]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.6
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for Ipv4Addr {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        match IpAddr::decode_value(decoder, length)? {
+            IpAddr::V4(addr) => Ok(addr),
+            IpAddr::V6(_) => Err(ErrorKind::Length { tag: Self::TAG }.into()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for Ipv4Addr {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::new(4))
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Tagged for Ipv4Addr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+/// IPv6 address encoded as a 16-byte `OCTET STRING`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for Ipv6Addr {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        match IpAddr::decode_value(decoder, length)? {
+            IpAddr::V6(addr) => Ok(addr),
+            IpAddr::V4(_) => Err(ErrorKind::Length { tag: Self::TAG }.into()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for Ipv6Addr {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::new(16))
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Tagged for Ipv6Addr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+/// IPv4 or IPv6 address encoded as an `OCTET STRING`, distinguished by its
+/// length: 4 bytes for IPv4 and 16 bytes for IPv6. Any other length is
+/// rejected.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for IpAddr {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        let bytes = decoder.bytes(length)?;
+
+        if let Ok(octets) = <[u8; 4]>::try_from(bytes) {
+            Ok(Ipv4Addr::from(octets).into())
+        } else if let Ok(octets) = <[u8; 16]>::try_from(bytes) {
+            Ok(Ipv6Addr::from(octets).into())
+        } else {
+            Err(decoder.error(ErrorKind::Length { tag: Self::TAG }))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for IpAddr {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            IpAddr::V4(addr) => addr.value_len(),
+            IpAddr::V6(addr) => addr.value_len(),
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            IpAddr::V4(addr) => addr.encode_value(encoder),
+            IpAddr::V6(addr) => addr.encode_value(encoder),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Tagged for IpAddr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<Any<'_>> for IpAddr {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<IpAddr> {
+        any.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OctetString;
+    use crate::{Decodable, Decoder, Encodable, ErrorKind};
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "std")]
+    use {
+        crate::Tag,
+        std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    };
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec_round_trip() {
+        let der = &[0x04, 0x03, 0xCA, 0xFE, 0x00];
+        let bytes = {
+            let input = Vec::from(&der[..]);
+            Decoder::new(&input).decode::<Vec<u8>>().unwrap()
+        };
+        assert_eq!(bytes, &[0xCA, 0xFE, 0x00]);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(bytes.encode_to_slice(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cow_round_trip() {
+        use alloc::borrow::Cow;
+
+        let der = &[0x04, 0x03, 0xCA, 0xFE, 0x00];
+        let mut buf = [0u8; 8];
+
+        let borrowed = Cow::Borrowed(&der[2..]);
+        assert_eq!(borrowed.encode_to_slice(&mut buf).unwrap(), der);
+
+        let decoded = Cow::<'static, [u8]>::from_der(der).unwrap();
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, borrowed);
+        assert_eq!(decoded.encode_to_slice(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    fn chunks() {
+        let mut der = [0u8; 516];
+        der[..4].copy_from_slice(&[0x04, 0x82, 0x02, 0x00]);
+
+        for (i, byte) in der[4..].iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let octet_string = OctetString::from_der(&der).unwrap();
+        let mut chunks = octet_string.chunks(64);
+
+        for i in 0..8 {
+            let chunk = chunks.next().unwrap();
+            assert_eq!(chunk.len(), 64);
+            assert_eq!(chunk.as_ptr(), der[(4 + i * 64)..].as_ptr());
+            assert_eq!(chunk, &der[(4 + i * 64)..(4 + (i + 1) * 64)]);
+        }
+
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn decode_lenient_constructed() {
+        let ber = [0x24, 0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x02, 0x03, 0x04];
+        let mut buffer = [0u8; 16];
+        let mut decoder = Decoder::new(&ber);
+        let octet_string = OctetString::decode_lenient(&mut decoder, &mut buffer).unwrap();
+        assert!(decoder.is_finished());
+        assert_eq!(octet_string.as_bytes(), &[0x01, 0x02, 0x03, 0x04]);
+
+        let mut der = [0u8; 8];
+        assert_eq!(
+            octet_string.encode_to_slice(&mut der).unwrap(),
+            &[0x04, 0x04, 0x01, 0x02, 0x03, 0x04]
+        );
+    }
+
+    #[test]
+    fn decode_lenient_indefinite_length() {
+        let ber = [
+            0x24, 0x80, 0x04, 0x02, 0x01, 0x02, 0x04, 0x01, 0x03, 0x00, 0x00,
+        ];
+        let mut buffer = [0u8; 16];
+        let mut decoder = Decoder::new(&ber);
+        let octet_string = OctetString::decode_lenient(&mut decoder, &mut buffer).unwrap();
+        assert!(decoder.is_finished());
+        assert_eq!(octet_string.as_bytes(), &[0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn decode_lenient_primitive() {
+        let der = [0x04, 0x02, 0x01, 0x02];
+        let mut buffer = [0u8; 2];
+        let octet_string =
+            OctetString::decode_lenient(&mut Decoder::new(&der), &mut buffer).unwrap();
+        assert_eq!(octet_string.as_bytes(), &[0x01, 0x02]);
+    }
+
+    #[test]
+    fn decode_lenient_buffer_too_small() {
+        let ber = [0x24, 0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x02, 0x03, 0x04];
+        let mut buffer = [0u8; 3];
+        let err = OctetString::decode_lenient(&mut Decoder::new(&ber), &mut buffer).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ipv4() {
+        let der = [0x04, 0x04, 192, 0, 2, 1];
+        let addr = IpAddr::from_der(&der).unwrap();
+        assert_eq!(addr, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+        assert_eq!(
+            Ipv4Addr::from_der(&der).unwrap(),
+            Ipv4Addr::new(192, 0, 2, 1)
+        );
+
+        let mut buffer = [0u8; 6];
+        assert_eq!(addr.encode_to_slice(&mut buffer).unwrap(), &der);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ipv6() {
+        let mut der = [0u8; 18];
+        der[..4].copy_from_slice(&[0x04, 0x10, 0x20, 0x01]);
+        der[4..6].copy_from_slice(&[0x0d, 0xb8]);
+        der[17] = 1;
+
+        let expected = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let addr = IpAddr::from_der(&der).unwrap();
+        assert_eq!(addr, IpAddr::V6(expected));
+        assert_eq!(Ipv6Addr::from_der(&der).unwrap(), expected);
+
+        let mut buffer = [0u8; 18];
+        assert_eq!(addr.encode_to_slice(&mut buffer).unwrap(), &der);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reject_ip_addr_invalid_length() {
+        let err = IpAddr::from_der(&[0x04, 0x05, 192, 0, 2, 1, 0]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::OctetString
+            }
+        );
+
+        let err = Ipv4Addr::from_der(&[0x04, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::OctetString
+            }
+        );
+    }
+}
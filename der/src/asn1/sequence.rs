@@ -4,8 +4,8 @@ pub(super) mod iter;
 
 use self::iter::SequenceIter;
 use crate::{
-    asn1::Any, ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind,
-    Length, Result, Tag, Tagged,
+    asn1::Any, ByteSlice, Decodable, DecodeValue, Decoder, Encodable, EncodeValue, Encoder, Error,
+    ErrorKind, Length, Result, Tag, Tagged,
 };
 use core::convert::TryFrom;
 
@@ -29,6 +29,11 @@ impl<'a> Sequence<'a> {
         self.inner.as_bytes()
     }
 
+    /// Is the body of this sequence empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Decode values nested within a sequence, creating a new [`Decoder`] for
     /// the data contained in the sequence's body and passing it to the provided
     /// [`FnOnce`].
@@ -86,3 +91,164 @@ impl<'a> TryFrom<Any<'a>> for Sequence<'a> {
 impl<'a> Tagged for Sequence<'a> {
     const TAG: Tag = Tag::Sequence;
 }
+
+/// ASN.1 `SEQUENCE` with an empty body, i.e. `SEQUENCE {}`.
+///
+/// Useful for placeholder fields and for asserting that a structure (e.g. an
+/// extension value) has no contents: decoding fails if the body of the
+/// `SEQUENCE` contains any bytes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct EmptySequence;
+
+impl DecodeValue<'_> for EmptySequence {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        if length.is_zero() {
+            Ok(EmptySequence)
+        } else {
+            Err(decoder.error(ErrorKind::Length { tag: Self::TAG }))
+        }
+    }
+}
+
+impl EncodeValue for EmptySequence {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::ZERO)
+    }
+
+    fn encode_value(&self, _encoder: &mut Encoder<'_>) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> From<EmptySequence> for Any<'a> {
+    fn from(_: EmptySequence) -> Any<'a> {
+        Any::from_tag_and_value(Tag::Sequence, ByteSlice::default())
+    }
+}
+
+impl TryFrom<Any<'_>> for EmptySequence {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<EmptySequence> {
+        any.decode_into()
+    }
+}
+
+impl Tagged for EmptySequence {
+    const TAG: Tag = Tag::Sequence;
+}
+
+/// ASN.1 `SEQUENCE OF` containing exactly `N` elements.
+///
+/// Decoding fails if the `SEQUENCE OF` contains fewer elements (in which case
+/// decoding the missing element fails with e.g. [`ErrorKind::Truncated`]) or
+/// more elements ([`ErrorKind::TrailingData`]) than the array holds.
+impl<'a, T, const N: usize> DecodeValue<'a> for [T; N]
+where
+    T: Decodable<'a> + Default + Copy,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        decoder.read_nested(length, |decoder| decoder.decode_array())
+    }
+}
+
+impl<T, const N: usize> EncodeValue for [T; N]
+where
+    T: Encodable,
+{
+    fn value_len(&self) -> Result<Length> {
+        self.iter()
+            .try_fold(Length::ZERO, |len, elem| len + elem.encoded_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        for elem in self {
+            elem.encode(encoder)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Tagged for [T; N] {
+    const TAG: Tag = Tag::Sequence;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmptySequence, Sequence};
+    use crate::{asn1::Null, Decodable, Encodable, ErrorKind, Tag};
+
+    #[test]
+    fn decode_empty_sequence() {
+        EmptySequence::from_der(&[0x30, 0x00]).unwrap();
+        assert!(Sequence::from_der(&[0x30, 0x00]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn encode_empty_sequence() {
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            &[0x30, 0x00],
+            EmptySequence.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_non_empty_sequence() {
+        let err = EmptySequence::from_der(&[0x30, 0x01, 0x00]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Sequence });
+        assert!(!Sequence::from_der(&[0x30, 0x01, 0x00]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn decode_sequence_of_array() {
+        let der = [
+            0x30, 0x0D, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03, 0x02, 0x02, 0x01,
+            0x00,
+        ];
+        let array = <[u32; 4]>::from_der(&der).unwrap();
+        assert_eq!(array, [1, 2, 3, 256]);
+
+        let mut buffer = [0u8; 15];
+        assert_eq!(&der, array.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn empty_sequence_of() {
+        let seq = Sequence::from_der(&[0x30, 0x00]).unwrap();
+        assert!(seq.iter::<u32>().next().is_none());
+
+        let array = <[u32; 0]>::from_der(&[0x30, 0x00]).unwrap();
+        let mut buffer = [0u8; 2];
+        assert_eq!(&[0x30, 0x00], array.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn round_trip_large_sequence_of_array() {
+        // `SEQUENCE OF NULL` with more elements than `Default` is implemented
+        // for on arrays
+        let mut der = [0x05u8; 82];
+        der[..2].copy_from_slice(&[0x30, 80]);
+
+        for elem in der[2..].chunks_mut(2) {
+            elem[1] = 0x00;
+        }
+
+        let array = <[Null; 40]>::from_der(&der).unwrap();
+        assert_eq!(array, [Null; 40]);
+
+        let mut buffer = [0u8; 82];
+        assert_eq!(&der[..], array.encode_to_slice(&mut buffer).unwrap());
+    }
+
+    #[test]
+    fn reject_sequence_of_array_count_mismatch() {
+        let der = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+        let err = <[u32; 3]>::from_der(&der).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+
+        let err = <[u32; 1]>::from_der(&der).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+}
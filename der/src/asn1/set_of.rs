@@ -9,7 +9,11 @@ use core::{convert::TryFrom, marker::PhantomData};
 #[cfg(feature = "alloc")]
 use {
     crate::Header,
-    alloc::collections::{btree_set, BTreeSet},
+    alloc::{
+        collections::{btree_set, BTreeSet},
+        vec::Vec,
+    },
+    core::slice,
 };
 
 /// ASN.1 `SET OF` denotes a collection of zero or more occurrences of a
@@ -56,8 +60,13 @@ where
 {
     /// Create a new [`SetOfRef`] from a slice.
     pub fn new(slice: &'a [u8]) -> Result<Self> {
+        Self::from_decoder(slice, Decoder::new(slice))
+    }
+
+    /// Create a new [`SetOfRef`] from a slice, validating its elements using
+    /// the provided [`Decoder`] for the same slice.
+    fn from_decoder(slice: &'a [u8], mut decoder: Decoder<'a>) -> Result<Self> {
         let inner = ByteSlice::new(slice).map_err(|_| ErrorKind::Length { tag: Self::TAG })?;
-        let mut decoder = Decoder::new(slice);
         let mut last_value = None;
 
         // Validate that we can decode all elements in the slice, and that they
@@ -100,7 +109,10 @@ where
     T: Clone + Decodable<'a> + Encodable + Ord,
 {
     fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
-        Self::new(ByteSlice::decode_value(decoder, length)?.as_bytes())
+        let offset = decoder.absolute_position()?;
+        let slice = ByteSlice::decode_value(decoder, length)?.as_bytes();
+        let nested = Decoder::new_nested(slice, decoder.is_strict(), offset);
+        Self::from_decoder(slice, nested)
     }
 }
 
@@ -226,7 +238,7 @@ where
         }
 
         if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
+            return Err(decoder.error(ErrorKind::Length { tag: Self::TAG }));
         }
 
         if let Some(last) = last_value {
@@ -293,6 +305,139 @@ where
     const TAG: Tag = Tag::Set;
 }
 
+/// ASN.1 `SET OF` backed by a [`Vec`], for element types which don't impl
+/// [`Ord`].
+///
+/// Elements are kept in ascending order of their DER encodings as required
+/// for `SET OF`: they're sorted when the set is constructed, and decoding
+/// rejects elements which aren't in order as non-canonical. Unlike
+/// [`BTreeSet`], elements with identical encodings are permitted.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SetOfVec<T> {
+    inner: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Encodable> SetOfVec<T> {
+    /// Create a new [`SetOfVec`] from the given elements, sorting them in
+    /// ascending order of their DER encodings.
+    ///
+    /// Returns an error if any of the elements fail to encode.
+    pub fn new(elements: Vec<T>) -> Result<Self> {
+        let mut keyed = elements
+            .into_iter()
+            .map(|elem| Ok((elem.to_vec()?, elem)))
+            .collect::<Result<Vec<_>>>()?;
+
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(Self {
+            inner: keyed.into_iter().map(|(_, elem)| elem).collect(),
+        })
+    }
+
+    /// Borrow the elements of this set as a slice, in DER order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// Convert this set into a [`Vec`] of its elements, in DER order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner
+    }
+
+    /// Iterate over the elements of this set, in DER order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.inner.iter()
+    }
+
+    /// Get the number of elements in this set.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Is this set empty?
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a, T> DecodeValue<'a> for SetOfVec<T>
+where
+    T: Decodable<'a> + Encodable,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        decoder.read_nested(length, |decoder| {
+            let mut inner = Vec::new();
+            let mut last_der: Option<Vec<u8>> = None;
+
+            while !decoder.is_finished() {
+                let value: T = decoder.decode()?;
+                let der = value.to_vec()?;
+
+                if let Some(last) = last_der.as_ref() {
+                    if last > &der {
+                        return Err(decoder.error(ErrorKind::Noncanonical { tag: Self::TAG }));
+                    }
+                }
+
+                last_der = Some(der);
+                inner.push(value);
+            }
+
+            Ok(Self { inner })
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: Encodable> EncodeValue for SetOfVec<T> {
+    fn value_len(&self) -> Result<Length> {
+        self.iter()
+            .try_fold(Length::ZERO, |acc, elem| acc + elem.encoded_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        for elem in self.iter() {
+            encoder.encode(elem)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a, T> TryFrom<Any<'a>> for SetOfVec<T>
+where
+    T: Decodable<'a> + Encodable,
+{
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Self> {
+        any.decode_into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> Tagged for SetOfVec<T> {
+    const TAG: Tag = Tag::Set;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> From<SetOfVec<T>> for Vec<T> {
+    fn from(set: SetOfVec<T>) -> Vec<T> {
+        set.inner
+    }
+}
+
 /// Get the encoded length of a [`BTreeSet`]
 #[cfg(feature = "alloc")]
 fn btreeset_inner_len<'a, T>(set: &BTreeSet<T>) -> Result<Length>
@@ -300,5 +445,97 @@ where
     T: Clone + Decodable<'a> + Encodable + Ord,
 {
     set.iter()
-        .fold(Ok(Length::ZERO), |acc, val| acc? + val.encoded_len()?)
+        .try_fold(Length::ZERO, |acc, val| acc + val.encoded_len()?)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{SetOf, SetOfRef, SetOfVec};
+    use crate::{asn1::Any, Decodable, Encodable, ErrorKind, Tag};
+    use alloc::{collections::BTreeSet, vec};
+    use hex_literal::hex;
+
+    /// Empty `SET OF`.
+    const EMPTY: &[u8] = &hex!("3100");
+
+    /// `SET OF ANY` containing `BOOLEAN TRUE`, `INTEGER 1` and `INTEGER 2`,
+    /// in ascending order of their encodings.
+    const IN_ORDER: &[u8] = &hex!("31090101FF020101020102");
+
+    /// `SET OF ANY` containing the same elements as [`IN_ORDER`] with the
+    /// `BOOLEAN` out of order.
+    const OUT_OF_ORDER: &[u8] = &hex!("3109020101 0101FF 020102");
+
+    /// `SET OF INTEGER` whose second element runs past the end of the `SET`.
+    const OVERRUN: &[u8] = &hex!("3104 020101 020102");
+
+    #[test]
+    fn decode_set_of_vec_in_order() {
+        let set = SetOfVec::<Any<'_>>::from_der(IN_ORDER).unwrap();
+        let tags = set.iter().map(|elem| elem.tag()).collect::<vec::Vec<_>>();
+        assert_eq!(tags, [Tag::Boolean, Tag::Integer, Tag::Integer]);
+
+        let mut buf = [0u8; 16];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), IN_ORDER);
+    }
+
+    #[test]
+    fn reject_set_of_vec_out_of_order() {
+        let err = SetOfVec::<Any<'_>>::from_der(OUT_OF_ORDER).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Set });
+    }
+
+    #[test]
+    fn reject_set_of_vec_element_overrun() {
+        let err = SetOfVec::<u32>::from_der(OVERRUN).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+    }
+
+    #[test]
+    fn reject_set_of_btreeset_element_overrun() {
+        let err = BTreeSet::<u32>::from_der(OVERRUN).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Set });
+    }
+
+    #[test]
+    fn new_set_of_vec_sorts_by_encoding() {
+        let set = SetOfVec::new(vec![
+            Any::new(Tag::Integer, &[0x02]).unwrap(),
+            Any::new(Tag::Boolean, &[0xFF]).unwrap(),
+            Any::new(Tag::Integer, &[0x01]).unwrap(),
+        ])
+        .unwrap();
+
+        let mut buf = [0u8; 16];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), IN_ORDER);
+    }
+
+    #[test]
+    fn empty_set_of_ref() {
+        let set = SetOfRef::<'_, u32>::from_der(EMPTY).unwrap();
+        assert!(set.as_bytes().is_empty());
+        assert_eq!(set.elements().count(), 0);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), EMPTY);
+    }
+
+    #[test]
+    fn empty_set_of_btreeset() {
+        let set = BTreeSet::<u32>::from_der(EMPTY).unwrap();
+        assert!(set.is_empty());
+
+        let mut buf = [0u8; 2];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), EMPTY);
+    }
+
+    #[test]
+    fn empty_set_of_vec() {
+        let set = SetOfVec::<u32>::from_der(EMPTY).unwrap();
+        assert!(set.is_empty());
+        assert!(SetOfVec::<u32>::new(vec![]).unwrap().is_empty());
+
+        let mut buf = [0u8; 2];
+        assert_eq!(set.encode_to_slice(&mut buf).unwrap(), EMPTY);
+    }
 }
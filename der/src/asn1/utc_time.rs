@@ -3,9 +3,10 @@
 use crate::{
     asn1::Any,
     datetime::{self, DateTime},
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, Length, Result, Tag, Tagged,
+    ByteSlice, Decodable, DecodeValue, Decoder, EncodeValue, Encoder, Error, Header, Length,
+    Result, Tag, Tagged,
 };
-use core::{convert::TryFrom, time::Duration};
+use core::{convert::TryFrom, fmt, time::Duration};
 
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -53,6 +54,51 @@ impl UtcTime {
         self.0
     }
 
+    /// Decode a [`UtcTime`] which may include an explicit time zone offset
+    /// (i.e. `YYMMDDHHMMSS+hhmm` or `YYMMDDHHMMSS-hhmm`) in place of `Z`,
+    /// normalizing it to UTC.
+    ///
+    /// Such offsets are permitted by BER but not DER, so the [`Decodable`]
+    /// impl for this type only accepts Z-normalized times.
+    pub fn decode_lenient(decoder: &mut Decoder<'_>) -> Result<Self> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+
+        match *ByteSlice::decode_value(decoder, header.length)?.as_bytes() {
+            [ref datetime @ .., b'Z'] => Self::decode_datetime(datetime).and_then(Self::new),
+            [ref datetime @ .., sign, hour1, hour2, min1, min2] => {
+                let local_time = Self::decode_datetime(datetime)?;
+                let offset = [sign, hour1, hour2, min1, min2];
+                datetime::apply_offset(Self::TAG, local_time, offset).and_then(Self::new)
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+
+    /// Decode the `YYMMDDHHMMSS` portion of a [`UtcTime`] as a [`Duration`]
+    /// since `UNIX_EPOCH`.
+    fn decode_datetime(bytes: &[u8]) -> Result<Duration> {
+        match *bytes {
+            // RFC 5280 requires mandatory seconds
+            [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2] => {
+                let year = datetime::decode_decimal(Self::TAG, year1, year2)?;
+                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
+                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
+                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
+                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
+                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
+
+                // RFC 5280 rules for interpreting the year
+                let year = if year >= 50 { year + 1900 } else { year + 2000 };
+
+                DateTime::new(year, month, day, hour, minute, second)
+                    .and_then(|dt| dt.unix_duration())
+                    .map_err(|_| Self::TAG.value_error())
+            }
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+
     /// Instantiate from [`SystemTime`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -73,23 +119,8 @@ impl UtcTime {
 impl DecodeValue<'_> for UtcTime {
     fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
         match *ByteSlice::decode_value(decoder, length)?.as_bytes() {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, year1, year2)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                // RFC 5280 rules for interpreting the year
-                let year = if year >= 50 { year + 1900 } else { year + 2000 };
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .and_then(|dt| dt.unix_duration())
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(Self::new)
-            }
+            // RFC 5280 requires Z-normalized time zone
+            [ref datetime @ .., b'Z'] => Self::decode_datetime(datetime).and_then(Self::new),
             _ => Err(Self::TAG.value_error()),
         }
     }
@@ -142,6 +173,14 @@ impl TryFrom<Any<'_>> for UtcTime {
     }
 }
 
+impl fmt::Display for UtcTime {
+    /// Render this time in ISO 8601 format, i.e. `YYYY-MM-DDThh:mm:ssZ`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = DateTime::from_unix_duration(self.0).map_err(|_| fmt::Error)?;
+        fmt::Display::fmt(&datetime, f)
+    }
+}
+
 impl Tagged for UtcTime {
     const TAG: Tag = Tag::UtcTime;
 }
@@ -149,7 +188,7 @@ impl Tagged for UtcTime {
 #[cfg(test)]
 mod tests {
     use super::{DateTime, UtcTime};
-    use crate::{Decodable, Encodable, Encoder};
+    use crate::{Decodable, Decoder, Encodable, Encoder};
     use hex_literal::hex;
 
     #[test]
@@ -164,6 +203,19 @@ mod tests {
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn display_iso8601() {
+        use alloc::string::ToString;
+        let utc_time =
+            UtcTime::from_der(&hex!("17 0d 32 33 30 37 31 34 30 39 30 35 30 37 5a")).unwrap();
+        assert_eq!(utc_time.to_string(), "2023-07-14T09:05:07Z");
+
+        let utc_time =
+            UtcTime::from_der(&hex!("17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a")).unwrap();
+        assert_eq!(utc_time.to_string(), "1991-05-06T23:45:40Z");
+    }
+
     #[test]
     fn round_trip_examples() {
         for year in 1970..=2049 {
@@ -187,4 +239,35 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn decode_lenient_with_offset() {
+        // 1991-05-06T23:45:40+0500 == 1991-05-06T18:45:40Z
+        let der = b"\x17\x11910506234540+0500";
+        let time = UtcTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573540 - 5 * 3600);
+
+        // 1991-05-06T23:45:40-0800 == 1991-05-07T07:45:40Z (next day)
+        let der = b"\x17\x11910506234540-0800";
+        let time = UtcTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(time.unix_duration().as_secs(), 673573540 + 8 * 3600);
+        assert_eq!(
+            DateTime::from_unix_duration(time.unix_duration()).unwrap(),
+            DateTime::new(1991, 5, 7, 7, 45, 40).unwrap()
+        );
+
+        // 2000-01-01T01:00:00+0130 == 1999-12-31T23:30:00Z (previous year)
+        let der = b"\x17\x11000101010000+0130";
+        let time = UtcTime::decode_lenient(&mut Decoder::new(der)).unwrap();
+        assert_eq!(
+            DateTime::from_unix_duration(time.unix_duration()).unwrap(),
+            DateTime::new(1999, 12, 31, 23, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_offset_by_default() {
+        let der = b"\x17\x11910506234540+0500";
+        assert!(UtcTime::from_der(der).is_err());
+    }
 }
@@ -7,7 +7,10 @@ use crate::{
 use core::{convert::TryFrom, fmt, str};
 
 #[cfg(feature = "alloc")]
-use alloc::{borrow::ToOwned, string::String};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+};
 
 /// ASN.1 `UTF8String` type.
 ///
@@ -149,6 +152,42 @@ impl Tagged for str {
     const TAG: Tag = Tag::Utf8String;
 }
 
+/// A `char` is encoded as a `UTF8String` containing exactly one code point.
+impl<'a> DecodeValue<'a> for char {
+    fn decode_value(decoder: &mut Decoder<'a>, length: Length) -> Result<Self> {
+        let utf8_string = Utf8String::decode_value(decoder, length)?;
+        let mut chars = utf8_string.as_str().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for char {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.len_utf8())
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let mut buf = [0u8; 4];
+        encoder.bytes(self.encode_utf8(&mut buf).as_bytes())
+    }
+}
+
+impl Tagged for char {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+impl TryFrom<Any<'_>> for char {
+    type Error = Error;
+
+    fn try_from(any: Any<'_>) -> Result<char> {
+        any.decode_into()
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl<'a> TryFrom<Any<'a>> for String {
@@ -161,13 +200,21 @@ impl<'a> TryFrom<Any<'a>> for String {
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl Encodable for String {
-    fn encoded_len(&self) -> Result<Length> {
-        Utf8String::new(self)?.encoded_len()
+impl DecodeValue<'_> for String {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        Utf8String::decode_value(decoder, length).map(|s| s.as_str().to_owned())
     }
+}
 
-    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        Utf8String::new(self)?.encode(encoder)
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for String {
+    fn value_len(&self) -> Result<Length> {
+        Utf8String::new(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::new(self)?.encode_value(encoder)
     }
 }
 
@@ -177,10 +224,37 @@ impl Tagged for String {
     const TAG: Tag = Tag::Utf8String;
 }
 
+/// Decoding always produces [`Cow::Owned`], so the result can outlive the input.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl DecodeValue<'_> for Cow<'_, str> {
+    fn decode_value(decoder: &mut Decoder<'_>, length: Length) -> Result<Self> {
+        String::decode_value(decoder, length).map(Cow::Owned)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for Cow<'_, str> {
+    fn value_len(&self) -> Result<Length> {
+        Utf8String::new(self.as_bytes())?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::new(self.as_bytes())?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for Cow<'_, str> {
+    const TAG: Tag = Tag::Utf8String;
+}
+
 #[cfg(test)]
 mod tests {
     use super::Utf8String;
-    use crate::Decodable;
+    use crate::{Decodable, Encodable, ErrorKind, Tag};
 
     #[test]
     fn parse_ascii_bytes() {
@@ -198,4 +272,64 @@ mod tests {
         let utf8_string = Utf8String::from_der(example_bytes).unwrap();
         assert_eq!(utf8_string.as_str(), "Helló");
     }
+
+    #[test]
+    fn char_round_trip() {
+        for (c, der) in &[
+            ('A', &[0x0c, 0x01, 0x41][..]),
+            ('ó', &[0x0c, 0x02, 0xc3, 0xb3][..]),
+            ('€', &[0x0c, 0x03, 0xe2, 0x82, 0xac][..]),
+            ('🦀', &[0x0c, 0x04, 0xf0, 0x9f, 0xa6, 0x80][..]),
+        ] {
+            assert_eq!(char::from_der(der).unwrap(), *c);
+
+            let mut buf = [0u8; 8];
+            assert_eq!(c.encode_to_slice(&mut buf).unwrap(), *der);
+        }
+    }
+
+    #[test]
+    fn reject_char_with_wrong_code_point_count() {
+        for der in &[&[0x0c, 0x00][..], &[0x0c, 0x02, 0x41, 0x42][..]] {
+            let err = char::from_der(der).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Value {
+                    tag: Tag::Utf8String
+                }
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn string_round_trip() {
+        use crate::Decoder;
+        use alloc::string::String;
+
+        let der = &[0x0c, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f];
+        let mut decoder = Decoder::new(der);
+        let string = decoder.decode::<String>().unwrap();
+        assert_eq!(string, "hello");
+
+        let mut buf = [0u8; 8];
+        assert_eq!(string.encode_to_slice(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cow_round_trip() {
+        use alloc::borrow::Cow;
+
+        let der = &[0x0c, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f];
+        let mut buf = [0u8; 8];
+
+        let borrowed = Cow::Borrowed("hello");
+        assert_eq!(borrowed.encode_to_slice(&mut buf).unwrap(), der);
+
+        let decoded = Cow::<'static, str>::from_der(der).unwrap();
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, borrowed);
+        assert_eq!(decoded.encode_to_slice(&mut buf).unwrap(), der);
+    }
 }
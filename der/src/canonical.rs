@@ -0,0 +1,189 @@
+//! Canonical DER checks.
+
+use crate::{
+    asn1::{Any, GeneralizedTime, Ia5String, IntBytes, Null, PrintableString, UtcTime, Utf8String},
+    Decodable, Decoder, Tag,
+};
+use core::convert::TryInto;
+
+/// Maximum nesting depth of constructed values checked by [`is_canonical`].
+const MAX_DEPTH: usize = 32;
+
+/// Is the given input a single value encoded as canonical DER?
+///
+/// The value is decoded as an [`Any`], recursing into constructed values,
+/// and checked for conformance with the DER encoding rules, namely:
+///
+/// - Lengths are definite and use the minimal number of octets.
+/// - `BOOLEAN` values are encoded as `0x00` or `0xFF`.
+/// - `INTEGER` and `ENUMERATED` values have no redundant leading octets.
+/// - Unused bits in a `BIT STRING` are zero.
+/// - `NULL` values are empty and `OBJECT IDENTIFIER` arcs are minimal.
+/// - String and time types are well-formed.
+/// - The elements of a `SET` (or `SET OF`) are sorted by their encodings.
+///
+/// The contents of primitive values with non-universal tags can't be
+/// checked without knowing their type, and are accepted as is. Values
+/// nested more than 32 levels deep are rejected.
+pub fn is_canonical(bytes: &[u8]) -> bool {
+    matches!(Any::from_der(bytes), Ok(any) if is_canonical_value(any, 0))
+}
+
+/// Check the contents of a single decoded value.
+fn is_canonical_value(any: Any<'_>, depth: usize) -> bool {
+    match any.tag() {
+        Tag::Boolean => any.decode_into::<bool>().is_ok(),
+        Tag::Integer => any.decode_into::<IntBytes<'_>>().is_ok(),
+        Tag::Enumerated => Any::new(Tag::Integer, any.value())
+            .and_then(|int| int.decode_into::<IntBytes<'_>>())
+            .is_ok(),
+        Tag::BitString => is_canonical_bit_string(any.value()),
+        Tag::Null => any.decode_into::<Null>().is_ok(),
+        Tag::ObjectIdentifier => is_canonical_oid(any.value()),
+        Tag::Utf8String => any.decode_into::<Utf8String<'_>>().is_ok(),
+        Tag::PrintableString => any.decode_into::<PrintableString<'_>>().is_ok(),
+        Tag::Ia5String => any.decode_into::<Ia5String<'_>>().is_ok(),
+        Tag::UtcTime => any.decode_into::<UtcTime>().is_ok(),
+        Tag::GeneralizedTime => any.decode_into::<GeneralizedTime>().is_ok(),
+        Tag::Set => is_canonical_constructed(any.value(), depth, true),
+        tag if tag.is_constructed() => is_canonical_constructed(any.value(), depth, false),
+        _ => true,
+    }
+}
+
+/// Check each of the values in the body of a constructed value, and
+/// optionally that they're sorted by their encodings.
+fn is_canonical_constructed(bytes: &[u8], depth: usize, sorted: bool) -> bool {
+    if depth >= MAX_DEPTH {
+        return false;
+    }
+
+    let mut decoder = Decoder::new(bytes);
+    let mut prev: Option<&[u8]> = None;
+
+    while !decoder.is_finished() {
+        let start = decoder.position();
+
+        let any = match decoder.decode::<Any<'_>>() {
+            Ok(any) => any,
+            Err(_) => return false,
+        };
+
+        if !is_canonical_value(any, depth + 1) {
+            return false;
+        }
+
+        if sorted {
+            let range = match (start.try_into(), decoder.position().try_into()) {
+                (Ok(start), Ok(end)) => start..end,
+                _ => return false,
+            };
+
+            let elem = &bytes[range];
+
+            if matches!(prev, Some(prev) if prev > elem) {
+                return false;
+            }
+
+            prev = Some(elem);
+        }
+    }
+
+    true
+}
+
+/// Check the body of a `BIT STRING`, i.e. the count of unused bits followed
+/// by the bits themselves, of which the unused ones must be zero.
+fn is_canonical_bit_string(bytes: &[u8]) -> bool {
+    match bytes {
+        [0] => true,
+        [unused_bits, .., last] if *unused_bits < 8 => last & ((1 << unused_bits) - 1) == 0,
+        _ => false,
+    }
+}
+
+/// Check the body of an `OBJECT IDENTIFIER`, i.e. that it's non-empty, its
+/// final arc is terminated and no arc has a leading `0x80` octet.
+fn is_canonical_oid(bytes: &[u8]) -> bool {
+    let mut arc_start = true;
+
+    for &byte in bytes {
+        if arc_start && byte == 0x80 {
+            return false;
+        }
+
+        arc_start = byte < 0x80;
+    }
+
+    !bytes.is_empty() && arc_start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_canonical;
+    use hex_literal::hex;
+
+    #[test]
+    fn canonical() {
+        for der in &[
+            &hex!("01 01 FF")[..],
+            &hex!("02 01 00"),
+            &hex!("02 02 00 80"),
+            &hex!("02 01 FF"),
+            &hex!("03 01 00"),
+            &hex!("03 02 07 80"),
+            &hex!("05 00"),
+            &hex!("06 03 2B 65 70"),
+            &hex!("06 06 2A 86 48 86 F7 0D"),
+            &hex!("30 06 02 01 2A 01 01 00"),
+            &hex!("31 06 02 01 01 02 01 02"),
+            &hex!("31 06 02 01 01 02 01 01"),
+            &hex!("A0 05 30 03 02 01 2A"),
+        ] {
+            assert!(is_canonical(der), "{:02X?}", der);
+        }
+    }
+
+    #[test]
+    fn non_canonical() {
+        for der in &[
+            // Non-minimal and indefinite lengths
+            &hex!("02 81 01 2A")[..],
+            &hex!("30 80 02 01 2A 00 00"),
+            // BOOLEAN which isn't `0x00` or `0xFF`
+            &hex!("01 01 01"),
+            // INTEGERs with redundant leading octets
+            &hex!("02 02 00 2A"),
+            &hex!("02 02 FF FF"),
+            &hex!("0A 02 00 01"),
+            // BIT STRINGs with non-zero unused bits
+            &hex!("03 02 07 C0"),
+            &hex!("03 01 01"),
+            // Non-empty NULL
+            &hex!("05 01 00"),
+            // OID arc with a leading `0x80` octet
+            &hex!("06 04 2B 80 65 70"),
+            // Unsorted SET OF
+            &hex!("31 06 02 01 02 02 01 01"),
+            // Non-canonical values nested within constructed values
+            &hex!("30 06 02 01 2A 01 01 01"),
+            &hex!("A0 06 30 04 02 02 00 2A"),
+            // Trailing data
+            &hex!("05 00 00"),
+        ] {
+            assert!(!is_canonical(der), "{:02X?}", der);
+        }
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let mut der = [0u8; 128];
+
+        for (i, chunk) in der.chunks_mut(2).enumerate() {
+            chunk.copy_from_slice(&[0x30, (126 - i * 2) as u8]);
+        }
+
+        assert!(!is_canonical(&der));
+        assert!(is_canonical(&der[126 - 2 * 31..]));
+    }
+}
@@ -237,7 +237,7 @@ impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:02}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
             self.year, self.month, self.day, self.hour, self.minutes, self.seconds
         )
     }
@@ -252,6 +252,29 @@ pub(crate) fn decode_decimal(tag: Tag, hi: u8, lo: u8) -> Result<u16> {
     }
 }
 
+/// Decode an explicit time zone offset of the form `+hhmm` or `-hhmm` and
+/// apply it to the given local time, returning the equivalent UTC time.
+pub(crate) fn apply_offset(tag: Tag, local_time: Duration, offset: [u8; 5]) -> Result<Duration> {
+    let [sign, hour1, hour2, min1, min2] = offset;
+    let hours = decode_decimal(tag, hour1, hour2)?;
+    let minutes = decode_decimal(tag, min1, min2)?;
+
+    if hours > 23 || minutes > 59 {
+        return Err(tag.value_error());
+    }
+
+    let offset = Duration::from_secs((u64::from(hours) * 60 + u64::from(minutes)) * 60);
+
+    match sign {
+        // Local time is ahead of UTC
+        b'+' => local_time.checked_sub(offset),
+        // Local time is behind UTC
+        b'-' => local_time.checked_add(offset),
+        _ => None,
+    }
+    .ok_or_else(|| tag.value_error())
+}
+
 /// Encode 2-digit decimal value
 pub(crate) fn encode_decimal(encoder: &mut Encoder<'_>, tag: Tag, value: u16) -> Result<()> {
     let hi_val = value / 10;
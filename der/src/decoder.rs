@@ -1,33 +1,107 @@
 //! DER decoder.
 
 use crate::{
-    asn1::*, Choice, Decodable, DecodeValue, Error, ErrorKind, Length, Result, Tag, TagMode,
-    TagNumber, Tagged,
+    asn1::*,
+    reader::{SliceReader, Source},
+    trace::Tracer,
+    Choice, Decodable, DecodeValue, Error, ErrorKind, Header, Length, Reader, Result, Span, Tag,
+    TagMode, TagNumber, Tagged,
 };
-use core::convert::{TryFrom, TryInto};
+use core::{convert::TryInto, fmt};
+
+#[cfg(feature = "trace")]
+use crate::TraceEvent;
 
 /// DER decoder.
 #[derive(Debug)]
 pub struct Decoder<'a> {
-    /// Byte slice being decoded.
+    /// Input source being decoded.
+    input: Input<'a>,
+
+    /// Has an error previously been encountered?
     ///
-    /// In the event an error was previously encountered this will be set to
-    /// `None` to prevent further decoding while in a bad state.
-    bytes: Option<&'a [u8]>,
+    /// This prevents further decoding while in a bad state, until the
+    /// decoder is rewound using [`Decoder::set_position`].
+    failed: bool,
 
     /// Position within the decoded slice.
     position: Length,
+
+    /// Offset of the input relative to the outermost decoder, for decoders
+    /// nested within another.
+    offset: Length,
+
+    /// Reject unknown context-specific fields rather than skipping them.
+    strict: bool,
+
+    /// Tracer notified of each decoded value.
+    tracer: Tracer<'a>,
 }
 
 impl<'a> Decoder<'a> {
     /// Create a new decoder for the given byte slice.
     pub fn new(bytes: &'a [u8]) -> Self {
         Self {
-            bytes: Some(bytes),
+            input: Input::Slice(SliceReader::new(bytes)),
+            failed: false,
+            position: Length::ZERO,
+            offset: Length::ZERO,
+            strict: false,
+            tracer: Tracer::default(),
+        }
+    }
+
+    /// Create a new decoder which reads its input from the given [`Reader`].
+    ///
+    /// The reader is only borrowed for the lifetime of the decoder and the
+    /// values decoded by it, which may be shorter than the lifetime `'r` of
+    /// its input.
+    pub fn from_reader<'r: 'a, R: Reader<'r>>(reader: &'a mut R) -> Self {
+        Self {
+            input: Input::Reader(reader),
+            failed: false,
             position: Length::ZERO,
+            offset: Length::ZERO,
+            strict: false,
+            tracer: Tracer::default(),
+        }
+    }
+
+    /// Create a new strict decoder for the given byte slice.
+    ///
+    /// Decoders are permissive by default, and types which support extension
+    /// fields skip any unknown context-specific fields they encounter. Strict
+    /// decoders (and any nested decoders created from them) instead signal to
+    /// those types that such fields should be rejected with an error.
+    pub fn new_strict(bytes: &'a [u8]) -> Self {
+        Self::new_nested(bytes, true, Length::ZERO)
+    }
+
+    /// Create a new decoder for the body of a value which was decoded by
+    /// another decoder, inheriting its strictness. The body begins at the
+    /// given offset relative to the outermost decoder's input.
+    pub(crate) fn new_nested(bytes: &'a [u8], strict: bool, offset: Length) -> Self {
+        Self {
+            strict,
+            offset,
+            ..Self::new(bytes)
         }
     }
 
+    /// Attach a tracer to this decoder, which is called with a
+    /// [`TraceEvent`] whenever decoding a TLV value begins and ends.
+    ///
+    /// The tracer is passed on to nested decoders, e.g. those created by
+    /// [`Decoder::sequence`], so events for the contents of a `SEQUENCE` are
+    /// emitted between its own [`TraceEvent::Enter`] and
+    /// [`TraceEvent::Exit`] events.
+    #[cfg(feature = "trace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "trace")))]
+    pub fn with_tracer(mut self, tracer: &'a mut dyn FnMut(TraceEvent)) -> Self {
+        self.tracer = Tracer::new(tracer);
+        self
+    }
+
     /// Decode a value which impls the [`Decodable`] trait.
     pub fn decode<T: Decodable<'a>>(&mut self) -> Result<T> {
         if self.is_failed() {
@@ -35,15 +109,146 @@ impl<'a> Decoder<'a> {
         }
 
         T::decode(self).map_err(|e| {
-            self.bytes.take();
+            self.failed = true;
             e.nested(self.position)
         })
     }
 
+    /// Decode a value which impls the [`Decodable`] trait, along with the
+    /// [`Span`] of input it occupied.
+    ///
+    /// This is useful for mapping decoded fields back to their location in
+    /// the original input, e.g. for diagnostics.
+    pub fn decode_spanned<T: Decodable<'a>>(&mut self) -> Result<(T, Span)> {
+        let start = self.position;
+        let value = self.decode()?;
+
+        let span = Span {
+            offset: (self.offset + start)?,
+            length: (self.position - start)?,
+        };
+
+        Ok((value, span))
+    }
+
+    /// Decode a value using the provided closure, which is run against this
+    /// [`Decoder`].
+    ///
+    /// This is an extension point for ad hoc decoding of values which don't
+    /// have a type implementing [`Decodable`], similar to
+    /// [`Decoder::sequence`] but without expecting a `SEQUENCE` header.
+    pub fn decode_with<F, T>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        if self.is_failed() {
+            return Err(self.error(ErrorKind::Failed));
+        }
+
+        let result = f(self);
+        self.failed |= result.is_err();
+        result
+    }
+
+    /// Read a nested value spanning the next `len` bytes of input, e.g. the
+    /// contents of an `OCTET STRING` which encapsulates another DER message.
+    ///
+    /// The provided closure is run against a new nested [`Decoder`] over
+    /// those bytes, and must consume all of them, otherwise
+    /// [`ErrorKind::TrailingData`] is returned. On success this [`Decoder`]
+    /// is advanced past the nested bytes.
+    pub fn read_nested<F, T>(&mut self, len: Length, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let start = self.position;
+        let bytes = self.bytes(len)?;
+
+        // Report errors relative to the start of the nested bytes
+        self.run_nested(bytes, start, f).map_err(|e| {
+            self.failed = true;
+            e.nested(start)
+        })
+    }
+
+    /// Decode a value of type `T` if the next tag matches it, or otherwise
+    /// return the provided `default` without consuming any input.
+    ///
+    /// This is useful for ASN.1 fields which are `DEFAULT` (or `OPTIONAL`
+    /// with a well-known fallback), e.g. in algorithm parameter structures.
+    pub fn decode_or_default<T: Choice<'a>>(&mut self, default: T) -> Result<T> {
+        Ok(self.decode::<Option<T>>()?.unwrap_or(default))
+    }
+
+    /// Decode the value of type `T` described by the given [`Header`],
+    /// ensuring exactly the length declared in the header is consumed.
+    ///
+    /// Returns [`ErrorKind::TrailingData`] if the value's body contains
+    /// trailing data which wasn't consumed while decoding it.
+    ///
+    /// When decoding a byte slice, [`ErrorKind::Truncated`] is returned up
+    /// front if the declared length exceeds the remaining input, before any
+    /// of the value is consumed. The remaining length of other [`Reader`]s
+    /// isn't known in advance, so they fail once the input runs out.
+    pub(crate) fn decode_value<T>(&mut self, header: Header) -> Result<T>
+    where
+        T: DecodeValue<'a>,
+    {
+        if matches!(self.input, Input::Slice(_)) && header.length > self.remaining_len()? {
+            return Err(self.error(ErrorKind::Truncated));
+        }
+
+        let start = self.position;
+        self.tracer.enter(header, start)?;
+
+        let value = T::decode_value(self, header.length)?;
+        let consumed = (self.position - start)?;
+
+        if consumed < header.length {
+            let kind = ErrorKind::TrailingData {
+                decoded: consumed,
+                remaining: (header.length - consumed)?,
+            };
+
+            Err(self.error(kind))
+        } else if consumed > header.length {
+            Err(self.error(ErrorKind::Length { tag: header.tag }))
+        } else {
+            self.tracer.exit(header, start)?;
+            Ok(value)
+        }
+    }
+
+    /// Decode exactly `N` consecutive values of type `T` into an array.
+    ///
+    /// Returns an error if fewer than `N` values are present, or if any data
+    /// remains in this [`Decoder`] after the `N`-th value.
+    pub fn decode_array<T, const N: usize>(&mut self) -> Result<[T; N]>
+    where
+        T: Decodable<'a> + Default + Copy,
+    {
+        let mut array = [T::default(); N];
+
+        for elem in array.iter_mut() {
+            *elem = self.decode()?;
+        }
+
+        if !self.is_finished() {
+            let kind = ErrorKind::TrailingData {
+                decoded: self.position,
+                remaining: self.remaining_len()?,
+            };
+
+            return Err(self.error(kind));
+        }
+
+        Ok(array)
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error(&mut self, kind: ErrorKind) -> Error {
-        self.bytes.take();
+        self.failed = true;
         kind.at(self.position)
     }
 
@@ -54,7 +259,14 @@ impl<'a> Decoder<'a> {
 
     /// Did the decoding operation fail due to an error?
     pub fn is_failed(&self) -> bool {
-        self.bytes.is_none()
+        self.failed
+    }
+
+    /// Is this a strict decoder which rejects unknown context-specific fields?
+    ///
+    /// See [`Decoder::new_strict`].
+    pub fn is_strict(&self) -> bool {
+        self.strict
     }
 
     /// Get the position within the buffer.
@@ -62,16 +274,52 @@ impl<'a> Decoder<'a> {
         self.position
     }
 
+    /// Get the position relative to the start of the outermost decoder's
+    /// input, for decoders nested within another.
+    pub(crate) fn absolute_position(&self) -> Result<Length> {
+        self.offset + self.position
+    }
+
+    /// Rewind the decoder to a position previously obtained from
+    /// [`Decoder::position`], e.g. to attempt decoding the same input as a
+    /// different type after a failed attempt.
+    ///
+    /// Only input which has already been consumed by this decoder can be
+    /// revisited, so positions past the current one are rejected, as are
+    /// all positions for decoders reading from a [`Reader`]. Rewinding also
+    /// clears any error state resulting from the failed attempt.
+    ///
+    /// Nested decoders (e.g. those passed to [`Decoder::sequence`]) have
+    /// their own positions relative to the body of the nested value, so a
+    /// decoder can't be rewound into the middle of a value it has consumed
+    /// as a whole.
+    pub fn set_position(&mut self, position: Length) -> Result<()> {
+        if position > self.position {
+            return Err(ErrorKind::Position.at(self.position));
+        }
+
+        match &mut self.input {
+            Input::Slice(reader) => reader.set_position(position.try_into()?)?,
+            Input::Reader(_) => return Err(ErrorKind::Position.at(self.position)),
+        }
+
+        self.position = position;
+        self.failed = false;
+        Ok(())
+    }
+
     /// Peek at the next byte in the decoder without modifying the cursor.
     pub fn peek(&self) -> Option<u8> {
-        self.remaining()
-            .ok()
-            .and_then(|bytes| bytes.get(0).cloned())
+        if self.is_failed() {
+            None
+        } else {
+            self.input.reader().peek_byte()
+        }
     }
 
     /// Finish decoding, returning the given value if there is no
     /// remaining data, or an error otherwise
-    pub fn finish<T>(self, value: T) -> Result<T> {
+    pub fn finish<T>(mut self, value: T) -> Result<T> {
         if self.is_failed() {
             Err(ErrorKind::Failed.at(self.position))
         } else if !self.is_finished() {
@@ -90,7 +338,7 @@ impl<'a> Decoder<'a> {
     /// Returns `false` if we're not finished decoding or if a fatal error
     /// has occurred.
     pub fn is_finished(&self) -> bool {
-        self.remaining().map(|rem| rem.is_empty()).unwrap_or(false)
+        !self.is_failed() && self.input.reader().peek_byte().is_none()
     }
 
     /// Attempt to decode an ASN.1 `ANY` value.
@@ -152,6 +400,29 @@ impl<'a> Decoder<'a> {
         .map(|field| field.value))
     }
 
+    /// Decode the [`Header`] of an ASN.1 `CONTEXT-SPECIFIC` field, checking
+    /// that it has the provided [`TagNumber`].
+    ///
+    /// The field may be either primitive or constructed, and its body is left
+    /// to be decoded by the caller. Returns [`ErrorKind::UnexpectedTag`] if
+    /// the next value has any other tag.
+    pub fn expect_context(&mut self, number: TagNumber) -> Result<Header> {
+        let position = self.position;
+        let header = self.decode::<Header>()?;
+
+        match header.tag {
+            Tag::ContextSpecific { number: actual, .. } if actual == number => Ok(header),
+            actual => {
+                self.failed = true;
+                Err(ErrorKind::UnexpectedTag {
+                    expected: Some(Tag::context_specific(number, actual.is_constructed())),
+                    actual,
+                }
+                .at(position))
+            }
+        }
+    }
+
     /// Attempt to decode an ASN.1 `GeneralizedTime`.
     pub fn generalized_time(&mut self) -> Result<GeneralizedTime> {
         self.decode()
@@ -205,10 +476,42 @@ impl<'a> Decoder<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        Sequence::decode(self)?.decode_nested(f).map_err(|e| {
-            self.bytes.take();
+        let header = Header::decode(self)?;
+        header.tag.assert_eq(Tag::Sequence)?;
+
+        // The `SEQUENCE` itself is traced here rather than when its body is
+        // read, so the events for its contents appear in between
+        let start = self.position;
+        let body = self.bytes(header.length)?;
+        self.tracer.enter(header, start)?;
+
+        let result = self.run_nested(body, start, f).map_err(|e| {
+            self.failed = true;
             e.nested(self.position)
-        })
+        })?;
+
+        self.tracer.exit(header, start)?;
+        Ok(result)
+    }
+
+    /// Run the provided closure against a new nested [`Decoder`] over the
+    /// given bytes, which begin at the given position of this decoder,
+    /// ensuring the closure consumes all of them.
+    ///
+    /// The nested decoder inherits this decoder's strictness and tracer, and
+    /// tracks its offset relative to the outermost decoder.
+    fn run_nested<F, T>(&mut self, bytes: &'a [u8], position: Length, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Decoder<'a>) -> Result<T>,
+    {
+        let mut nested = Decoder::new(bytes);
+        nested.strict = self.strict;
+        nested.offset = (self.offset + position)?;
+        nested.tracer = self.tracer.nest(position)?;
+
+        let result = f(&mut nested);
+        self.tracer.restore(&mut nested.tracer);
+        result.and_then(|value| nested.finish(value))
     }
 
     /// Decode a single byte, updating the internal cursor.
@@ -222,36 +525,69 @@ impl<'a> Decoder<'a> {
     /// Obtain a slice of bytes of the given length from the current cursor
     /// position, or return an error if we have insufficient data.
     pub(crate) fn bytes(&mut self, len: impl TryInto<Length>) -> Result<&'a [u8]> {
-        if self.is_failed() {
-            return Err(self.error(ErrorKind::Failed));
-        }
-
         let len = len
             .try_into()
             .map_err(|_| self.error(ErrorKind::Overflow))?;
 
-        let result = self
-            .remaining()?
-            .get(..len.try_into()?)
-            .ok_or_else(|| self.error(ErrorKind::Truncated))?;
+        if self.is_failed() {
+            return Err(self.error(ErrorKind::Failed));
+        }
+
+        match self.input.reader_mut().read_slice(len) {
+            Ok(slice) => {
+                self.position = (self.position + len)?;
+                Ok(slice)
+            }
+            Err(e) => Err(self.error(e.kind())),
+        }
+    }
 
-        self.position = (self.position + len)?;
-        Ok(result)
+    /// Get the number of bytes still remaining in the input.
+    ///
+    /// Decoders reading from a [`Reader`] may need to read ahead to
+    /// determine this.
+    fn remaining_len(&mut self) -> Result<Length> {
+        if self.is_failed() {
+            Err(ErrorKind::Truncated.at(self.position))
+        } else {
+            self.input.reader_mut().remaining_len()
+        }
     }
+}
 
-    /// Obtain the remaining bytes in this decoder from the current cursor
-    /// position.
-    fn remaining(&self) -> Result<&'a [u8]> {
-        let pos = usize::try_from(self.position)?;
+/// Input source of a [`Decoder`].
+enum Input<'a> {
+    /// Byte slice.
+    Slice(SliceReader<'a>),
 
-        self.bytes
-            .and_then(|b| b.get(pos..))
-            .ok_or_else(|| ErrorKind::Truncated.at(self.position))
+    /// Arbitrary [`Reader`].
+    Reader(&'a mut dyn Source<'a>),
+}
+
+impl<'a> Input<'a> {
+    /// Borrow the inner [`Reader`].
+    fn reader(&self) -> &dyn Source<'a> {
+        match self {
+            Input::Slice(reader) => reader,
+            Input::Reader(reader) => &**reader,
+        }
     }
 
-    /// Get the number of bytes still remaining in the buffer.
-    fn remaining_len(&self) -> Result<Length> {
-        self.remaining()?.len().try_into()
+    /// Mutably borrow the inner [`Reader`].
+    fn reader_mut(&mut self) -> &mut dyn Source<'a> {
+        match self {
+            Input::Slice(reader) => reader,
+            Input::Reader(reader) => &mut **reader,
+        }
+    }
+}
+
+impl fmt::Debug for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Slice(reader) => f.debug_tuple("Slice").field(reader).finish(),
+            Input::Reader(_) => f.debug_tuple("Reader").finish(),
+        }
     }
 }
 
@@ -264,7 +600,50 @@ impl<'a> From<&'a [u8]> for Decoder<'a> {
 #[cfg(test)]
 mod tests {
     use super::Decoder;
-    use crate::{Decodable, ErrorKind, Length};
+    use crate::{
+        asn1::{BitString, ContextSpecific},
+        reader::SliceReader,
+        Decodable, DecodeValue, ErrorKind, Header, Length, Result, Span, Tag, TagMode, TagNumber,
+        Tagged,
+    };
+
+    /// `SEQUENCE` whose `DecodeValue` impl decodes a single `INTEGER` and
+    /// ignores the declared length of its body.
+    #[derive(Debug)]
+    struct Lenient;
+
+    impl<'a> DecodeValue<'a> for Lenient {
+        fn decode_value(decoder: &mut Decoder<'a>, _length: Length) -> Result<Self> {
+            decoder.decode::<u8>().map(|_| Self)
+        }
+    }
+
+    impl Tagged for Lenient {
+        const TAG: Tag = Tag::Sequence;
+    }
+
+    /// `INTEGER` which records the [`Span`] it was decoded from.
+    struct IntSpan(Span);
+
+    impl<'a> Decodable<'a> for IntSpan {
+        fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+            decoder.decode_spanned::<u8>().map(|(_, span)| Self(span))
+        }
+    }
+
+    /// `NULL` which records whether it was decoded by a strict decoder.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+    struct IsStrict(bool);
+
+    impl<'a> DecodeValue<'a> for IsStrict {
+        fn decode_value(decoder: &mut Decoder<'a>, _length: Length) -> Result<Self> {
+            Ok(Self(decoder.is_strict()))
+        }
+    }
+
+    impl Tagged for IsStrict {
+        const TAG: Tag = Tag::Null;
+    }
 
     #[test]
     fn truncated_message() {
@@ -282,6 +661,195 @@ mod tests {
         assert_eq!(Some(Length::from(2u8)), err.position());
     }
 
+    #[test]
+    fn declared_length_exceeds_input() {
+        // `BIT STRING` header claims 10 bytes but only 2 are present
+        let mut decoder = Decoder::new(&[0x03, 0x0A, 0x00, 0xFF]);
+        let err = BitString::decode(&mut decoder).err().unwrap();
+        assert_eq!(ErrorKind::Truncated, err.kind());
+        assert_eq!(Some(Length::from(2u8)), err.position());
+
+        // Nothing past the header is consumed
+        assert_eq!(decoder.position(), Length::from(2u8));
+        assert!(decoder.is_failed());
+
+        // Same for an `OCTET STRING` nested in a `SEQUENCE`
+        let mut decoder = Decoder::new(&[0x30, 0x04, 0x04, 0x05, 0x01, 0x02]);
+        let err = decoder
+            .sequence(|nested| nested.octet_string())
+            .err()
+            .unwrap();
+        assert_eq!(ErrorKind::Truncated, err.kind());
+    }
+
+    #[test]
+    fn decode_with_closure() {
+        /// Ad hoc structure comprising an `INTEGER` followed by a `BOOLEAN`
+        #[derive(Debug, Eq, PartialEq)]
+        struct Fields {
+            version: u8,
+            critical: bool,
+        }
+
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x02, 0x01, 0x01, 0xFF]);
+        let fields = decoder
+            .decode_with(|decoder| {
+                Ok(Fields {
+                    version: decoder.decode()?,
+                    critical: decoder.decode()?,
+                })
+            })
+            .unwrap();
+
+        assert_eq!(
+            fields,
+            Fields {
+                version: 2,
+                critical: true
+            }
+        );
+        assert!(decoder.is_finished());
+
+        // Errors returned by the closure mark the decoder as failed
+        let mut decoder = Decoder::new(&[0x01, 0x01, 0xFF]);
+        let err = decoder.decode_with(|decoder| decoder.decode::<u8>());
+        assert!(err.is_err());
+        assert!(decoder.is_failed());
+    }
+
+    #[test]
+    fn read_nested_octet_string_contents() {
+        // `OCTET STRING` encapsulating a `SEQUENCE` containing `INTEGER` 42
+        let mut decoder = Decoder::new(&[0x04, 0x05, 0x30, 0x03, 0x02, 0x01, 0x2A]);
+        let header = Header::decode(&mut decoder).unwrap();
+        assert_eq!(header.tag, Tag::OctetString);
+
+        let n = decoder
+            .read_nested(header.length, |nested| {
+                nested.sequence(|seq| seq.decode::<u8>())
+            })
+            .unwrap();
+
+        assert_eq!(n, 42);
+        assert!(decoder.is_finished());
+
+        // Nested bytes which aren't fully consumed are an error
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x00]);
+        let err = decoder
+            .read_nested(Length::from(4u8), |nested| nested.decode::<u8>())
+            .err()
+            .unwrap();
+
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+        assert!(decoder.is_failed());
+    }
+
+    #[test]
+    fn decode_spanned_sequence_fields() {
+        let der = [0x30, 0x06, 0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF];
+        let mut decoder = Decoder::new(&der);
+        let ((n, n_span), (b, b_span)) = decoder
+            .sequence(|nested| {
+                Ok((
+                    nested.decode_spanned::<u8>()?,
+                    nested.decode_spanned::<bool>()?,
+                ))
+            })
+            .unwrap();
+
+        assert_eq!(n, 42);
+        assert_eq!(n_span.offset, Length::from(2u8));
+        assert_eq!(n_span.length, Length::from(3u8));
+        assert_eq!(&der[n_span.range().unwrap()], &[0x02, 0x01, 0x2A]);
+
+        assert!(b);
+        assert_eq!(b_span.offset, Length::from(5u8));
+        assert_eq!(b_span.length, Length::from(3u8));
+        assert_eq!(&der[b_span.range().unwrap()], &[0x01, 0x01, 0xFF]);
+
+        // Offsets within bytes read by `read_nested` are also absolute
+        let der = [0x04, 0x05, 0x30, 0x03, 0x02, 0x01, 0x2A];
+        let mut decoder = Decoder::new(&der);
+        decoder.byte().unwrap();
+        let len = Length::decode(&mut decoder).unwrap();
+        let (_, span) = decoder
+            .read_nested(len, |nested| {
+                nested.sequence(|seq| seq.decode_spanned::<u8>())
+            })
+            .unwrap();
+
+        assert_eq!(&der[span.range().unwrap()], &[0x02, 0x01, 0x2A]);
+        assert_eq!(span.end().unwrap(), Length::from(7u8));
+    }
+
+    #[test]
+    fn decode_spanned_within_any_and_context_specific() {
+        // `SEQUENCE { [0] EXPLICIT INTEGER 42, SEQUENCE { INTEGER 42 } }`
+        let der = [
+            0x30, 0x0A, 0xA0, 0x03, 0x02, 0x01, 0x2A, 0x30, 0x03, 0x02, 0x01, 0x2A,
+        ];
+
+        let (explicit, sequence) = Decoder::new(&der)
+            .sequence(|nested| {
+                let explicit =
+                    ContextSpecific::<IntSpan>::decode_explicit(nested, TagNumber::new(0))?;
+                let sequence = nested.any()?.sequence(IntSpan::decode)?;
+                Ok((explicit.unwrap().value.0, sequence.0))
+            })
+            .unwrap();
+
+        assert_eq!(explicit.offset, Length::from(4u8));
+        assert_eq!(sequence.offset, Length::from(9u8));
+
+        let any = Decoder::new(&der)
+            .sequence(|nested| {
+                let field = nested.decode::<ContextSpecific<IntSpan>>()?;
+                nested.any()?;
+                Ok(field.value.0)
+            })
+            .unwrap();
+
+        assert_eq!(any.offset, Length::from(4u8));
+    }
+
+    #[test]
+    fn expect_context_matching() {
+        // Primitive and constructed fields are both accepted
+        for der in &[[0x81, 0x01, 0xFF], [0xA1, 0x01, 0xFF]] {
+            let mut decoder = Decoder::new(der);
+            let header = decoder.expect_context(TagNumber::new(1)).unwrap();
+            assert_eq!(header.tag.number(), TagNumber::new(1));
+            assert_eq!(header.tag.is_constructed(), der[0] == 0xA1);
+            assert_eq!(header.length, Length::ONE);
+            assert_eq!(decoder.position(), Length::from(2u8));
+        }
+    }
+
+    #[test]
+    fn expect_context_mismatching() {
+        let mut decoder = Decoder::new(&[0x30, 0x00]);
+        let err = decoder.expect_context(TagNumber::new(1)).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::context_specific(TagNumber::new(1), true)),
+                actual: Tag::Sequence,
+            }
+        );
+        assert_eq!(err.position(), Some(Length::ZERO));
+        assert!(decoder.is_failed());
+
+        let mut decoder = Decoder::new(&[0x82, 0x01, 0xFF]);
+        let err = decoder.expect_context(TagNumber::new(1)).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::context_specific(TagNumber::new(1), false)),
+                actual: Tag::context_specific(TagNumber::new(2), false),
+            }
+        );
+    }
+
     #[test]
     fn trailing_data() {
         let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x00]);
@@ -298,4 +866,294 @@ mod tests {
         );
         assert_eq!(Some(Length::from(3u8)), err.position());
     }
+
+    #[test]
+    fn strict_sequence() {
+        let mut decoder = Decoder::new_strict(&[0x30, 0x03, 0x02, 0x01, 0x2A]);
+        let x = decoder
+            .sequence(|nested| {
+                assert!(nested.is_strict());
+                nested.decode::<u8>()
+            })
+            .unwrap();
+
+        assert_eq!(42, x);
+        assert!(!Decoder::new(&[]).is_strict());
+    }
+
+    #[test]
+    fn strict_nested_decoders() {
+        for &strict in &[false, true] {
+            let decoder = |bytes| {
+                if strict {
+                    Decoder::new_strict(bytes)
+                } else {
+                    Decoder::new(bytes)
+                }
+            };
+
+            // `[0] EXPLICIT NULL`
+            let explicit = decoder(&[0xA0, 0x02, 0x05, 0x00])
+                .context_specific::<IsStrict>(TagNumber::new(0), TagMode::Explicit)
+                .unwrap();
+            assert_eq!(explicit, Some(IsStrict(strict)));
+
+            let context_specific = decoder(&[0xA0, 0x02, 0x05, 0x00])
+                .decode::<ContextSpecific<IsStrict>>()
+                .unwrap();
+            assert_eq!(context_specific.value, IsStrict(strict));
+
+            // `SEQUENCE OF NULL`
+            let array = decoder(&[0x30, 0x02, 0x05, 0x00])
+                .decode::<[IsStrict; 1]>()
+                .unwrap();
+            assert_eq!(array, [IsStrict(strict)]);
+
+            let any = decoder(&[0x05, 0x00]).any().unwrap();
+            assert_eq!(any.decode_into::<IsStrict>().unwrap(), IsStrict(strict));
+
+            let any = decoder(&[0x30, 0x02, 0x05, 0x00]).any().unwrap();
+            let nested = any.sequence(|nested| nested.decode::<IsStrict>()).unwrap();
+            assert_eq!(nested, IsStrict(strict));
+        }
+    }
+
+    #[test]
+    fn decode_array() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03]);
+        let array: [u8; 3] = decoder.decode_array().unwrap();
+        assert_eq!(array, [1, 2, 3]);
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn decode_large_array() {
+        let der = [0x01, 0x01, 0xFF].repeat(40);
+        let mut decoder = Decoder::new(&der);
+        let array: [bool; 40] = decoder.decode_array().unwrap();
+        assert!(array.iter().all(|&b| b));
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn decode_array_too_short() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x01, 0x02, 0x01, 0x02]);
+        let err = decoder.decode_array::<u8, 3>().err().unwrap();
+        assert_eq!(ErrorKind::Truncated, err.kind());
+        assert!(decoder.is_failed());
+    }
+
+    #[test]
+    fn decode_array_trailing_data() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03]);
+        let err = decoder.decode_array::<u8, 2>().err().unwrap();
+        assert_eq!(
+            ErrorKind::TrailingData {
+                decoded: 6u8.into(),
+                remaining: 3u8.into()
+            },
+            err.kind()
+        );
+    }
+
+    #[test]
+    fn decode_or_default() {
+        let mut decoder = Decoder::new(&[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+        assert_eq!(decoder.decode_or_default(7u8).unwrap(), 42);
+
+        let position = decoder.position();
+        assert_eq!(decoder.decode_or_default(2048u16).unwrap(), 2048);
+        assert_eq!(decoder.position(), position);
+
+        assert!(decoder.decode::<bool>().unwrap());
+        assert_eq!(decoder.decode_or_default(2048u16).unwrap(), 2048);
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn from_reader() {
+        let mut reader = SliceReader::new(&[0x30, 0x06, 0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF]);
+        let mut decoder = Decoder::from_reader(&mut reader);
+        let (x, y) = decoder
+            .sequence(|nested| Ok((nested.decode::<u8>()?, nested.decode::<bool>()?)))
+            .unwrap();
+
+        assert_eq!((x, y), (42, true));
+        assert!(decoder.is_finished());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_io_reader() {
+        use crate::IoReader;
+
+        let input: &[u8] = &[0x30, 0x06, 0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF];
+        let mut buffer = [0u8; 8];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+        let mut decoder = Decoder::from_reader(&mut reader);
+        let (x, y) = decoder
+            .sequence(|nested| Ok((nested.decode::<u8>()?, nested.decode::<bool>()?)))
+            .unwrap();
+
+        assert_eq!((x, y), (42, true));
+        assert!(decoder.is_finished());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_io_reader_errors() {
+        use crate::IoReader;
+
+        let input: &[u8] = &[0x30, 0x06, 0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF];
+
+        // Buffer too small to hold the message
+        let mut buffer = [0u8; 4];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+        let err = Decoder::from_reader(&mut reader)
+            .sequence(|nested| nested.decode::<u8>())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+
+        // Truncated input
+        let mut buffer = [0u8; 8];
+        let mut reader = IoReader::new(std::io::Cursor::new(&input[..7]), &mut buffer).unwrap();
+        let err = Decoder::from_reader(&mut reader)
+            .sequence(|nested| nested.decode::<u8>())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_io_reader_trailing_data() {
+        use crate::IoReader;
+
+        let input: &[u8] = &[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF, 0x05, 0x00];
+
+        let mut buffer = [0u8; 8];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+        let mut decoder = Decoder::from_reader(&mut reader);
+        let x = decoder.decode::<u8>().unwrap();
+        let err = decoder.finish(x).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TrailingData {
+                decoded: 3u8.into(),
+                remaining: 5u8.into()
+            }
+        );
+
+        let mut buffer = [0u8; 8];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+        let err = Decoder::from_reader(&mut reader)
+            .decode_array::<u8, 1>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TrailingData {
+                decoded: 3u8.into(),
+                remaining: 5u8.into()
+            }
+        );
+
+        // Trailing data which doesn't fit in the buffer can't be counted
+        let mut buffer = [0u8; 3];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+        let mut decoder = Decoder::from_reader(&mut reader);
+        assert_eq!(decoder.decode::<u8>().unwrap(), 42);
+        assert!(!decoder.is_finished());
+        assert_eq!(
+            decoder.finish(()).unwrap_err().kind(),
+            ErrorKind::Overlength
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_io_reader_reborrow() {
+        use crate::IoReader;
+
+        let input: &[u8] = &[0x02, 0x01, 0x2A, 0x01, 0x01, 0xFF];
+        let mut buffer = [0u8; 6];
+        let mut reader = IoReader::new(std::io::Cursor::new(input), &mut buffer).unwrap();
+
+        // The reader can be used again once the first decoder is done with it
+        assert_eq!(
+            Decoder::from_reader(&mut reader).decode::<u8>().unwrap(),
+            42
+        );
+        let mut decoder = Decoder::from_reader(&mut reader);
+        assert!(decoder.decode::<bool>().unwrap());
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn trailing_data_in_constructed_value() {
+        // SEQUENCE { INTEGER 42, INTEGER 43 }
+        let der = &[0x30, 0x06, 0x02, 0x01, 0x2A, 0x02, 0x01, 0x2B];
+        let mut decoder = Decoder::new(der);
+        let err = decoder.decode::<Lenient>().unwrap_err();
+
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TrailingData {
+                decoded: Length::from(3u8),
+                remaining: Length::from(3u8)
+            }
+        );
+    }
+
+    #[test]
+    fn trailing_data_in_implicit_context_specific() {
+        // [0] IMPLICIT SEQUENCE { INTEGER 42, INTEGER 43 }
+        let der = &[0xA0, 0x06, 0x02, 0x01, 0x2A, 0x02, 0x01, 0x2B];
+        let mut decoder = Decoder::new(der);
+        let err = ContextSpecific::<Lenient>::decode_implicit(&mut decoder, TagNumber::new(0))
+            .unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+
+    #[test]
+    fn overlong_read_in_constructed_value() {
+        // SEQUENCE { } followed by INTEGER 42
+        let der = &[0x30, 0x00, 0x02, 0x01, 0x2A];
+        let mut decoder = Decoder::new(der);
+        let err = decoder.decode::<Lenient>().unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Sequence });
+    }
+
+    #[test]
+    fn backtrack_with_set_position() {
+        // INTEGER 42, UTF8String "hi"
+        let der = &[0x02, 0x01, 0x2A, 0x0C, 0x02, 0x68, 0x69];
+        let mut decoder = Decoder::new(der);
+        assert_eq!(decoder.decode::<u8>().unwrap(), 42);
+
+        let position = decoder.position();
+        assert!(decoder.decode::<bool>().is_err());
+        assert!(decoder.is_failed());
+
+        decoder.set_position(position).unwrap();
+        assert!(!decoder.is_failed());
+
+        let s = decoder.utf8_string().unwrap();
+        assert_eq!(s.as_str(), "hi");
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn set_position_errors() {
+        let der = &[0x02, 0x01, 0x2A];
+        let mut decoder = Decoder::new(der);
+        let err = decoder.set_position(Length::ONE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Position);
+
+        let mut reader = SliceReader::new(der);
+        let mut decoder = Decoder::from_reader(&mut reader);
+        assert_eq!(decoder.decode::<u8>().unwrap(), 42);
+        let err = decoder.set_position(Length::ZERO).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Position);
+    }
 }
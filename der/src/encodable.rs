@@ -1,6 +1,6 @@
 //! Trait definition for [`Encodable`].
 
-use crate::{EncodeValue, Encoder, Header, Length, Result, Tagged};
+use crate::{EncodeValue, Encoder, Length, Result, Tagged, Writer};
 
 #[cfg(feature = "alloc")]
 use {
@@ -28,6 +28,14 @@ pub trait Encodable {
         encoder.finish()
     }
 
+    /// Encode this value as ASN.1 DER to the provided [`Writer`], returning
+    /// the number of bytes written.
+    fn encode_to_writer(&self, writer: &mut dyn Writer) -> Result<Length> {
+        let mut encoder = Encoder::from_writer(writer);
+        self.encode(&mut encoder)?;
+        encoder.finish_len()
+    }
+
     /// Encode this message as ASN.1 DER, appending it to the provided
     /// byte vector.
     #[cfg(feature = "alloc")]
@@ -73,7 +81,26 @@ where
 
     /// Encode this value as ASN.1 DER using the provided [`Encoder`].
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        Header::new(T::TAG, self.value_len()?)?.encode(encoder)?;
-        self.encode_value(encoder)
+        encoder.tlv(
+            T::TAG,
+            || self.value_len(),
+            |encoder| self.encode_value(encoder),
+        )
+    }
+}
+
+/// Trait objects are encoded as the value they refer to.
+///
+/// This makes it possible to encode an `Option<&dyn Encodable>`, which is
+/// useful for conditionally including a field in a hand-written
+/// [`Message::fields`][`crate::Message::fields`] impl: `None` is encoded as
+/// nothing at all, i.e. the field is omitted.
+impl Encodable for &dyn Encodable {
+    fn encoded_len(&self) -> Result<Length> {
+        (**self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        (**self).encode(encoder)
     }
 }
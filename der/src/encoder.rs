@@ -1,18 +1,27 @@
 //! DER encoder.
 
 use crate::{
-    asn1::*, message, Encodable, EncodeValue, Error, ErrorKind, Header, Length, Result, Tag,
-    TagMode, TagNumber, Tagged,
+    asn1::*, message, writer::SliceWriter, Decodable, Encodable, EncodeValue, Error, ErrorKind,
+    Header, Length, Result, Tag, TagMode, TagNumber, Tagged, Writer,
 };
-use core::convert::{TryFrom, TryInto};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt,
+};
+
+#[cfg(feature = "test-vectors")]
+use crate::LengthForm;
 
 /// DER encoder.
 #[derive(Debug)]
 pub struct Encoder<'a> {
-    /// Buffer into which DER-encoded message is written
-    bytes: Option<&'a mut [u8]>,
+    /// Output sink into which the DER-encoded message is written.
+    ///
+    /// In the event an error was previously encountered this will be set to
+    /// `None` to prevent further encoding while in a bad state.
+    output: Option<Output<'a>>,
 
-    /// Total number of bytes written to buffer so far
+    /// Total number of bytes written so far
     position: Length,
 }
 
@@ -20,7 +29,15 @@ impl<'a> Encoder<'a> {
     /// Create a new encoder with the given byte slice as a backing buffer.
     pub fn new(bytes: &'a mut [u8]) -> Self {
         Self {
-            bytes: Some(bytes),
+            output: Some(Output::Slice(SliceWriter::new(bytes))),
+            position: Length::ZERO,
+        }
+    }
+
+    /// Create a new encoder which writes its output to the given [`Writer`].
+    pub fn from_writer(writer: &'a mut dyn Writer) -> Self {
+        Self {
+            output: Some(Output::Writer(writer)),
             position: Length::ZERO,
         }
     }
@@ -32,40 +49,86 @@ impl<'a> Encoder<'a> {
         }
 
         encodable.encode(self).map_err(|e| {
-            self.bytes.take();
+            self.output.take();
             e.nested(self.position)
         })
     }
 
+    /// Encode a value which has already been DER-encoded, such as the raw
+    /// TLV bytes of a previously decoded element, writing it out verbatim.
+    ///
+    /// Only the header of `tlv` is checked: it must describe exactly one
+    /// value which spans all of `tlv`. The value itself is not re-parsed.
+    pub fn encode_raw(&mut self, tlv: &[u8]) -> Result<()> {
+        if self.is_failed() {
+            self.error(ErrorKind::Failed)?;
+        }
+
+        if let Err(e) = Any::from_der(tlv) {
+            return self.error(e.kind());
+        }
+
+        self.bytes(tlv)
+    }
+
+    /// Encode a [`Length`] in the given [`LengthForm`], e.g. a long-form
+    /// length where DER requires the short form.
+    ///
+    /// **FOR TESTING ONLY**: this is intended for producing malformed inputs
+    /// to test decoders with, and can produce encodings which aren't valid
+    /// DER. Never use it to encode messages.
+    ///
+    /// Returns [`ErrorKind::Overlength`] if the length doesn't fit in the
+    /// requested form, or if the long form has more than 4 octets.
+    #[cfg(feature = "test-vectors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+    pub fn encode_length_form(&mut self, length: Length, form: LengthForm) -> Result<()> {
+        length
+            .encode_form(form, self)
+            .or_else(|e| self.error(e.kind()))
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     // TODO(tarcieri): change return type to `Error`
     pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
-        self.bytes.take();
+        self.output.take();
         Err(kind.at(self.position))
     }
 
     /// Return an error for an invalid value with the given tag.
     // TODO(tarcieri): compose this with `Encoder::error` after changing its return type
     pub fn value_error(&mut self, tag: Tag) -> Error {
-        self.bytes.take();
+        self.output.take();
         tag.value_error().kind().at(self.position)
     }
 
     /// Did the decoding operation fail due to an error?
     pub fn is_failed(&self) -> bool {
-        self.bytes.is_none()
+        self.output.is_none()
     }
 
     /// Finish encoding to the buffer, returning a slice containing the data
     /// written to the buffer.
+    ///
+    /// Encoders created with [`Encoder::from_writer`] have no buffer, and
+    /// return [`ErrorKind::Failed`]. Use [`Encoder::finish_len`] instead.
     pub fn finish(self) -> Result<&'a [u8]> {
         let pos = self.position;
-        let range = ..usize::try_from(self.position)?;
 
-        match self.bytes {
-            Some(bytes) => bytes.get(range).ok_or_else(|| ErrorKind::Truncated.at(pos)),
-            None => Err(ErrorKind::Failed.at(pos)),
+        match self.output {
+            Some(Output::Slice(writer)) => writer.finish().map_err(|e| e.kind().at(pos)),
+            Some(Output::Writer(_)) | None => Err(ErrorKind::Failed.at(pos)),
+        }
+    }
+
+    /// Finish encoding, returning the number of bytes written to the buffer
+    /// or [`Writer`].
+    pub fn finish_len(self) -> Result<Length> {
+        if self.is_failed() {
+            Err(ErrorKind::Failed.at(self.position))
+        } else {
+            Ok(self.position)
         }
     }
 
@@ -114,15 +177,17 @@ impl<'a> Encoder<'a> {
     /// Encode a message with the provided [`Encodable`] fields as an
     /// ASN.1 `SEQUENCE`.
     pub fn message(&mut self, fields: &[&dyn Encodable]) -> Result<()> {
-        let length = message::encoded_len_inner(fields)?;
-
-        self.sequence(length, |nested_encoder| {
-            for field in fields {
-                field.encode(nested_encoder)?;
-            }
+        self.tlv(
+            Tag::Sequence,
+            || message::encoded_len_inner(fields),
+            |nested_encoder| {
+                for field in fields {
+                    field.encode(nested_encoder)?;
+                }
 
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 
     /// Encode an ASN.1 `NULL` value.
@@ -166,10 +231,34 @@ impl<'a> Encoder<'a> {
     {
         Header::new(Tag::Sequence, length).and_then(|header| header.encode(self))?;
 
-        let mut nested_encoder = Encoder::new(self.reserve(length)?);
-        f(&mut nested_encoder)?;
+        let reserved_len = usize::try_from(length)?;
+
+        let nested_result = match self.output.as_mut() {
+            Some(Output::Slice(writer)) => writer
+                .reserve(reserved_len)
+                .and_then(|bytes| Encoder::new(bytes).nested(f)),
+            Some(Output::Writer(writer)) => Encoder::from_writer(&mut **writer).nested(f),
+            None => Err(ErrorKind::Failed.into()),
+        };
+
+        let actual_len = match nested_result {
+            Ok(len) => len,
+            Err(e) => {
+                self.output.take();
+                return Err(e);
+            }
+        };
 
-        if nested_encoder.finish()?.len() == length.try_into()? {
+        // The full reserved length is consumed when writing to a slice,
+        // whereas a `Writer` has only received what was actually written
+        let consumed_len = match self.output {
+            Some(Output::Slice(_)) => length,
+            _ => actual_len,
+        };
+
+        self.position = (self.position + consumed_len).or_else(|e| self.error(e.kind()))?;
+
+        if actual_len == length {
             Ok(())
         } else {
             self.error(ErrorKind::Length { tag: Tag::Sequence })
@@ -192,78 +281,260 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
-    /// Reserve a portion of the internal buffer, updating the internal cursor
-    /// position and returning a mutable slice.
-    fn reserve(&mut self, len: impl TryInto<Length>) -> Result<&mut [u8]> {
-        let len = len
-            .try_into()
-            .or_else(|_| self.error(ErrorKind::Overflow))?;
+    /// Encode a TLV-encoded value with the given [`Tag`], whose value is
+    /// written by `f` and has the length computed by `value_len`.
+    ///
+    /// Constructed values written to a slice are encoded in a single pass:
+    /// the value is written first, then the header is inserted in front of
+    /// it. This avoids computing `value_len` up front, which for nested
+    /// values would traverse each of them once per level of nesting.
+    /// [`Writer`]s need the header first, so `value_len` is used for them.
+    pub(crate) fn tlv<L, F>(&mut self, tag: Tag, value_len: L, f: F) -> Result<()>
+    where
+        L: FnOnce() -> Result<Length>,
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        let writer = match self.output.as_mut() {
+            Some(Output::Slice(writer)) if tag.is_constructed() => writer,
+            _ => {
+                Header::new(tag, value_len()?)?.encode(self)?;
+                return f(self);
+            }
+        };
 
-        if len > self.remaining_len()? {
-            self.error(ErrorKind::Overlength)?;
+        match writer.write_with(|buf| encode_tlv_into(buf, tag, f)) {
+            Ok(len) => {
+                self.position = (self.position + len).or_else(|e| self.error(e.kind()))?;
+                Ok(())
+            }
+            Err(e) => {
+                self.output.take();
+                Err(e)
+            }
         }
+    }
 
-        let end = (self.position + len).or_else(|e| self.error(e.kind()))?;
-        let range = self.position.try_into()?..end.try_into()?;
-        let position = &mut self.position;
+    /// Run the provided closure on this (nested) encoder, returning the
+    /// number of bytes it wrote.
+    fn nested<F>(mut self, f: F) -> Result<Length>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        f(&mut self)?;
 
-        // TODO(tarcieri): non-panicking version of this code
-        // We ensure above that the buffer is untainted and there is sufficient
-        // space to perform this slicing operation, however it would be nice to
-        // have fully panic-free code.
-        //
-        // Unfortunately tainting the buffer on error is tricky to do when
-        // potentially holding a reference to the buffer, and failure to taint
-        // it would not uphold the invariant that any errors should taint it.
-        let slice = &mut self.bytes.as_mut().expect("DER encoder tainted")[range];
-        *position = end;
+        if self.is_failed() {
+            return Err(ErrorKind::Failed.at(self.position));
+        }
 
-        Ok(slice)
+        Ok(self.position)
     }
 
-    /// Encode a single byte into the backing buffer.
+    /// Encode a single byte into the output.
     pub(crate) fn byte(&mut self, byte: u8) -> Result<()> {
-        match self.reserve(1u8)?.first_mut() {
-            Some(b) => {
-                *b = byte;
+        self.bytes(&[byte])
+    }
+
+    /// Encode the provided byte slice into the output.
+    pub(crate) fn bytes(&mut self, slice: &[u8]) -> Result<()> {
+        let len = Length::try_from(slice.len()).or_else(|_| self.error(ErrorKind::Overlength))?;
+        let end = (self.position + len).or_else(|e| self.error(e.kind()))?;
+
+        let result = match self.output.as_mut() {
+            Some(Output::Slice(writer)) => writer.write(slice),
+            Some(Output::Writer(writer)) => writer.write(slice),
+            None => return self.error(ErrorKind::Failed),
+        };
+
+        match result {
+            Ok(()) => {
+                self.position = end;
                 Ok(())
             }
-            None => self.error(ErrorKind::Truncated),
+            Err(e) => self.error(e.kind()),
         }
     }
+}
 
-    /// Encode the provided byte slice into the backing buffer.
-    pub(crate) fn bytes(&mut self, slice: &[u8]) -> Result<()> {
-        self.reserve(slice.len())?.copy_from_slice(slice);
-        Ok(())
+/// Length of the shortest possible header, i.e. a single byte each for the
+/// tag and the length.
+const MIN_HEADER_LEN: usize = 2;
+
+/// Encode a TLV-encoded value with the given [`Tag`] whose value is written
+/// by `f` into the start of `buf`, returning the total length written.
+fn encode_tlv_into<F>(buf: &mut [u8], tag: Tag, f: F) -> Result<usize>
+where
+    F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+{
+    // Write the value after the shortest possible header, then move it along
+    // if the header turns out to be longer
+    let value_buf = buf.get_mut(MIN_HEADER_LEN..).ok_or(ErrorKind::Overlength)?;
+    let value_len = Encoder::new(value_buf).nested(f)?;
+
+    let header = Header::new(tag, value_len)?;
+    let header_len = usize::try_from(header.encoded_len()?)?;
+    let value_len = usize::try_from(value_len)?;
+    let total_len = header_len
+        .checked_add(value_len)
+        .ok_or(ErrorKind::Overflow)?;
+
+    if total_len > buf.len() {
+        return Err(ErrorKind::Overlength.into());
     }
 
-    /// Get the size of the buffer in bytes.
-    fn buffer_len(&self) -> Result<Length> {
-        self.bytes
-            .as_ref()
-            .map(|bytes| bytes.len())
-            .ok_or_else(|| ErrorKind::Failed.at(self.position))
-            .and_then(TryInto::try_into)
-    }
+    buf.copy_within(MIN_HEADER_LEN..(MIN_HEADER_LEN + value_len), header_len);
+    header.encode_to_slice(&mut buf[..header_len])?;
+    Ok(total_len)
+}
+
+/// Output sink of an [`Encoder`].
+enum Output<'a> {
+    /// Fixed-size byte slice used as a backing buffer.
+    Slice(SliceWriter<'a>),
 
-    /// Get the number of bytes still remaining in the buffer.
-    fn remaining_len(&self) -> Result<Length> {
-        let buffer_len = usize::try_from(self.buffer_len()?)?;
+    /// Arbitrary [`Writer`].
+    Writer(&'a mut dyn Writer),
+}
 
-        buffer_len
-            .checked_sub(self.position.try_into()?)
-            .ok_or_else(|| ErrorKind::Truncated.at(self.position))
-            .and_then(TryInto::try_into)
+impl fmt::Debug for Output<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Slice(writer) => f.debug_tuple("Slice").field(writer).finish(),
+            Output::Writer(_) => f.debug_tuple("Writer").finish(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Encoder;
-    use crate::{asn1::BitString, Encodable, ErrorKind, Length, TagMode, TagNumber};
+    use crate::{
+        asn1::{BitString, ContextSpecific, OctetString},
+        Encodable, ErrorKind, Length, Result, TagMode, TagNumber, Writer,
+    };
     use hex_literal::hex;
 
+    /// `SEQUENCE { INTEGER 42, SEQUENCE { BOOLEAN TRUE, OCTET STRING 'CAFE'H } }`
+    const NESTED_SEQUENCE: &[u8] = &hex!("300C02012A30070101FF0402CAFE");
+
+    /// Encode [`NESTED_SEQUENCE`] using the given encoder.
+    fn encode_nested_sequence(encoder: &mut Encoder<'_>) -> Result<()> {
+        let octet_string = OctetString::new(&[0xCA, 0xFE])?;
+
+        encoder.sequence(Length::from(12u8), |encoder| {
+            encoder.encode(&42u8)?;
+            encoder.message(&[&true, &octet_string])
+        })
+    }
+
+    /// [`Writer`] which appends output to a fixed-size buffer, failing once
+    /// it is full.
+    struct ArrayWriter {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Writer for ArrayWriter {
+        fn write(&mut self, slice: &[u8]) -> Result<()> {
+            let end = self.len + slice.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(ErrorKind::Overlength)?
+                .copy_from_slice(slice);
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn encode_to_writer() {
+        let mut writer = ArrayWriter {
+            bytes: [0u8; 32],
+            len: 0,
+        };
+
+        let mut encoder = Encoder::from_writer(&mut writer);
+        encode_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish_len().unwrap(), Length::from(14u8));
+        assert_eq!(&writer.bytes[..writer.len], NESTED_SEQUENCE);
+
+        let mut encoder = Encoder::from_writer(&mut writer);
+        encode_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap_err().kind(), ErrorKind::Failed);
+
+        let mut buf = [0u8; 32];
+        let mut encoder = Encoder::new(&mut buf);
+        encode_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap(), NESTED_SEQUENCE);
+    }
+
+    #[test]
+    fn encode_to_writer_error() {
+        let mut writer = ArrayWriter {
+            bytes: [0u8; 32],
+            len: 30,
+        };
+
+        let mut encoder = Encoder::from_writer(&mut writer);
+        let err = encode_nested_sequence(&mut encoder).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert!(encoder.is_failed());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_to_io_writer() {
+        let mut writer = crate::IoWriter::new(std::vec::Vec::new());
+        let octet_string = OctetString::new(&[0xCA, 0xFE]).unwrap();
+        let len = octet_string.encode_to_writer(&mut writer).unwrap();
+        assert_eq!(len, Length::from(4u8));
+        assert_eq!(writer.into_inner(), hex!("0402CAFE"));
+    }
+
+    /// Encode a `SEQUENCE { [0] EXPLICIT OCTET STRING }` whose headers all
+    /// require a multi-byte length.
+    fn encode_long_nested_sequence(encoder: &mut Encoder<'_>) -> Result<()> {
+        let octet_string = OctetString::new(&[0xAB; 300])?;
+        let field = ContextSpecific {
+            tag_number: TagNumber::new(0),
+            tag_mode: TagMode::Explicit,
+            value: octet_string,
+        };
+
+        encoder.message(&[&field])
+    }
+
+    #[test]
+    fn single_pass_output_unchanged() {
+        let mut expected = [0xABu8; 312];
+        expected[..12].copy_from_slice(&hex!("30820134A08201300482012C"));
+
+        let mut buf = [0u8; 312];
+        let mut encoder = Encoder::new(&mut buf);
+        encode_long_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap(), &expected[..]);
+
+        let mut buf = [0u8; 311];
+        let mut encoder = Encoder::new(&mut buf);
+        let err = encode_long_nested_sequence(&mut encoder).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert!(encoder.is_failed());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn single_pass_output_matches_writer() {
+        let mut writer = crate::IoWriter::new(std::vec::Vec::new());
+        let mut encoder = Encoder::from_writer(&mut writer);
+        encode_long_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish_len().unwrap(), Length::from(312u16));
+        let output = writer.into_inner();
+
+        let mut buf = [0u8; 312];
+        let mut encoder = Encoder::new(&mut buf);
+        encode_long_nested_sequence(&mut encoder).unwrap();
+        assert_eq!(encoder.finish().unwrap(), output.as_slice());
+    }
+
     #[test]
     fn overlength_message() {
         let mut buffer = [];
@@ -295,4 +566,82 @@ mod tests {
 
         assert_eq!(EXPECTED_BYTES, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn encode_raw_tlv_in_sequence() {
+        // `SEQUENCE { BOOLEAN TRUE, OCTET STRING 'CAFE'H }` captured verbatim
+        let tlv = &NESTED_SEQUENCE[5..];
+
+        let mut buf = [0u8; 32];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder
+            .sequence(Length::from(12u8), |encoder| {
+                encoder.encode(&42u8)?;
+                encoder.encode_raw(tlv)
+            })
+            .unwrap();
+        assert_eq!(encoder.finish().unwrap(), NESTED_SEQUENCE);
+    }
+
+    #[test]
+    fn reject_encode_raw_malformed_tlv() {
+        let mut buf = [0u8; 32];
+
+        // Trailing data after the first TLV
+        let mut encoder = Encoder::new(&mut buf);
+        let err = encoder.encode_raw(&hex!("0101FF00")).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+        assert!(encoder.is_failed());
+
+        // Header length exceeds the remaining input
+        let mut encoder = Encoder::new(&mut buf);
+        let err = encoder.encode_raw(&hex!("0402CA")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Truncated);
+    }
+
+    #[test]
+    #[cfg(feature = "test-vectors")]
+    fn encode_non_minimal_length_form() {
+        use crate::{asn1::OctetString, Decodable, Decoder, LengthForm, Tag};
+
+        let mut buf = [0u8; 16];
+        let mut encoder = Encoder::new(&mut buf);
+        encoder.encode(&Tag::OctetString).unwrap();
+        encoder
+            .encode_length_form(Length::from(5u8), LengthForm::Long { octets: 1 })
+            .unwrap();
+        encoder.bytes(&[1, 2, 3, 4, 5]).unwrap();
+
+        let der = encoder.finish().unwrap();
+        assert_eq!(der, hex!("0481050102030405"));
+
+        let err = OctetString::decode(&mut Decoder::new_strict(der)).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::OctetString
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "test-vectors")]
+    fn reject_unrepresentable_length_form() {
+        use crate::LengthForm;
+
+        let mut buf = [0u8; 16];
+
+        for &(length, form) in &[
+            (0x80u16, LengthForm::Short),
+            (0x100, LengthForm::Long { octets: 1 }),
+            (0x05, LengthForm::Long { octets: 0 }),
+            (0x05, LengthForm::Long { octets: 5 }),
+        ] {
+            let mut encoder = Encoder::new(&mut buf);
+            let err = encoder
+                .encode_length_form(Length::from(length), form)
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Overlength);
+        }
+    }
 }
@@ -96,6 +96,26 @@ impl From<const_oid::Error> for Error {
     }
 }
 
+#[cfg(feature = "pem")]
+impl From<pem_rfc7468::Error> for Error {
+    fn from(err: pem_rfc7468::Error) -> Error {
+        ErrorKind::Pem(err).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> std::io::Error {
+        match err.kind() {
+            ErrorKind::Io(kind) => std::io::Error::new(kind, err),
+            _ => std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ErrorKind {}
 
@@ -116,7 +136,8 @@ pub enum ErrorKind {
     /// an error and tainted the state of a `Decoder` or `Encoder`.
     ///
     /// Once this occurs, the overall operation has failed and cannot be
-    /// subsequently resumed.
+    /// subsequently resumed, unless the `Decoder` is rewound to an earlier
+    /// position.
     Failed,
 
     /// Incorrect length for a given field.
@@ -131,6 +152,11 @@ pub enum ErrorKind {
         tag: Tag,
     },
 
+    /// I/O errors.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    Io(std::io::ErrorKind),
+
     /// Malformed OID
     MalformedOid,
 
@@ -140,6 +166,15 @@ pub enum ErrorKind {
     /// Message is longer than this library's internal limits support.
     Overlength,
 
+    /// PEM encoding errors.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    Pem(pem_rfc7468::Error),
+
+    /// Invalid position within the input of a `Decoder`, e.g. one which
+    /// it can't be rewound to.
+    Position,
+
     /// Undecoded trailing data at end of message.
     TrailingData {
         /// Length of the decoded data.
@@ -227,9 +262,14 @@ impl fmt::Display for ErrorKind {
             ErrorKind::Noncanonical { tag } => {
                 write!(f, "ASN.1 {} not canonically encoded as DER", tag)
             }
+            #[cfg(feature = "std")]
+            ErrorKind::Io(err) => write!(f, "I/O error: {:?}", err),
             ErrorKind::MalformedOid => write!(f, "malformed OID"),
             ErrorKind::Overflow => write!(f, "integer overflow"),
             ErrorKind::Overlength => write!(f, "DER message is too long"),
+            #[cfg(feature = "pem")]
+            ErrorKind::Pem(e) => write!(f, "PEM error: {}", e),
+            ErrorKind::Position => write!(f, "invalid decoder position"),
             ErrorKind::TrailingData { decoded, remaining } => {
                 write!(
                     f,
@@ -265,3 +305,22 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    #[test]
+    fn into_io_error() {
+        use crate::{Decodable, Error, ErrorKind};
+
+        fn decode_bool(bytes: &[u8]) -> std::io::Result<bool> {
+            Ok(bool::from_der(bytes)?)
+        }
+
+        let err = decode_bool(&[0x01, 0x01]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let der_err = err.get_ref().unwrap().downcast_ref::<Error>().unwrap();
+        assert_eq!(der_err.kind(), ErrorKind::Truncated);
+    }
+}
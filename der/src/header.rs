@@ -1,6 +1,8 @@
 //! ASN.1 DER headers.
 
-use crate::{Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Result, Tag};
+use crate::{
+    Class, Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Result, Tag, TagNumber,
+};
 use core::convert::TryInto;
 
 /// ASN.1 DER headers: tag + length component of TLV-encoded values
@@ -21,6 +23,16 @@ impl Header {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
         Ok(Self { tag, length })
     }
+
+    /// Get the [`Class`] of this header's [`Tag`].
+    pub fn class(self) -> Class {
+        self.tag.class()
+    }
+
+    /// Get the [`TagNumber`] of this header's [`Tag`].
+    pub fn tag_number(self) -> TagNumber {
+        self.tag.number()
+    }
 }
 
 impl Decodable<'_> for Header {
@@ -49,3 +61,21 @@ impl Encodable for Header {
         self.length.encode(encoder)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Header;
+    use crate::{Class, Decoder, Length, TagNumber};
+
+    #[test]
+    fn peek_context_specific_class_and_number() {
+        let mut decoder = Decoder::new(&[0xA3, 0x03, 0x02, 0x01, 0x05]);
+        let header = decoder.decode::<Header>().unwrap();
+        decoder.set_position(Length::ZERO).unwrap();
+
+        assert_eq!(header.class(), Class::ContextSpecific);
+        assert_eq!(header.tag_number(), TagNumber::new(3));
+        assert_eq!(header.length, Length::from(3u8));
+        assert_eq!(decoder.position(), Length::ZERO);
+    }
+}
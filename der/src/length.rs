@@ -38,6 +38,16 @@ impl Length {
         self == Length::ZERO
     }
 
+    /// Subtract `other` from this length, returning `None` on underflow.
+    pub fn checked_sub(self, other: Length) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Length)
+    }
+
+    /// Subtract `other` from this length, clamping at [`Length::ZERO`].
+    pub fn saturating_sub(self, other: Length) -> Self {
+        Length(self.0.saturating_sub(other.0))
+    }
+
     /// Get the length of DER Tag-Length-Value (TLV) encoded data if `self`
     /// is the length of the inner "value" portion of the message.
     pub fn for_tlv(self) -> Result<Self> {
@@ -66,6 +76,30 @@ impl Length {
     }
 }
 
+#[cfg(feature = "test-vectors")]
+impl Length {
+    /// Encode this length in the given [`LengthForm`].
+    pub(crate) fn encode_form(self, form: LengthForm, encoder: &mut Encoder<'_>) -> Result<()> {
+        match form {
+            LengthForm::Short if self.0 < 0x80 => encoder.byte(self.0 as u8),
+            LengthForm::Long {
+                octets: octets @ 1..=4,
+            } => {
+                let bytes = self.0.to_be_bytes();
+                let (leading, trailing) = bytes.split_at(bytes.len() - usize::from(octets));
+
+                if leading.iter().any(|&byte| byte != 0) {
+                    return Err(ErrorKind::Overlength.into());
+                }
+
+                encoder.byte(0x80 | octets)?;
+                encoder.bytes(trailing)
+            }
+            _ => Err(ErrorKind::Overlength.into()),
+        }
+    }
+}
+
 impl Add for Length {
     type Output = Result<Self>;
 
@@ -245,6 +279,27 @@ impl Encodable for Length {
     }
 }
 
+/// Form in which to encode a [`Length`] with [`Encoder::encode_length_form`].
+///
+/// **FOR TESTING ONLY**: only [`LengthForm::Long`] with the minimum number of
+/// octets is valid DER for lengths of `0x80` or more, and
+/// [`LengthForm::Short`] otherwise. Other forms are for producing malformed
+/// inputs to test decoders with.
+#[cfg(feature = "test-vectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LengthForm {
+    /// Short form: a single octet containing a length below `0x80`.
+    Short,
+
+    /// Long form: an initial octet giving the number of subsequent octets
+    /// (1-4) which contain the big endian length.
+    Long {
+        /// Number of subsequent length octets.
+        octets: u8,
+    },
+}
+
 impl fmt::Display for Length {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -319,6 +374,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ordering_across_short_and_long_form() {
+        let short_max = Length::from(0x7Fu8);
+        let long_min = Length::from(0x80u8);
+
+        assert!(short_max < long_min);
+        assert!(long_min < Length::from(0x100u16));
+        assert!(Length::from(0x100u16) < Length::try_from(0x10000u32).unwrap());
+        assert!(Length::try_from(0x10000u32).unwrap() < Length::MAX);
+        assert_eq!(short_max.max(long_min), long_min);
+    }
+
+    #[test]
+    fn checked_and_saturating_sub() {
+        let short_max = Length::from(0x7Fu8);
+        let long_min = Length::from(0x80u8);
+
+        assert_eq!(long_min.checked_sub(short_max), Some(Length::ONE));
+        assert_eq!(short_max.checked_sub(long_min), None);
+        assert_eq!(long_min.saturating_sub(short_max), Length::ONE);
+        assert_eq!(short_max.saturating_sub(long_min), Length::ZERO);
+    }
+
     #[test]
     fn reject_indefinite_lengths() {
         assert!(Length::from_der(&[0x80]).is_err());
@@ -29,16 +29,30 @@
 //! - [`bool`]: ASN.1 `BOOLEAN`
 //! - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`]: ASN.1 `INTEGER`
 //! - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`]: ASN.1 `INTEGER`
+//! - [`NonZeroU8`][`core::num::NonZeroU8`], [`NonZeroU16`][`core::num::NonZeroU16`],
+//!   [`NonZeroU32`][`core::num::NonZeroU32`], [`NonZeroU64`][`core::num::NonZeroU64`],
+//!   [`NonZeroU128`][`core::num::NonZeroU128`]: ASN.1 `INTEGER` which must be positive
 //! - [`str`], [`String`][`alloc::string::String`]: ASN.1 `UTF8String`
 //!   (see also [`Utf8String`]. `String` requires `alloc` feature)
+//! - [`char`]: ASN.1 `UTF8String` containing exactly one character
+//! - [`Vec<u8>`][`alloc::vec::Vec`]: ASN.1 `OCTET STRING` (requires `alloc` feature)
+//! - [`Cow<str>`][`alloc::borrow::Cow`], [`Cow<[u8]>`][`alloc::borrow::Cow`]: ASN.1
+//!   `UTF8String` and `OCTET STRING` respectively (requires `alloc` feature)
+//! - `[T; N]`: ASN.1 `SEQUENCE OF` containing exactly `N` elements
 //! - [`BTreeSet`][`alloc::collections::BTreeSet`]: ASN.1 `SET OF` (requires `alloc` feature)
 //! - [`Option`]: ASN.1 `OPTIONAL`
 //! - [`SystemTime`][`std::time::SystemTime`]: ASN.1 `GeneralizedTime` (requires `std` feature)
+//! - [`IpAddr`][`std::net::IpAddr`], [`Ipv4Addr`][`std::net::Ipv4Addr`],
+//!   [`Ipv6Addr`][`std::net::Ipv6Addr`]: ASN.1 `OCTET STRING` of 4 or 16 bytes
+//!   (requires `std` feature)
 //!
 //! The following ASN.1 types provided by this crate also impl these traits:
 //!
 //! - [`Any`]: ASN.1 `ANY`
 //! - [`BitString`]: ASN.1 `BIT STRING`
+//! - [`Boolean`]: ASN.1 `BOOLEAN` (guaranteed canonical newtype)
+//! - [`DefaultFalse`]: ASN.1 `BOOLEAN DEFAULT FALSE`
+//! - [`EmptySequence`]: ASN.1 `SEQUENCE {}`
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`
 //! - [`Ia5String`]: ASN.1 `IA5String`
 //! - [`Null`]: ASN.1 `NULL`
@@ -47,6 +61,8 @@
 //! - [`PrintableString`]: ASN.1 `PrintableString` (ASCII subset)
 //! - [`Sequence`]: ASN.1 `SEQUENCE`
 //! - [`SetOfRef`]: ASN.1 `SET OF`
+//! - [`SetOfVec`]: ASN.1 `SET OF` for elements without an `Ord` impl (requires `alloc` feature)
+//! - [`IntBytes`]: ASN.1 signed `INTEGER` with raw access to encoded bytes
 //! - [`UIntBytes`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes
 //! - [`UtcTime`]: ASN.1 `UTCTime`
 //! - [`Utf8String`]: ASN.1 `UTF8String`
@@ -315,8 +331,12 @@
 //! [A Warm Welcome to ASN.1 and DER]: https://letsencrypt.org/docs/a-warm-welcome-to-asn1-and-der/
 //!
 //! [`Any`]: asn1::Any
+//! [`IntBytes`]: asn1::IntBytes
 //! [`UIntBytes`]: asn1::UIntBytes
 //! [`BitString`]: asn1::BitString
+//! [`Boolean`]: asn1::Boolean
+//! [`DefaultFalse`]: asn1::DefaultFalse
+//! [`EmptySequence`]: asn1::EmptySequence
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
 //! [`Ia5String`]: asn1::Ia5String
 //! [`Null`]: asn1::Null
@@ -325,6 +345,7 @@
 //! [`PrintableString`]: asn1::PrintableString
 //! [`Sequence`]: asn1::Sequence
 //! [`SetOfRef`]: asn1::SetOfRef
+//! [`SetOfVec`]: asn1::SetOfVec
 //! [`UtcTime`]: asn1::UtcTime
 //! [`Utf8String`]: asn1::Utf8String
 
@@ -346,6 +367,7 @@ extern crate std;
 pub mod asn1;
 
 mod byte_slice;
+mod canonical;
 mod datetime;
 mod decodable;
 mod decoder;
@@ -355,12 +377,19 @@ mod error;
 mod header;
 mod length;
 mod message;
+#[cfg(feature = "pem")]
+mod pem;
+mod reader;
+mod span;
 mod str_slice;
 mod tag;
+mod trace;
 mod value;
+mod writer;
 
 pub use crate::{
     asn1::{Any, Choice},
+    canonical::is_canonical,
     datetime::DateTime,
     decodable::Decodable,
     decoder::Decoder,
@@ -370,10 +399,30 @@ pub use crate::{
     header::Header,
     length::Length,
     message::Message,
+    reader::Reader,
+    span::Span,
     tag::{Class, Tag, TagMode, TagNumber, Tagged},
     value::{DecodeValue, EncodeValue},
+    writer::Writer,
 };
 
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub use {
+    crate::pem::{DecodePem, EncodePem},
+    pem_rfc7468::LineEnding,
+};
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::{reader::IoReader, writer::IoWriter};
+
+#[cfg(feature = "test-vectors")]
+pub use crate::length::LengthForm;
+
+#[cfg(feature = "trace")]
+pub use crate::trace::TraceEvent;
+
 pub(crate) use crate::byte_slice::ByteSlice;
 
 #[cfg(feature = "bigint")]
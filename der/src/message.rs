@@ -57,3 +57,59 @@ pub(super) fn encoded_len_inner(fields: &[&dyn Encodable]) -> Result<Length> {
         sum + encodable.encoded_len()?
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Message;
+    use crate::{Decodable, Decoder, Encodable, Result};
+
+    /// Message with an optional field which is conditionally included using
+    /// an `Option<&dyn Encodable>`.
+    #[derive(Debug, Eq, PartialEq)]
+    struct Extension {
+        version: u8,
+        critical: Option<bool>,
+    }
+
+    impl<'a> Decodable<'a> for Extension {
+        fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+            decoder.sequence(|decoder| {
+                Ok(Self {
+                    version: decoder.decode()?,
+                    critical: decoder.decode()?,
+                })
+            })
+        }
+    }
+
+    impl<'a> Message<'a> for Extension {
+        fn fields<F, T>(&self, f: F) -> Result<T>
+        where
+            F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+        {
+            let critical = self.critical.as_ref().map(|c| c as &dyn Encodable);
+            f(&[&self.version, &critical])
+        }
+    }
+
+    #[test]
+    fn omit_absent_field() {
+        let mut buf = [0u8; 16];
+
+        let ext = Extension {
+            version: 2,
+            critical: None,
+        };
+        let der = ext.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, &[0x30, 0x03, 0x02, 0x01, 0x02]);
+        assert_eq!(Extension::from_der(der).unwrap(), ext);
+
+        let ext = Extension {
+            version: 2,
+            critical: Some(true),
+        };
+        let der = ext.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(der, &[0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF]);
+        assert_eq!(Extension::from_der(der).unwrap(), ext);
+    }
+}
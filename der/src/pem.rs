@@ -0,0 +1,43 @@
+//! PEM encoding support for arbitrary DER messages.
+
+use crate::{Decodable, Encodable, Result};
+use alloc::string::String;
+use pem_rfc7468::LineEnding;
+
+/// PEM encoding extension trait, impl'd for all [`Encodable`] types.
+///
+/// Encodes the message as ASN.1 DER, then Base64-encodes it within PEM
+/// encapsulation boundaries containing the provided type label.
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub trait EncodePem: Encodable {
+    /// Serialize this message as a PEM-encoded string with the given label.
+    fn to_pem(&self, label: &str, line_ending: LineEnding) -> Result<String>;
+}
+
+impl<T: Encodable> EncodePem for T {
+    fn to_pem(&self, label: &str, line_ending: LineEnding) -> Result<String> {
+        let der = self.to_vec()?;
+        Ok(pem_rfc7468::encode_string(label, line_ending, &der)?)
+    }
+}
+
+/// PEM decoding extension trait, impl'd for all [`Decodable`] types which
+/// don't borrow from their input.
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub trait DecodePem: for<'a> Decodable<'a> {
+    /// Parse `Self` from a PEM-encoded string, ensuring its type label
+    /// matches the expected one.
+    fn from_pem(pem: impl AsRef<[u8]>, label: &str) -> Result<Self>;
+}
+
+impl<T: for<'a> Decodable<'a>> DecodePem for T {
+    fn from_pem(pem: impl AsRef<[u8]>, label: &str) -> Result<Self> {
+        let (actual_label, der) = pem_rfc7468::decode_vec(pem.as_ref())?;
+
+        if actual_label != label {
+            return Err(pem_rfc7468::Error::Label.into());
+        }
+
+        Self::from_der(&der)
+    }
+}
@@ -0,0 +1,261 @@
+//! Trait definition for [`Reader`].
+
+use crate::{ErrorKind, Length, Result};
+use core::convert::{TryFrom, TryInto};
+
+/// Input source for DER-encoded messages read by a [`Decoder`].
+///
+/// Decoders created with [`Decoder::new`] read from a byte slice, whereas
+/// [`Decoder::from_reader`] accepts any [`Reader`]. When the `std` feature is
+/// enabled, [`IoReader`] adapts any [`std::io::Read`] type.
+///
+/// Decoded values borrow from the input, so slices returned by a reader
+/// must live for the lifetime `'r`.
+///
+/// [`Decoder`]: crate::Decoder
+/// [`Decoder::new`]: crate::Decoder::new
+/// [`Decoder::from_reader`]: crate::Decoder::from_reader
+pub trait Reader<'r> {
+    /// Peek at the next byte of input without consuming it.
+    ///
+    /// Returns `None` at the end of the input.
+    fn peek_byte(&self) -> Option<u8>;
+
+    /// Read a single byte.
+    fn read_byte(&mut self) -> Result<u8> {
+        match self.read_slice(Length::ONE)? {
+            [byte] => Ok(*byte),
+            _ => Err(ErrorKind::Truncated.into()),
+        }
+    }
+
+    /// Read a slice of exactly `len` bytes.
+    fn read_slice(&mut self, len: Length) -> Result<&'r [u8]>;
+
+    /// Get the number of bytes remaining in the input.
+    ///
+    /// Readers which can't otherwise determine this may read ahead, as long
+    /// as the bytes read are still returned by subsequent reads.
+    fn remaining_len(&mut self) -> Result<Length>;
+}
+
+/// Object-safe view of a [`Reader<'r>`] which returns slices borrowed for
+/// some shorter lifetime `'a`.
+///
+/// This allows [`Decoder::from_reader`] to borrow a reader for less than
+/// the lifetime of its input.
+///
+/// [`Decoder::from_reader`]: crate::Decoder::from_reader
+pub(crate) trait Source<'a> {
+    /// See [`Reader::peek_byte`].
+    fn peek_byte(&self) -> Option<u8>;
+
+    /// See [`Reader::read_slice`].
+    fn read_slice(&mut self, len: Length) -> Result<&'a [u8]>;
+
+    /// See [`Reader::remaining_len`].
+    fn remaining_len(&mut self) -> Result<Length>;
+}
+
+impl<'a, 'r: 'a, R: Reader<'r>> Source<'a> for R {
+    fn peek_byte(&self) -> Option<u8> {
+        Reader::peek_byte(self)
+    }
+
+    fn read_slice(&mut self, len: Length) -> Result<&'a [u8]> {
+        Reader::read_slice(self, len)
+    }
+
+    fn remaining_len(&mut self) -> Result<Length> {
+        Reader::remaining_len(self)
+    }
+}
+
+/// [`Reader`] over a byte slice.
+#[derive(Clone, Debug)]
+pub(crate) struct SliceReader<'a> {
+    /// Byte slice being read.
+    bytes: &'a [u8],
+
+    /// Position within the byte slice.
+    position: usize,
+}
+
+impl<'a> SliceReader<'a> {
+    /// Create a new reader for the given byte slice.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Move the cursor to the given position within the byte slice.
+    pub fn set_position(&mut self, position: usize) -> Result<()> {
+        if position > self.bytes.len() {
+            return Err(ErrorKind::Position.into());
+        }
+
+        self.position = position;
+        Ok(())
+    }
+
+    /// Obtain the remaining bytes from the current cursor position.
+    fn remaining(&self) -> &'a [u8] {
+        self.bytes.get(self.position..).unwrap_or_default()
+    }
+}
+
+impl<'a> Reader<'a> for SliceReader<'a> {
+    fn peek_byte(&self) -> Option<u8> {
+        self.remaining().first().cloned()
+    }
+
+    fn read_slice(&mut self, len: Length) -> Result<&'a [u8]> {
+        let len = usize::try_from(len)?;
+        let result = self.remaining().get(..len).ok_or(ErrorKind::Truncated)?;
+
+        self.position += len;
+        Ok(result)
+    }
+
+    fn remaining_len(&mut self) -> Result<Length> {
+        self.remaining().len().try_into()
+    }
+}
+
+/// [`Reader`] which reads from a [`std::io::Read`] type.
+///
+/// Decoded values borrow from the input, so bytes are read on demand into a
+/// caller-provided buffer which must be large enough to hold the entire
+/// message, otherwise [`ErrorKind::Overlength`] is returned.
+///
+/// The end of the input is only known once it has been reached, so
+/// [`Reader::remaining_len`] reads the rest of the input into the buffer.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct IoReader<'r, R: std::io::Read> {
+    /// Inner reader.
+    reader: R,
+
+    /// Unconsumed portion of the caller-provided buffer.
+    buffer: &'r mut [u8],
+
+    /// Number of bytes at the start of `buffer` which have been read ahead.
+    buffered: usize,
+
+    /// Byte of input which was read after the buffer was full, if any.
+    excess: Option<u8>,
+
+    /// Has the end of the input been reached?
+    eof: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'r, R: std::io::Read> IoReader<'r, R> {
+    /// Create a new reader which reads from `reader` into `buffer`.
+    pub fn new(reader: R, buffer: &'r mut [u8]) -> Result<Self> {
+        let mut io_reader = Self {
+            reader,
+            buffer,
+            buffered: 0,
+            excess: None,
+            eof: false,
+        };
+
+        io_reader.lookahead()?;
+        Ok(io_reader)
+    }
+
+    /// Read ahead until at least `len` bytes are buffered or the end of the
+    /// input is reached.
+    fn fill(&mut self, len: usize) -> Result<()> {
+        while self.buffered < len && !self.eof {
+            match read_some(&mut self.reader, &mut self.buffer[self.buffered..])? {
+                0 => self.eof = true,
+                n => self.buffered += n,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure the next byte of input (if any) is known.
+    fn lookahead(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            self.read_excess()
+        } else {
+            self.fill(1)
+        }
+    }
+
+    /// Check for input beyond a full buffer, keeping its first byte aside so
+    /// that [`Reader::peek_byte`] still works, although it can't be read.
+    fn read_excess(&mut self) -> Result<()> {
+        if !self.eof && self.excess.is_none() {
+            let mut byte = [0u8];
+
+            match read_some(&mut self.reader, &mut byte)? {
+                0 => self.eof = true,
+                _ => self.excess = Some(byte[0]),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'r, R: std::io::Read> Reader<'r> for IoReader<'r, R> {
+    fn peek_byte(&self) -> Option<u8> {
+        self.buffer[..self.buffered]
+            .first()
+            .cloned()
+            .or(self.excess)
+    }
+
+    fn read_slice(&mut self, len: Length) -> Result<&'r [u8]> {
+        let len = usize::try_from(len)?;
+
+        if len > self.buffer.len() {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        self.fill(len)?;
+
+        if len > self.buffered {
+            return Err(ErrorKind::Truncated.into());
+        }
+
+        let (slice, buffer) = core::mem::take(&mut self.buffer).split_at_mut(len);
+        self.buffer = buffer;
+        self.buffered -= len;
+
+        self.lookahead()?;
+        Ok(slice)
+    }
+
+    /// Reads the rest of the input into the buffer, returning
+    /// [`ErrorKind::Overlength`] if it doesn't fit.
+    fn remaining_len(&mut self) -> Result<Length> {
+        self.fill(self.buffer.len())?;
+
+        if self.buffered == self.buffer.len() {
+            self.read_excess()?;
+        }
+
+        match self.excess {
+            Some(_) => Err(ErrorKind::Overlength.into()),
+            None => self.buffered.try_into(),
+        }
+    }
+}
+
+/// Read some bytes from `reader` into `buf`, retrying if interrupted.
+#[cfg(feature = "std")]
+fn read_some(reader: &mut impl std::io::Read, buf: &mut [u8]) -> Result<usize> {
+    loop {
+        match reader.read(buf) {
+            Ok(n) => return Ok(n),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ErrorKind::Io(e.kind()).into()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Byte ranges occupied by decoded values.
+
+use crate::{Length, Result};
+use core::{convert::TryInto, ops::Range};
+
+/// Byte range occupied by a value within the input of a
+/// [`Decoder`](crate::Decoder), as returned by
+/// [`Decoder::decode_spanned`](crate::Decoder::decode_spanned).
+///
+/// The span covers the entire encoding of the value, including its header.
+/// Offsets are relative to the start of the outermost decoder's input, even
+/// for values decoded by nested decoders (e.g. those passed to
+/// [`Decoder::sequence`](crate::Decoder::sequence)), including those which
+/// decode the value of an [`Any`](crate::asn1::Any) decoded from that input.
+/// Values decoded from an [`Any`](crate::asn1::Any) which was created rather
+/// than decoded have offsets relative to the start of its value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Span {
+    /// Offset of the first byte of the value.
+    pub offset: Length,
+
+    /// Length of the value's encoding.
+    pub length: Length,
+}
+
+impl Span {
+    /// Get the offset immediately following the end of the value.
+    pub fn end(&self) -> Result<Length> {
+        self.offset + self.length
+    }
+
+    /// Get this span as a [`Range`] suitable for indexing into the original
+    /// input.
+    pub fn range(&self) -> Result<Range<usize>> {
+        Ok(self.offset.try_into()?..self.end()?.try_into()?)
+    }
+}
@@ -54,6 +54,9 @@ pub enum Tag {
     /// `OBJECT IDENTIFIER` tag: `0x06`.
     ObjectIdentifier,
 
+    /// `ENUMERATED` tag: `0x0A`.
+    Enumerated,
+
     /// `UTF8String` tag: `0x0C`.
     Utf8String,
 
@@ -75,6 +78,9 @@ pub enum Tag {
     /// `GeneralizedTime` tag: `0x18`.
     GeneralizedTime,
 
+    /// `VisibleString` tag: `0x1A`.
+    VisibleString,
+
     /// Application tag.
     Application {
         /// Is this tag constructed? (vs primitive).
@@ -104,6 +110,30 @@ pub enum Tag {
 }
 
 impl Tag {
+    /// Create an `APPLICATION` tag with the given [`TagNumber`].
+    pub const fn application(number: TagNumber, constructed: bool) -> Tag {
+        Tag::Application {
+            constructed,
+            number,
+        }
+    }
+
+    /// Create a `CONTEXT-SPECIFIC` tag with the given [`TagNumber`].
+    pub const fn context_specific(number: TagNumber, constructed: bool) -> Tag {
+        Tag::ContextSpecific {
+            constructed,
+            number,
+        }
+    }
+
+    /// Create a `PRIVATE` tag with the given [`TagNumber`].
+    pub const fn private(number: TagNumber, constructed: bool) -> Tag {
+        Tag::Private {
+            constructed,
+            number,
+        }
+    }
+
     /// Assert that this [`Tag`] matches the provided expected tag.
     ///
     /// On mismatch, returns an [`Error`] with [`ErrorKind::UnexpectedTag`].
@@ -164,6 +194,7 @@ impl Tag {
             Tag::OctetString => 0x04,
             Tag::Null => 0x05,
             Tag::ObjectIdentifier => 0x06,
+            Tag::Enumerated => 0x0A,
             Tag::Utf8String => 0x0C,
             Tag::Sequence => 0x10 | CONSTRUCTED_FLAG,
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
@@ -171,6 +202,7 @@ impl Tag {
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
+            Tag::VisibleString => 0x1A,
             Tag::Application {
                 constructed,
                 number,
@@ -219,7 +251,9 @@ impl TryFrom<u8> for Tag {
 
     fn try_from(byte: u8) -> Result<Tag> {
         let constructed = byte & CONSTRUCTED_FLAG != 0;
-        let number = TagNumber::try_from(byte & TagNumber::MASK)?;
+        // Tag number 31 indicates the (unsupported) high tag number form
+        let number = TagNumber::try_from(byte & TagNumber::MASK)
+            .map_err(|_| Error::from(ErrorKind::UnknownTag { byte }))?;
 
         match byte {
             0x01 => Ok(Tag::Boolean),
@@ -228,11 +262,13 @@ impl TryFrom<u8> for Tag {
             0x04 => Ok(Tag::OctetString),
             0x05 => Ok(Tag::Null),
             0x06 => Ok(Tag::ObjectIdentifier),
+            0x0A => Ok(Tag::Enumerated),
             0x0C => Ok(Tag::Utf8String),
             0x13 => Ok(Tag::PrintableString),
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
+            0x1A => Ok(Tag::VisibleString),
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
             0x40..=0x7E => Ok(Tag::Application {
@@ -291,12 +327,14 @@ impl fmt::Display for Tag {
             Tag::OctetString => f.write_str("OCTET STRING"),
             Tag::Null => f.write_str("NULL"),
             Tag::ObjectIdentifier => f.write_str("OBJECT IDENTIFIER"),
+            Tag::Enumerated => f.write_str("ENUMERATED"),
             Tag::Utf8String => f.write_str("UTF8String"),
             Tag::Set => f.write_str("SET"),
             Tag::PrintableString => f.write_str("PrintableString"),
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
+            Tag::VisibleString => f.write_str("VisibleString"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
                 constructed,
@@ -336,6 +374,8 @@ impl fmt::Debug for Tag {
 mod tests {
     use super::TagNumber;
     use super::{Class, Tag};
+    use crate::ErrorKind;
+    use core::convert::TryFrom;
 
     #[test]
     fn tag_class() {
@@ -345,12 +385,14 @@ mod tests {
         assert_eq!(Tag::OctetString.class(), Class::Universal);
         assert_eq!(Tag::Null.class(), Class::Universal);
         assert_eq!(Tag::ObjectIdentifier.class(), Class::Universal);
+        assert_eq!(Tag::Enumerated.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::VisibleString.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
@@ -386,4 +428,82 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn tag_constructors() {
+        for num in 0..=30 {
+            for &constructed in &[false, true] {
+                let number = TagNumber::try_from(num).unwrap();
+
+                assert_eq!(
+                    Tag::application(number, constructed).octet(),
+                    Tag::Application {
+                        constructed,
+                        number
+                    }
+                    .octet()
+                );
+
+                assert_eq!(
+                    Tag::context_specific(number, constructed).octet(),
+                    Tag::ContextSpecific {
+                        constructed,
+                        number
+                    }
+                    .octet()
+                );
+
+                assert_eq!(
+                    Tag::private(number, constructed).octet(),
+                    Tag::Private {
+                        constructed,
+                        number
+                    }
+                    .octet()
+                );
+            }
+        }
+
+        assert_eq!(Tag::context_specific(TagNumber::new(0), true).octet(), 0xA0);
+        assert_eq!(
+            Tag::context_specific(TagNumber::new(1), false).octet(),
+            0x81
+        );
+        assert!(TagNumber::try_from(31).is_err());
+    }
+
+    #[test]
+    fn try_from_all_bytes() {
+        const UNIVERSAL: &[u8] = &[
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x0A, 0x0C, 0x13, 0x16, 0x17, 0x18, 0x1A, 0x30,
+            0x31,
+        ];
+
+        for byte in 0..=0xFFu8 {
+            let class = match byte >> 6 {
+                0b00 => Class::Universal,
+                0b01 => Class::Application,
+                0b10 => Class::ContextSpecific,
+                _ => Class::Private,
+            };
+
+            let supported = if class == Class::Universal {
+                UNIVERSAL.contains(&byte)
+            } else {
+                byte & TagNumber::MASK != TagNumber::MASK
+            };
+
+            match Tag::try_from(byte) {
+                Ok(tag) => {
+                    assert!(supported, "byte 0x{:02x} decoded as {}", byte, tag);
+                    assert_eq!(tag.octet(), byte);
+                    assert_eq!(tag.class(), class);
+                }
+                Err(err) => {
+                    assert!(!supported, "byte 0x{:02x} rejected", byte);
+                    assert_eq!(err.kind(), ErrorKind::UnknownTag { byte });
+                }
+            }
+        }
+    }
 }
@@ -31,7 +31,7 @@ impl TagNumber {
     /// Create a new tag number (const-friendly).
     ///
     /// Panics if the tag number is greater than [`TagNumber::MAX`]. For a fallible
-    /// conversion, use [`TryFrom`] instead.
+    /// conversion, use [`TagNumber::try_new`] instead.
     #[allow(clippy::no_effect)]
     pub const fn new(byte: u8) -> Self {
         // TODO(tarcieri): hax! use const panic when available
@@ -39,6 +39,12 @@ impl TagNumber {
         Self(byte)
     }
 
+    /// Create a new tag number, returning an error if it is greater than
+    /// [`TagNumber::MAX`].
+    pub fn try_new(byte: u8) -> Result<Self> {
+        Self::try_from(byte)
+    }
+
     /// Create an `APPLICATION` tag with this tag number.
     pub fn application(self, constructed: bool) -> Tag {
         Tag::Application {
@@ -91,3 +97,23 @@ impl fmt::Display for TagNumber {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TagNumber;
+    use crate::ErrorKind;
+
+    #[test]
+    fn try_new_in_range() {
+        assert_eq!(TagNumber::try_new(0).unwrap(), TagNumber::new(0));
+        assert_eq!(TagNumber::try_new(30).unwrap().value(), TagNumber::MAX);
+    }
+
+    #[test]
+    fn try_new_out_of_range() {
+        for &byte in &[31, 32, 255] {
+            let err = TagNumber::try_new(byte).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnknownTag { byte });
+        }
+    }
+}
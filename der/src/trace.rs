@@ -0,0 +1,160 @@
+//! Decoder tracing support.
+
+use crate::{Header, Length, Result};
+
+#[cfg(feature = "trace")]
+use {
+    crate::{Encodable, Tag},
+    core::fmt,
+};
+
+#[cfg(not(feature = "trace"))]
+use core::marker::PhantomData;
+
+/// Event emitted by a [`Decoder`] with a tracer attached, as registered
+/// using [`Decoder::with_tracer`].
+///
+/// Offsets are relative to the start of the outermost decoder's input, even
+/// for values decoded by nested decoders (e.g. those passed to
+/// [`Decoder::sequence`]).
+///
+/// [`Decoder`]: crate::Decoder
+/// [`Decoder::with_tracer`]: crate::Decoder::with_tracer
+/// [`Decoder::sequence`]: crate::Decoder::sequence
+#[cfg(feature = "trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "trace")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TraceEvent {
+    /// Decoding of a TLV value has begun.
+    Enter {
+        /// Tag of the value.
+        tag: Tag,
+
+        /// Length of the value's body.
+        length: Length,
+
+        /// Offset of the value's header.
+        offset: Length,
+    },
+
+    /// A TLV value was decoded successfully.
+    Exit {
+        /// Tag of the value.
+        tag: Tag,
+
+        /// Length of the value's body.
+        length: Length,
+
+        /// Offset of the value's header.
+        offset: Length,
+    },
+}
+
+/// Tracer attached to a [`Decoder`](crate::Decoder).
+#[cfg(feature = "trace")]
+#[derive(Default)]
+pub(crate) struct Tracer<'a> {
+    /// Callback invoked with each event.
+    callback: Option<&'a mut dyn FnMut(TraceEvent)>,
+
+    /// Offset of the decoder's input relative to the outermost decoder.
+    offset: Length,
+}
+
+#[cfg(feature = "trace")]
+impl<'a> Tracer<'a> {
+    /// Create a new tracer which invokes the given callback.
+    pub fn new(callback: &'a mut dyn FnMut(TraceEvent)) -> Self {
+        Self {
+            callback: Some(callback),
+            offset: Length::ZERO,
+        }
+    }
+
+    /// Move the callback into a tracer for a nested decoder whose input
+    /// begins at the given position of the current decoder.
+    pub fn nest(&mut self, position: Length) -> Result<Self> {
+        Ok(Self {
+            callback: self.callback.take(),
+            offset: (self.offset + position)?,
+        })
+    }
+
+    /// Take back the callback from a tracer created with [`Tracer::nest`].
+    pub fn restore(&mut self, nested: &mut Self) {
+        self.callback = nested.callback.take();
+    }
+
+    /// Emit an event for the value with the given header, whose body begins
+    /// at the given position.
+    pub fn enter(&mut self, header: Header, position: Length) -> Result<()> {
+        let offset = self.header_offset(header, position)?;
+        self.emit(TraceEvent::Enter {
+            tag: header.tag,
+            length: header.length,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Emit an event for the value with the given header, whose body begins
+    /// at the given position.
+    pub fn exit(&mut self, header: Header, position: Length) -> Result<()> {
+        let offset = self.header_offset(header, position)?;
+        self.emit(TraceEvent::Exit {
+            tag: header.tag,
+            length: header.length,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Compute the offset of the given header from the position of the body
+    /// which follows it.
+    fn header_offset(&self, header: Header, position: Length) -> Result<Length> {
+        (self.offset + position)? - header.encoded_len()?
+    }
+
+    /// Invoke the callback (if any) with the given event.
+    fn emit(&mut self, event: TraceEvent) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(event);
+        }
+    }
+}
+
+#[cfg(feature = "trace")]
+impl fmt::Debug for Tracer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer")
+            .field("enabled", &self.callback.is_some())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+/// No-op tracer used when the `trace` feature is disabled.
+#[cfg(not(feature = "trace"))]
+#[derive(Debug, Default)]
+pub(crate) struct Tracer<'a>(PhantomData<&'a ()>);
+
+#[cfg(not(feature = "trace"))]
+impl Tracer<'_> {
+    /// Create a tracer for a nested decoder.
+    pub fn nest(&mut self, _position: Length) -> Result<Self> {
+        Ok(Self::default())
+    }
+
+    /// Take back the state of a tracer created with [`Tracer::nest`].
+    pub fn restore(&mut self, _nested: &mut Self) {}
+
+    /// Ignore the start of a value.
+    pub fn enter(&mut self, _header: Header, _position: Length) -> Result<()> {
+        Ok(())
+    }
+
+    /// Ignore the end of a value.
+    pub fn exit(&mut self, _header: Header, _position: Length) -> Result<()> {
+        Ok(())
+    }
+}
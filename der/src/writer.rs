@@ -0,0 +1,126 @@
+//! Trait definition for [`Writer`].
+
+use crate::{ErrorKind, Length, Result};
+use core::convert::TryFrom;
+
+/// Output sink for DER-encoded messages written by an [`Encoder`].
+///
+/// Encoders created with [`Encoder::new`] write into a byte slice used as a
+/// backing buffer, whereas [`Encoder::from_writer`] accepts any [`Writer`].
+/// When the `std` feature is enabled, [`IoWriter`] adapts any
+/// [`std::io::Write`] type.
+///
+/// [`Encoder`]: crate::Encoder
+/// [`Encoder::new`]: crate::Encoder::new
+/// [`Encoder::from_writer`]: crate::Encoder::from_writer
+pub trait Writer {
+    /// Write the given DER-encoded bytes as output.
+    fn write(&mut self, slice: &[u8]) -> Result<()>;
+
+    /// Write a single byte.
+    fn write_byte(&mut self, byte: u8) -> Result<()> {
+        self.write(&[byte])
+    }
+}
+
+/// [`Writer`] which writes to a [`std::io::Write`] type.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct IoWriter<W: std::io::Write> {
+    /// Inner writer.
+    writer: W,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+    /// Create a new writer which writes to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Borrow the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Mutably borrow the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwrap the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Writer for IoWriter<W> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        self.writer
+            .write_all(slice)
+            .map_err(|e| ErrorKind::Io(e.kind()).into())
+    }
+}
+
+/// [`Writer`] which writes into a fixed-size byte slice.
+#[derive(Debug)]
+pub(crate) struct SliceWriter<'a> {
+    /// Buffer into which DER-encoded message is written.
+    bytes: &'a mut [u8],
+
+    /// Total number of bytes written to buffer so far.
+    position: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Create a new writer with the given byte slice as a backing buffer.
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Reserve a portion of the internal buffer, updating the internal cursor
+    /// position and returning a mutable slice.
+    pub fn reserve(&mut self, len: usize) -> Result<&mut [u8]> {
+        let end = self.position.checked_add(len).ok_or(ErrorKind::Overflow)?;
+
+        let slice = self
+            .bytes
+            .get_mut(self.position..end)
+            .ok_or(ErrorKind::Overlength)?;
+
+        self.position = end;
+        Ok(slice)
+    }
+
+    /// Write a value of initially unknown length using the provided function,
+    /// which is given the unused portion of the buffer and returns the number
+    /// of bytes it wrote to the start of it.
+    pub fn write_with<F>(&mut self, f: F) -> Result<Length>
+    where
+        F: FnOnce(&mut [u8]) -> Result<usize>,
+    {
+        let remaining = self
+            .bytes
+            .get_mut(self.position..)
+            .ok_or(ErrorKind::Overlength)?;
+
+        let len = f(remaining)?;
+        self.reserve(len)?;
+        Length::try_from(len)
+    }
+
+    /// Finish writing, returning the portion of the buffer written so far.
+    pub fn finish(self) -> Result<&'a [u8]> {
+        let bytes: &'a [u8] = self.bytes;
+        Ok(bytes.get(..self.position).ok_or(ErrorKind::Truncated)?)
+    }
+}
+
+impl Writer for SliceWriter<'_> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        self.reserve(slice.len())?.copy_from_slice(slice);
+        Ok(())
+    }
+}
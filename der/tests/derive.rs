@@ -5,10 +5,10 @@
 
 use der::{
     asn1::{GeneralizedTime, UtcTime},
-    Choice, Decodable, Encodable, Encoder,
+    Choice, Decodable, Encodable, Encoder, ErrorKind, Message, Tag,
 };
 use hex_literal::hex;
-use std::time::Duration;
+use std::{marker::PhantomData, time::Duration};
 
 /// Custom derive test case for the `Choice` mcaro.
 ///
@@ -64,3 +64,151 @@ fn encode_enum_variants() {
     general_time.encode(&mut encoder).unwrap();
     assert_eq!(GENERAL_TIMESTAMP, encoder.finish().unwrap());
 }
+
+/// Custom derive test case for the `Message` macro with fields whose
+/// ASN.1 type is selected using the `#[asn1(type = "...")]` attribute.
+///
+/// ```text
+/// KeyPair ::= SEQUENCE {
+///      publicKey      BIT STRING,
+///      privateKey     OCTET STRING }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct KeyPair<'a> {
+    #[asn1(type = "BIT STRING")]
+    pub public_key: &'a [u8],
+
+    #[asn1(type = "OCTET STRING")]
+    pub private_key: &'a [u8],
+}
+
+const KEY_PAIR: &[u8] = &hex!("30 0b 03 04 00 01 02 03 04 03 04 05 06");
+
+#[test]
+fn decode_message_fields_with_type() {
+    let key_pair = KeyPair::from_der(KEY_PAIR).unwrap();
+    assert_eq!(key_pair.public_key, &[1, 2, 3]);
+    assert_eq!(key_pair.private_key, &[4, 5, 6]);
+}
+
+#[test]
+fn decode_message_fields_with_wrong_type() {
+    // `publicKey` encoded as `OCTET STRING` rather than `BIT STRING`
+    let err = KeyPair::from_der(&hex!("30 0a 04 03 01 02 03 04 03 04 05 06")).unwrap_err();
+
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::BitString),
+            actual: Tag::OctetString
+        }
+    );
+}
+
+#[test]
+fn encode_message_fields_with_type() {
+    let key_pair = KeyPair {
+        public_key: &[1, 2, 3],
+        private_key: &[4, 5, 6],
+    };
+
+    let mut buf = [0u8; 128];
+    assert_eq!(KEY_PAIR, key_pair.encode_to_slice(&mut buf).unwrap());
+}
+
+/// Custom derive test case for the `Message` macro with a field excluded
+/// from encoding/decoding using the `#[asn1(skip)]` attribute.
+///
+/// ```text
+/// TaggedKey ::= SEQUENCE {
+///      privateKey     OCTET STRING }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct TaggedKey<'a, K> {
+    #[asn1(type = "OCTET STRING")]
+    pub private_key: &'a [u8],
+
+    #[asn1(skip)]
+    pub key_type: PhantomData<K>,
+}
+
+/// Marker type for [`TaggedKey`] which doesn't impl any ASN.1 traits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Ed25519;
+
+const TAGGED_KEY: &[u8] = &hex!("30 05 04 03 04 05 06");
+
+#[test]
+fn decode_message_with_skipped_field() {
+    let key = TaggedKey::<'_, Ed25519>::from_der(TAGGED_KEY).unwrap();
+    assert_eq!(key.private_key, &[4, 5, 6]);
+    assert_eq!(key.key_type, PhantomData);
+}
+
+#[test]
+fn encode_message_with_skipped_field() {
+    let key = TaggedKey::<'_, Ed25519> {
+        private_key: &[4, 5, 6],
+        key_type: PhantomData,
+    };
+
+    let mut buf = [0u8; 128];
+    assert_eq!(TAGGED_KEY, key.encode_to_slice(&mut buf).unwrap());
+}
+
+/// Custom derive test case for the `Message` macro with fields encoded in a
+/// different order from their declaration using `#[asn1(order = N)]`.
+///
+/// ```text
+/// OrderedFields ::= SEQUENCE {
+///      version        INTEGER,
+///      notAfter       GeneralizedTime,
+///      critical       BOOLEAN,
+///      algorithm      OCTET STRING }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Message)]
+pub struct OrderedFields<'a> {
+    #[asn1(order = 3)]
+    #[asn1(type = "OCTET STRING")]
+    pub algorithm: &'a [u8],
+
+    #[asn1(order = 0)]
+    pub version: u8,
+
+    #[asn1(skip)]
+    pub cached: bool,
+
+    #[asn1(order = 2)]
+    pub critical: bool,
+
+    #[asn1(order = 1)]
+    #[asn1(type = "GeneralizedTime")]
+    pub not_after: GeneralizedTime,
+}
+
+const ORDERED_FIELDS: &[u8] =
+    &hex!("30 1B 02 01 01 18 0F 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5A 01 01 FF 04 02 01 02");
+
+#[test]
+fn decode_message_with_ordered_fields() {
+    let fields = OrderedFields::from_der(ORDERED_FIELDS).unwrap();
+    assert_eq!(fields.version, 1);
+    assert_eq!(fields.not_after.unix_duration().as_secs(), 673573540);
+    assert!(fields.critical);
+    assert_eq!(fields.algorithm, &[1, 2]);
+    assert!(!fields.cached);
+}
+
+#[test]
+fn encode_message_with_ordered_fields() {
+    let fields = OrderedFields {
+        algorithm: &[1, 2],
+        version: 1,
+        cached: true,
+        critical: true,
+        not_after: GeneralizedTime::new(Duration::from_secs(673573540)).unwrap(),
+    };
+
+    let mut buf = [0u8; 128];
+    assert_eq!(ORDERED_FIELDS, fields.encode_to_slice(&mut buf).unwrap());
+}
@@ -0,0 +1,61 @@
+//! PEM encoding tests
+
+#![cfg(feature = "pem")]
+
+use der::{
+    Decodable, DecodePem, Decoder, Encodable, EncodePem, ErrorKind, LineEnding, Message, Result,
+};
+
+/// Type label used for the PEM-encoded test message.
+const LABEL: &str = "TEST POINT";
+
+/// Custom message used to test PEM encoding.
+///
+/// ```text
+/// Point ::= SEQUENCE {
+///     x INTEGER,
+///     y INTEGER }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl Decodable<'_> for Point {
+    fn decode(decoder: &mut Decoder<'_>) -> Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                x: decoder.decode()?,
+                y: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl Message<'_> for Point {
+    fn fields<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> Result<T>,
+    {
+        f(&[&self.x, &self.y])
+    }
+}
+
+#[test]
+fn pem_round_trip() {
+    let point = Point { x: 1, y: 256 };
+    let pem = point.to_pem(LABEL, LineEnding::LF).unwrap();
+    assert_eq!(
+        pem,
+        "-----BEGIN TEST POINT-----\nMAcCAQECAgEA\n-----END TEST POINT-----\n"
+    );
+    assert_eq!(Point::from_pem(&pem, LABEL).unwrap(), point);
+}
+
+#[test]
+fn pem_label_mismatch() {
+    let pem = Point { x: 1, y: 2 }.to_pem(LABEL, LineEnding::LF).unwrap();
+    let err = Point::from_pem(&pem, "PUBLIC KEY").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Pem(pem_rfc7468::Error::Label));
+}
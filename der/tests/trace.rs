@@ -0,0 +1,78 @@
+//! Decoder tracing tests
+
+#![cfg(feature = "trace")]
+
+use der::{Decoder, Tag, TraceEvent};
+use hex_literal::hex;
+
+/// `SEQUENCE { INTEGER 1, SEQUENCE { BOOLEAN TRUE, NULL } }`
+const NESTED_EXAMPLE: &[u8] = &hex!("300A02010130050101FF0500");
+
+fn enter(tag: Tag, length: u8, offset: u8) -> TraceEvent {
+    TraceEvent::Enter {
+        tag,
+        length: length.into(),
+        offset: offset.into(),
+    }
+}
+
+fn exit(tag: Tag, length: u8, offset: u8) -> TraceEvent {
+    TraceEvent::Exit {
+        tag,
+        length: length.into(),
+        offset: offset.into(),
+    }
+}
+
+#[test]
+fn trace_nested_sequences() {
+    let mut events = Vec::new();
+    let mut tracer = |event| events.push(event);
+    let mut decoder = Decoder::new(NESTED_EXAMPLE).with_tracer(&mut tracer);
+
+    let (int, (boolean, null)) = decoder
+        .sequence(|decoder| {
+            let int = decoder.decode::<u8>()?;
+            let inner = decoder.sequence(|decoder| {
+                let boolean = decoder.decode::<bool>()?;
+                let null = decoder.null()?;
+                Ok((boolean, null))
+            })?;
+            Ok((int, inner))
+        })
+        .unwrap();
+
+    decoder.finish(()).unwrap();
+    assert_eq!((int, boolean, null), (1, true, der::asn1::Null));
+
+    assert_eq!(
+        events,
+        [
+            enter(Tag::Sequence, 10, 0),
+            enter(Tag::Integer, 1, 2),
+            exit(Tag::Integer, 1, 2),
+            enter(Tag::Sequence, 5, 5),
+            enter(Tag::Boolean, 1, 7),
+            exit(Tag::Boolean, 1, 7),
+            enter(Tag::Null, 0, 10),
+            exit(Tag::Null, 0, 10),
+            exit(Tag::Sequence, 5, 5),
+            exit(Tag::Sequence, 10, 0),
+        ]
+    );
+}
+
+#[test]
+fn trace_failed_value() {
+    let mut events = Vec::new();
+    let mut tracer = |event| events.push(event);
+    let mut decoder = Decoder::new(&hex!("0101FF020100")).with_tracer(&mut tracer);
+
+    assert!(decoder.decode::<bool>().unwrap());
+    assert!(decoder.decode::<bool>().is_err());
+
+    assert_eq!(
+        events,
+        [enter(Tag::Boolean, 1, 0), exit(Tag::Boolean, 1, 0),]
+    );
+}
@@ -14,8 +14,8 @@
 use alloc::vec::Vec;
 
 use crate::{
-    grammar, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
-    PRE_ENCAPSULATION_BOUNDARY,
+    grammar, Error, Result, BASE64_WRAP_WIDTH, ENCAPSULATION_BOUNDARY_DELIMITER,
+    POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
 };
 use base64ct::{Base64, Encoding};
 use core::{convert::TryFrom, str};
@@ -25,6 +25,10 @@ use core::{convert::TryFrom, str};
 /// On success, writes the decoded document into the provided buffer, returning
 /// the decoded label and the portion of the provided buffer containing the
 /// decoded message.
+///
+/// The encapsulated text may only contain Base64 characters and line endings:
+/// any other characters, including whitespace within a line, result in an
+/// [`Error::Base64`].
 pub fn decode<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o [u8])> {
     let encapsulation = Encapsulation::try_from(pem)?;
     let label = encapsulation.label();
@@ -52,6 +56,24 @@ pub fn decode_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
     Ok((label, result))
 }
 
+/// Decode a PEM document leniently, skipping a leading UTF-8 byte order mark
+/// (BOM) and any data which appears before the first pre-encapsulation
+/// boundary, even if it isn't on a line of its own.
+///
+/// Once found, the PEM document itself is decoded according to RFC 7468's
+/// "Strict" grammar as with [`decode`].
+pub fn decode_lenient<'i, 'o>(pem: &'i [u8], buf: &'o mut [u8]) -> Result<(&'i str, &'o [u8])> {
+    decode(grammar::strip_preamble_lenient(pem)?, buf)
+}
+
+/// Decode a PEM document leniently as with [`decode_lenient`], returning the
+/// result as a [`Vec`] upon success.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_vec_lenient(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
+    decode_vec(grammar::strip_preamble_lenient(pem)?)
+}
+
 /// Decode the encapsulation boundaries of a PEM document according to RFC 7468's "Strict" grammar.
 ///
 /// On success, returning the decoded label.
@@ -59,6 +81,44 @@ pub fn decode_label(pem: &[u8]) -> Result<&str> {
     Ok(Encapsulation::try_from(pem)?.label())
 }
 
+/// Find the PEM block with the given label in an input which may contain
+/// several PEM blocks, e.g. a bundle of a certificate and its private key.
+///
+/// Blocks with other labels, along with any text in between blocks, are
+/// ignored. Returns the first matching block from its pre-encapsulation
+/// boundary through its post-encapsulation boundary, which can then be
+/// decoded using e.g. [`decode`], or `None` if there is no such block.
+pub fn find_block<'i>(input: &'i str, label: &str) -> Option<&'i str> {
+    let mut block_start = None;
+    let mut line_start = 0;
+
+    for line in input.split_inclusive('\n') {
+        let line_end = line_start + line.trim_end_matches(&['\r', '\n'][..]).len();
+        let boundary = &input.as_bytes()[line_start..line_end];
+
+        match block_start {
+            None if is_boundary(boundary, PRE_ENCAPSULATION_BOUNDARY, label) => {
+                block_start = Some(line_start);
+            }
+            Some(start) if is_boundary(boundary, POST_ENCAPSULATION_BOUNDARY, label) => {
+                return input.get(start..line_end);
+            }
+            _ => (),
+        }
+
+        line_start += line.len();
+    }
+
+    None
+}
+
+/// Is the given line an encapsulation boundary with the given prefix and label?
+fn is_boundary(line: &[u8], prefix: &[u8], label: &str) -> bool {
+    line.strip_prefix(prefix)
+        .and_then(|rest| rest.strip_suffix(ENCAPSULATION_BOUNDARY_DELIMITER))
+        == Some(label.as_bytes())
+}
+
 /// PEM encapsulation parser.
 ///
 /// This parser performs an initial pass over the data, locating the
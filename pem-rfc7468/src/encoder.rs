@@ -17,39 +17,61 @@ pub fn encode<'a>(
     input: &[u8],
     buf: &'a mut [u8],
 ) -> Result<&'a [u8]> {
-    grammar::validate_label(label.as_bytes())?;
-
-    let mut buf = Buffer::new(buf, line_ending);
-    buf.write(PRE_ENCAPSULATION_BOUNDARY)?;
-    buf.write(label.as_bytes())?;
-    buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
-
-    for chunk in input.chunks((BASE64_WRAP_WIDTH * 3) / 4) {
-        buf.write_base64ln(chunk)?;
-    }
+    encode_wrapped(label, BASE64_WRAP_WIDTH, line_ending, input, buf)
+}
 
-    buf.write(POST_ENCAPSULATION_BOUNDARY)?;
-    buf.write(label.as_bytes())?;
-    buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
-    buf.finish()
+/// Encode a PEM document, wrapping the Base64-encoded lines at the given
+/// `line_width` rather than the 64 characters required by RFC 7468.
+///
+/// The line width must be a non-zero multiple of 4 (i.e. the size of a
+/// Base64 quantum), otherwise [`Error::Length`] is returned.
+///
+/// Note that documents wrapped at any width other than 64 characters don't
+/// conform to RFC 7468's "Strict" grammar, and are rejected by [`decode`].
+///
+/// [`decode`]: crate::decode
+pub fn encode_wrapped<'a>(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+    buf: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let mut encoder = Encoder::new_wrapped(label, line_width, line_ending, buf)?;
+    encoder.encode(input)?;
+    encoder.finish()
 }
 
 /// Get the length of a PEM encoded document with the given bytes and label.
 pub fn encoded_len(label: &str, line_ending: LineEnding, input: &[u8]) -> usize {
+    // The default line width is always valid
+    encoded_len_wrapped(label, BASE64_WRAP_WIDTH, line_ending, input).unwrap_or_default()
+}
+
+/// Get the length of a PEM encoded document with the given bytes and label,
+/// with Base64-encoded lines wrapped at the given `line_width`.
+///
+/// See [`encode_wrapped`] for the constraints on `line_width`.
+pub fn encoded_len_wrapped(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<usize> {
+    let chunk_len = wrapped_chunk_len(line_width)?;
+
     // TODO(tarcieri): use checked arithmetic
-    PRE_ENCAPSULATION_BOUNDARY.len()
+    Ok(PRE_ENCAPSULATION_BOUNDARY.len()
         + label.as_bytes().len()
         + ENCAPSULATION_BOUNDARY_DELIMITER.len()
         + line_ending.len()
-        + input
-            .chunks((BASE64_WRAP_WIDTH * 3) / 4)
-            .fold(0, |acc, chunk| {
-                acc + Base64::encoded_len(chunk) + line_ending.len()
-            })
+        + input.chunks(chunk_len).fold(0, |acc, chunk| {
+            acc + Base64::encoded_len(chunk) + line_ending.len()
+        })
         + POST_ENCAPSULATION_BOUNDARY.len()
         + label.as_bytes().len()
         + ENCAPSULATION_BOUNDARY_DELIMITER.len()
-        + line_ending.len()
+        + line_ending.len())
 }
 
 /// Encode a PEM document according to RFC 7468's "Strict" grammar, returning
@@ -57,15 +79,153 @@ pub fn encoded_len(label: &str, line_ending: LineEnding, input: &[u8]) -> usize
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub fn encode_string(label: &str, line_ending: LineEnding, input: &[u8]) -> Result<String> {
-    let mut buf = vec![0u8; encoded_len(label, line_ending, input)];
-    encode(label, line_ending, input, &mut buf)?;
+    encode_string_wrapped(label, BASE64_WRAP_WIDTH, line_ending, input)
+}
+
+/// Encode a PEM document with Base64-encoded lines wrapped at the given
+/// `line_width`, returning the result as a [`String`].
+///
+/// See [`encode_wrapped`] for the constraints on `line_width`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_string_wrapped(
+    label: &str,
+    line_width: usize,
+    line_ending: LineEnding,
+    input: &[u8],
+) -> Result<String> {
+    let mut buf = vec![0u8; encoded_len_wrapped(label, line_width, line_ending, input)?];
+    encode_wrapped(label, line_width, line_ending, input, &mut buf)?;
     String::from_utf8(buf).map_err(|_| Error::CharacterEncoding)
 }
 
+/// Get the number of input bytes encoded on each line when wrapping at the
+/// given `line_width`.
+fn wrapped_chunk_len(line_width: usize) -> Result<usize> {
+    match (line_width / 4, line_width % 4) {
+        (0, _) | (_, 1..=3) => Err(Error::Length),
+        (quanta, _) => Ok(quanta * 3),
+    }
+}
+
+/// Incremental PEM encoder which writes into a fixed-size buffer.
+///
+/// Unlike [`encode`], the input doesn't need to be available as a single
+/// slice: it can be provided in pieces by calling [`Encoder::encode`]
+/// repeatedly, e.g. while serializing a message.
+pub struct Encoder<'l, 'o> {
+    /// Type label.
+    label: &'l str,
+
+    /// Output buffer.
+    buf: Buffer<'o>,
+
+    /// Width at which Base64-encoded lines are wrapped.
+    line_width: usize,
+
+    /// Number of characters written to the current Base64-encoded line.
+    column: usize,
+
+    /// Input bytes which don't yet form a complete Base64 quantum.
+    pending: [u8; 3],
+
+    /// Number of bytes in `pending`.
+    pending_len: usize,
+}
+
+impl<'l, 'o> Encoder<'l, 'o> {
+    /// Create a new encoder which writes a PEM document with the given type
+    /// label into the provided buffer.
+    pub fn new(label: &'l str, line_ending: LineEnding, buf: &'o mut [u8]) -> Result<Self> {
+        Self::new_wrapped(label, BASE64_WRAP_WIDTH, line_ending, buf)
+    }
+
+    /// Create a new encoder which wraps the Base64-encoded lines at the given
+    /// `line_width`.
+    ///
+    /// See [`encode_wrapped`] for the constraints on `line_width`.
+    pub fn new_wrapped(
+        label: &'l str,
+        line_width: usize,
+        line_ending: LineEnding,
+        buf: &'o mut [u8],
+    ) -> Result<Self> {
+        grammar::validate_label(label.as_bytes())?;
+        wrapped_chunk_len(line_width)?;
+
+        let mut buf = Buffer::new(buf, line_ending);
+        buf.write(PRE_ENCAPSULATION_BOUNDARY)?;
+        buf.write(label.as_bytes())?;
+        buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
+
+        Ok(Self {
+            label,
+            buf,
+            line_width,
+            column: 0,
+            pending: [0u8; 3],
+            pending_len: 0,
+        })
+    }
+
+    /// Encode the given input, appending it to any previously encoded data.
+    pub fn encode(&mut self, mut input: &[u8]) -> Result<()> {
+        while !input.is_empty() {
+            let n = (self.pending.len() - self.pending_len).min(input.len());
+            self.pending[self.pending_len..][..n].copy_from_slice(&input[..n]);
+            self.pending_len += n;
+            input = &input[n..];
+
+            if self.pending_len == self.pending.len() {
+                self.flush_pending()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finish encoding, writing the post-encapsulation boundary and
+    /// returning the portion of the buffer containing the PEM document.
+    pub fn finish(mut self) -> Result<&'o [u8]> {
+        self.flush_pending()?;
+
+        if self.column > 0 {
+            self.buf.newline()?;
+        }
+
+        self.buf.write(POST_ENCAPSULATION_BOUNDARY)?;
+        self.buf.write(self.label.as_bytes())?;
+        self.buf.writeln(ENCAPSULATION_BOUNDARY_DELIMITER)?;
+        self.buf.finish()
+    }
+
+    /// Write the pending input as a Base64 quantum, wrapping the line once
+    /// it is full.
+    fn flush_pending(&mut self) -> Result<()> {
+        if self.pending_len == 0 {
+            return Ok(());
+        }
+
+        let pending = &self.pending[..self.pending_len];
+        let reserved = self.buf.reserve(Base64::encoded_len(pending))?;
+        Base64::encode(pending, reserved)?;
+        self.pending_len = 0;
+        self.column += 4;
+
+        if self.column == self.line_width {
+            self.buf.newline()?;
+            self.column = 0;
+        }
+
+        Ok(())
+    }
+}
+
 /// Line endings.
 ///
-/// Use [`LineEnding::default`] to get an appropriate line ending for the
-/// current operating system.
+/// Use [`LineEnding::platform_default`] (or [`LineEnding::default`]) to get an
+/// appropriate line ending for the current operating system. Functions which
+/// accept an explicit [`LineEnding`] can still be used to pick a specific one.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum LineEnding {
@@ -81,18 +241,23 @@ pub enum LineEnding {
 
 impl Default for LineEnding {
     /// Use the line ending for the current OS
-    #[cfg(windows)]
     fn default() -> LineEnding {
-        LineEnding::CRLF
-    }
-    #[cfg(not(windows))]
-    fn default() -> LineEnding {
-        LineEnding::LF
+        LineEnding::platform_default()
     }
 }
 
 #[allow(clippy::len_without_is_empty)]
 impl LineEnding {
+    /// Get the line ending for the target platform: [`LineEnding::CRLF`] on
+    /// Windows and [`LineEnding::LF`] everywhere else.
+    pub const fn platform_default() -> LineEnding {
+        if cfg!(windows) {
+            LineEnding::CRLF
+        } else {
+            LineEnding::LF
+        }
+    }
+
     /// Get the byte serialization of this [`LineEnding`].
     pub fn as_bytes(self) -> &'static [u8] {
         match self {
@@ -140,15 +305,11 @@ impl<'a> Buffer<'a> {
     /// Write a byte slice to the buffer with a newline at the end.
     pub fn writeln(&mut self, slice: &[u8]) -> Result<()> {
         self.write(slice)?;
-        self.write(self.line_ending.as_bytes())
+        self.newline()
     }
 
-    /// Write Base64-encoded data to the buffer.
-    ///
-    /// Automatically adds a newline at the end.
-    pub fn write_base64ln(&mut self, bytes: &[u8]) -> Result<()> {
-        let reserved = self.reserve(Base64::encoded_len(bytes))?;
-        Base64::encode(bytes, reserved)?;
+    /// Write a newline to the buffer.
+    pub fn newline(&mut self) -> Result<()> {
         self.write(self.line_ending.as_bytes())
     }
 
@@ -80,6 +80,26 @@ pub(crate) fn strip_preamble(mut bytes: &[u8]) -> Result<&[u8]> {
     Err(Error::Preamble)
 }
 
+/// UTF-8 encoded byte order mark (BOM).
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+/// Leniently strip a leading UTF-8 byte order mark along with any other data
+/// which appears before the first PEM pre-encapsulation boundary.
+///
+/// Unlike [`strip_preamble`], the boundary need not begin on a new line, and
+/// the skipped data may contain arbitrary bytes (including NUL). The returned
+/// slice starts at the pre-encapsulation boundary, which must still be parsed
+/// according to the strict grammar.
+pub(crate) fn strip_preamble_lenient(bytes: &[u8]) -> Result<&[u8]> {
+    let bytes = bytes.strip_prefix(UTF8_BOM).unwrap_or(bytes);
+
+    bytes
+        .windows(PRE_ENCAPSULATION_BOUNDARY.len())
+        .position(|window| window == PRE_ENCAPSULATION_BOUNDARY)
+        .map(|pos| &bytes[pos..])
+        .ok_or(Error::Preamble)
+}
+
 /// Strip a newline (`eol`) from the beginning of the provided byte slice.
 ///
 /// The newline is considered mandatory and a decoding error will occur if it
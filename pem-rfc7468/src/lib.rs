@@ -112,13 +112,16 @@ mod error;
 mod grammar;
 
 pub use crate::{
-    decoder::{decode, decode_label},
-    encoder::{encode, encoded_len, LineEnding},
+    decoder::{decode, decode_label, decode_lenient, find_block},
+    encoder::{encode, encode_wrapped, encoded_len, encoded_len_wrapped, Encoder, LineEnding},
     error::{Error, Result},
 };
 
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{
+    decoder::{decode_vec, decode_vec_lenient},
+    encoder::{encode_string, encode_string_wrapped},
+};
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
@@ -142,4 +145,4 @@ const ENCAPSULATION_BOUNDARY_DELIMITER: &[u8] = b"-----";
 /// > will encode the remainder of the data (within the 64-character line
 /// > boundary), and they MUST NOT emit extraneous whitespace.  Parsers MAY
 /// > handle other line sizes.
-const BASE64_WRAP_WIDTH: usize = 64;
+pub const BASE64_WRAP_WIDTH: usize = 64;
@@ -86,6 +86,26 @@ impl<'a> EncryptionScheme<'a> {
         }
     }
 
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    ///
+    /// Ciphers which aren't built into this crate are looked up in the
+    /// provided [`pbes2::CipherRegistry`].
+    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_with_registry(
+        &self,
+        password: impl AsRef<[u8]>,
+        ciphertext: &[u8],
+        registry: &pbes2::CipherRegistry<'_>,
+    ) -> Result<Vec<u8>, CryptoError> {
+        match self {
+            Self::Pbes2(params) => params.decrypt_with_registry(password, ciphertext, registry),
+            _ => Err(CryptoError),
+        }
+    }
+
     /// Attempt to decrypt the given ciphertext in-place using a key derived
     /// from the provided password and this scheme's parameters.
     ///
@@ -105,6 +125,27 @@ impl<'a> EncryptionScheme<'a> {
         }
     }
 
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and this scheme's parameters.
+    ///
+    /// Ciphers which aren't built into this crate are looked up in the
+    /// provided [`pbes2::CipherRegistry`].
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with_registry<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        registry: &pbes2::CipherRegistry<'_>,
+    ) -> Result<&'b [u8], CryptoError> {
+        match self {
+            Self::Pbes2(params) => {
+                params.decrypt_in_place_with_registry(password, buffer, registry)
+            }
+            _ => Err(CryptoError),
+        }
+    }
+
     /// Encrypt the given plaintext, allocating and returning a vector
     /// containing the ciphertext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -3,24 +3,35 @@
 //! [RFC 8018 Section 6.2]: https://tools.ietf.org/html/rfc8018#section-6.2
 
 mod kdf;
+mod policy;
 
 #[cfg(feature = "pbes2")]
 mod encryption;
+#[cfg(feature = "pbes2")]
+mod registry;
 
 pub use self::kdf::{
     Kdf, Pbkdf2Params, Pbkdf2Prf, ScryptParams, HMAC_WITH_SHA1_OID, HMAC_WITH_SHA256_OID,
     PBKDF2_OID, SCRYPT_OID,
 };
+pub use self::policy::{DecryptPolicy, PolicyViolation};
+
+#[cfg(feature = "pbes2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+pub use self::{
+    encryption::{CbcDecryptor, DerivedKey},
+    registry::{BlockCipherDecryptor, CipherRegistry},
+};
 
 use crate::{AlgorithmIdentifier, CryptoError};
 use core::convert::{TryFrom, TryInto};
 use der::{
     asn1::{Any, ObjectIdentifier, OctetString},
-    Decodable, Decoder, Encodable, Encoder, Error, ErrorKind, Length, Message,
+    Decodable, Decoder, Encodable, Encoder, Error, Length, Message,
 };
 
 #[cfg(all(feature = "alloc", feature = "pbes2"))]
-use alloc::vec::Vec;
+use {alloc::vec::Vec, zeroize::Zeroize};
 
 /// 128-bit Advanced Encryption Standard (AES) algorithm with Cipher-Block
 /// Chaining (CBC) mode of operation.
@@ -136,6 +147,53 @@ impl<'a> Parameters<'a> {
         Ok(Self { kdf, encryption })
     }
 
+    /// Set whether the key length is explicitly encoded in the `keyLength`
+    /// field of the key derivation function's parameters.
+    ///
+    /// The field is optional (and omitted by default) as the key length is
+    /// implied by the cipher, but some consumers require it to be present.
+    /// When present, it must match the cipher's key size for encryption
+    /// and decryption to succeed.
+    ///
+    /// Returns an error if the key size of the cipher is unknown, i.e. for
+    /// [`EncryptionScheme::Other`].
+    pub fn explicit_key_length(mut self, explicit: bool) -> Result<Self, CryptoError> {
+        let key_length = if explicit {
+            let key_size = self.encryption.builtin_key_size().ok_or(CryptoError)?;
+            Some(u16::try_from(key_size).map_err(|_| CryptoError)?)
+        } else {
+            None
+        };
+
+        match &mut self.kdf {
+            Kdf::Pbkdf2(params) => params.key_length = key_length,
+            Kdf::Scrypt(params) => params.key_length = key_length,
+        }
+
+        Ok(self)
+    }
+
+    /// Set the pseudo-random function used by PBKDF2 to derive the key.
+    ///
+    /// The PRF is encoded in the `prf` field of the PBKDF2 parameters, which
+    /// is omitted when it is the RFC 8018 default of HMAC-SHA1. Constructors
+    /// using PBKDF2 default to [`Pbkdf2Prf::HmacWithSha256`].
+    ///
+    /// Returns an error if the key derivation function isn't PBKDF2, or if
+    /// [`Pbkdf2Prf::HmacWithSha1`] is requested without the `sha1` feature.
+    pub fn with_prf(mut self, prf: Pbkdf2Prf) -> Result<Self, CryptoError> {
+        if prf == Pbkdf2Prf::HmacWithSha1 && !cfg!(feature = "sha1") {
+            return Err(CryptoError);
+        }
+
+        match &mut self.kdf {
+            Kdf::Pbkdf2(params) => params.prf = prf,
+            Kdf::Scrypt(_) => return Err(CryptoError),
+        }
+
+        Ok(self)
+    }
+
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -145,9 +203,35 @@ impl<'a> Parameters<'a> {
         &self,
         password: impl AsRef<[u8]>,
         ciphertext: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        self.decrypt_with_registry(password, ciphertext, &CipherRegistry::default())
+    }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext.
+    ///
+    /// Ciphers which aren't built into this crate are looked up in the
+    /// provided [`CipherRegistry`].
+    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_with_registry(
+        &self,
+        password: impl AsRef<[u8]>,
+        ciphertext: &[u8],
+        registry: &CipherRegistry<'_>,
     ) -> Result<Vec<u8>, CryptoError> {
         let mut buffer = ciphertext.to_vec();
-        let pt_len = self.decrypt_in_place(password, &mut buffer)?.len();
+
+        // Don't leave partially decrypted data behind if decryption fails
+        let pt_len = match self.decrypt_in_place_with_registry(password, &mut buffer, registry) {
+            Ok(pt) => pt.len(),
+            Err(err) => {
+                buffer.as_mut_slice().zeroize();
+                return Err(err);
+            }
+        };
+
         buffer.truncate(pt_len);
         Ok(buffer)
     }
@@ -165,7 +249,89 @@ impl<'a> Parameters<'a> {
         password: impl AsRef<[u8]>,
         buffer: &'b mut [u8],
     ) -> Result<&'b [u8], CryptoError> {
-        encryption::decrypt_in_place(self, password, buffer)
+        self.decrypt_in_place_with_registry(password, buffer, &CipherRegistry::default())
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a key derived
+    /// from the provided password and this scheme's parameters.
+    ///
+    /// Ciphers which aren't built into this crate are looked up in the
+    /// provided [`CipherRegistry`], and an error is returned if none match.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with_registry<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        registry: &CipherRegistry<'_>,
+    ) -> Result<&'b [u8], CryptoError> {
+        encryption::decrypt_in_place(self, password, buffer, registry)
+    }
+
+    /// Initialize an incremental decryptor using a key derived from the
+    /// provided password and this scheme's parameters, after checking that
+    /// they're allowed by the given [`DecryptPolicy`].
+    ///
+    /// This allows decrypting large messages in chunks without holding the
+    /// entire ciphertext in memory. Only AES-CBC is supported.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decryptor(
+        &self,
+        password: impl AsRef<[u8]>,
+        policy: &DecryptPolicy<'_>,
+    ) -> Result<CbcDecryptor, CryptoError> {
+        policy.check(self).map_err(|_| CryptoError)?;
+        CbcDecryptor::new(self, password.as_ref())
+    }
+
+    /// Derive the key used to decrypt messages encrypted with these
+    /// parameters from the provided password.
+    ///
+    /// The key can be reused with [`Parameters::decrypt_in_place_with_key`]
+    /// for other messages encrypted with the same password and KDF
+    /// parameters (including the salt), avoiding the cost of deriving it
+    /// again.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn derive_key(&self, password: impl AsRef<[u8]>) -> Result<DerivedKey<'a>, CryptoError> {
+        let cipher = CipherRegistry::default()
+            .get(self.encryption.oid())
+            .ok_or(CryptoError)?;
+
+        self.kdf.derive_key(password, cipher.key_size())
+    }
+
+    /// Attempt to decrypt the given ciphertext, allocating and returning a
+    /// byte vector containing the plaintext, using a previously derived key.
+    #[cfg(all(feature = "alloc", feature = "pbes2"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_with_key(
+        &self,
+        key: &DerivedKey<'_>,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, CryptoError> {
+        let mut buffer = ciphertext.to_vec();
+        let pt_len = self.decrypt_in_place_with_key(key, &mut buffer)?.len();
+        buffer.truncate(pt_len);
+        Ok(buffer)
+    }
+
+    /// Attempt to decrypt the given ciphertext in-place using a previously
+    /// derived key.
+    ///
+    /// Returns an error if the key wasn't derived using exactly the same KDF
+    /// parameters (including the salt) as these parameters, or if its size
+    /// doesn't match the cipher.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn decrypt_in_place_with_key<'b>(
+        &self,
+        key: &DerivedKey<'_>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], CryptoError> {
+        encryption::decrypt_in_place_with_key(self, key, buffer, &CipherRegistry::default())
     }
 
     /// Encrypt the given plaintext, allocating and returning a vector
@@ -272,19 +438,44 @@ pub enum EncryptionScheme<'a> {
         /// Initialisation vector
         iv: &'a [u8; DES_BLOCK_SIZE],
     },
+
+    /// Cipher which isn't built into this crate.
+    ///
+    /// The OID and parameters are preserved as-is, so such schemes can be
+    /// decoded and re-encoded, but can only be decrypted by providing a
+    /// [`BlockCipherDecryptor`] for them in a [`CipherRegistry`].
+    Other {
+        /// Cipher OID
+        oid: ObjectIdentifier,
+
+        /// Cipher parameters
+        parameters: Option<Any<'a>>,
+    },
 }
 
 impl<'a> EncryptionScheme<'a> {
     /// Get the size of a key used by this algorithm.
+    ///
+    /// The key size of an [`EncryptionScheme::Other`] cipher isn't known to
+    /// this crate, so `0` is returned for it: it's instead the
+    /// [`BlockCipherDecryptor::key_size`] of the cipher registered for its
+    /// OID, which can be looked up with [`CipherRegistry::get`].
     pub fn key_size(&self) -> usize {
+        self.builtin_key_size().unwrap_or(0)
+    }
+
+    /// Get the size of a key used by this algorithm, if it's built into this
+    /// crate.
+    fn builtin_key_size(&self) -> Option<usize> {
         match self {
-            Self::Aes128Cbc { .. } => 16,
-            Self::Aes192Cbc { .. } => 24,
-            Self::Aes256Cbc { .. } => 32,
+            Self::Aes128Cbc { .. } => Some(16),
+            Self::Aes192Cbc { .. } => Some(24),
+            Self::Aes256Cbc { .. } => Some(32),
             #[cfg(feature = "des-insecure")]
-            Self::DesCbc { .. } => 8,
+            Self::DesCbc { .. } => Some(8),
             #[cfg(feature = "3des")]
-            Self::DesEde3Cbc { .. } => 24,
+            Self::DesEde3Cbc { .. } => Some(24),
+            Self::Other { .. } => None,
         }
     }
 
@@ -298,6 +489,27 @@ impl<'a> EncryptionScheme<'a> {
             Self::DesCbc { .. } => DES_CBC_OID,
             #[cfg(feature = "3des")]
             Self::DesEde3Cbc { .. } => DES_EDE3_CBC_OID,
+            Self::Other { oid, .. } => *oid,
+        }
+    }
+
+    /// Get the initialization vector, if any.
+    ///
+    /// For [`EncryptionScheme::Other`] this is the parameters, provided they
+    /// are an `OCTET STRING`.
+    #[cfg(feature = "pbes2")]
+    fn iv(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Aes128Cbc { iv } => Some(*iv),
+            Self::Aes192Cbc { iv } => Some(*iv),
+            Self::Aes256Cbc { iv } => Some(*iv),
+            #[cfg(feature = "des-insecure")]
+            Self::DesCbc { iv } => Some(*iv),
+            #[cfg(feature = "3des")]
+            Self::DesEde3Cbc { iv } => Some(*iv),
+            Self::Other { parameters, .. } => parameters
+                .and_then(|params| params.octet_string().ok())
+                .map(|iv| iv.as_bytes()),
         }
     }
 }
@@ -312,38 +524,36 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
     type Error = Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
-        // TODO(tarcieri): support for non-AES algorithms?
-        let iv = alg.parameters_any()?.octet_string()?.as_bytes();
+        // Built-in ciphers take the IV as their parameters
+        let iv = move || -> der::Result<&'a [u8]> {
+            Ok(alg.parameters_any()?.octet_string()?.as_bytes())
+        };
+
+        // The IV must be exactly one block of the cipher's block size
+        let iv_length_error = |_| der::Tag::OctetString.length_error();
 
         match alg.oid {
             AES_128_CBC_OID => Ok(Self::Aes128Cbc {
-                iv: iv
-                    .try_into()
-                    .map_err(|_| der::Tag::OctetString.value_error())?,
+                iv: iv()?.try_into().map_err(iv_length_error)?,
             }),
             AES_192_CBC_OID => Ok(Self::Aes192Cbc {
-                iv: iv
-                    .try_into()
-                    .map_err(|_| der::Tag::OctetString.value_error())?,
+                iv: iv()?.try_into().map_err(iv_length_error)?,
             }),
             AES_256_CBC_OID => Ok(Self::Aes256Cbc {
-                iv: iv
-                    .try_into()
-                    .map_err(|_| der::Tag::OctetString.value_error())?,
+                iv: iv()?.try_into().map_err(iv_length_error)?,
             }),
             #[cfg(feature = "des-insecure")]
             DES_CBC_OID => Ok(Self::DesCbc {
-                iv: iv[0..DES_BLOCK_SIZE]
-                    .try_into()
-                    .map_err(|_| der::Tag::OctetString.value_error())?,
+                iv: iv()?.try_into().map_err(iv_length_error)?,
             }),
             #[cfg(feature = "3des")]
             DES_EDE3_CBC_OID => Ok(Self::DesEde3Cbc {
-                iv: iv[0..DES_BLOCK_SIZE]
-                    .try_into()
-                    .map_err(|_| der::Tag::OctetString.value_error())?,
+                iv: iv()?.try_into().map_err(iv_length_error)?,
+            }),
+            oid => Ok(Self::Other {
+                oid,
+                parameters: alg.parameters,
             }),
-            oid => Err(ErrorKind::UnknownOid { oid }.into()),
         }
     }
 }
@@ -360,6 +570,9 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
             EncryptionScheme::DesCbc { iv } => iv,
             #[cfg(feature = "3des")]
             EncryptionScheme::DesEde3Cbc { iv } => iv,
+            EncryptionScheme::Other { oid, parameters } => {
+                return Ok(AlgorithmIdentifier { oid, parameters })
+            }
         })?;
 
         Ok(AlgorithmIdentifier {
@@ -1,22 +1,24 @@
 //! PBES2 encryption implementation
 
-use super::{EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams};
+use super::{
+    CipherRegistry, EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams,
+    AES_BLOCK_SIZE,
+};
 use crate::CryptoError;
-use block_modes::block_padding::Pkcs7;
-use block_modes::{BlockMode, Cbc};
-use core::convert::TryInto;
+use block_modes::block_padding::{NoPadding, Pkcs7};
+use block_modes::{cipher::generic_array::GenericArray, BlockMode, Cbc};
+use core::{convert::TryInto, fmt, slice};
 use hmac::{
     digest::{generic_array::ArrayLength, BlockInput, FixedOutput, Reset, Update},
     Hmac,
 };
 use pbkdf2::pbkdf2;
 use scrypt::scrypt;
+use zeroize::Zeroize;
 
 type Aes128Cbc = Cbc<aes::Aes128, Pkcs7>;
 type Aes192Cbc = Cbc<aes::Aes192, Pkcs7>;
 type Aes256Cbc = Cbc<aes::Aes256, Pkcs7>;
-#[cfg(feature = "des-insecure")]
-type DesCbc = Cbc<des::Des, Pkcs7>;
 #[cfg(feature = "3des")]
 type DesEde3Cbc = Cbc<des::TdesEde3, Pkcs7>;
 
@@ -32,7 +34,7 @@ pub fn encrypt_in_place<'b>(
     let encryption_key = EncryptionKey::derive_from_password(
         password.as_ref(),
         &params.kdf,
-        params.encryption.key_size(),
+        params.encryption.builtin_key_size().ok_or(CryptoError)?,
     )?;
 
     match params.encryption {
@@ -59,6 +61,7 @@ pub fn encrypt_in_place<'b>(
         }
         #[cfg(feature = "des-insecure")]
         EncryptionScheme::DesCbc { .. } => Err(CryptoError),
+        EncryptionScheme::Other { .. } => Err(CryptoError),
     }
 }
 
@@ -67,46 +70,250 @@ pub fn decrypt_in_place<'a>(
     params: &Parameters<'_>,
     password: impl AsRef<[u8]>,
     buffer: &'a mut [u8],
+    registry: &CipherRegistry<'_>,
 ) -> Result<&'a [u8], CryptoError> {
-    let encryption_key = EncryptionKey::derive_from_password(
-        password.as_ref(),
-        &params.kdf,
-        params.encryption.key_size(),
-    )?;
+    let cipher = registry.get(params.encryption.oid()).ok_or(CryptoError)?;
+    let iv = params.encryption.iv().ok_or(CryptoError)?;
 
-    match params.encryption {
-        EncryptionScheme::Aes128Cbc { iv } => {
-            let cipher = Aes128Cbc::new_from_slices(encryption_key.as_slice(), iv)
-                .map_err(|_| CryptoError)?;
-            cipher.decrypt(buffer).map_err(|_| CryptoError)
-        }
-        EncryptionScheme::Aes192Cbc { iv } => {
-            let cipher = Aes192Cbc::new_from_slices(encryption_key.as_slice(), iv)
-                .map_err(|_| CryptoError)?;
-            cipher.decrypt(buffer).map_err(|_| CryptoError)
+    let encryption_key =
+        EncryptionKey::derive_from_password(password.as_ref(), &params.kdf, cipher.key_size())?;
+
+    cipher.decrypt_in_place(encryption_key.as_slice(), iv, buffer)
+}
+
+/// Decrypt a message encrypted with PBES2 using a previously derived key
+pub fn decrypt_in_place_with_key<'a>(
+    params: &Parameters<'_>,
+    key: &DerivedKey<'_>,
+    buffer: &'a mut [u8],
+    registry: &CipherRegistry<'_>,
+) -> Result<&'a [u8], CryptoError> {
+    let cipher = registry.get(params.encryption.oid()).ok_or(CryptoError)?;
+    let iv = params.encryption.iv().ok_or(CryptoError)?;
+
+    // The key is only valid for the exact KDF parameters (incl. salt) it was
+    // derived with, and for ciphers with a matching key size
+    if key.kdf != params.kdf || key.key.length != cipher.key_size() {
+        return Err(CryptoError);
+    }
+
+    cipher.decrypt_in_place(key.key.as_slice(), iv, buffer)
+}
+
+/// Incremental decryptor for PBES2-encrypted messages using AES-CBC.
+///
+/// Ciphertext can be supplied in chunks of any size using
+/// [`CbcDecryptor::update`], which writes the plaintext of all but the final
+/// block into a caller-provided output buffer. Only a single block is buffered
+/// internally in order to strip the PKCS#7 padding in
+/// [`CbcDecryptor::finish`], so peak memory usage is independent of the
+/// message size.
+///
+/// Any buffered data is zeroized on drop. The expanded AES key schedule is
+/// *not* wiped, as the `aes` crate provides no way to do so.
+pub struct CbcDecryptor {
+    /// Block cipher in CBC mode (without padding, which is handled separately).
+    cipher: AesCbc,
+
+    /// Buffered ciphertext which hasn't been decrypted yet.
+    buffer: [u8; AES_BLOCK_SIZE],
+
+    /// Number of bytes in the buffer.
+    buffer_len: usize,
+}
+
+/// AES-CBC ciphers supported by [`CbcDecryptor`].
+enum AesCbc {
+    Aes128(Cbc<aes::Aes128, NoPadding>),
+    Aes192(Cbc<aes::Aes192, NoPadding>),
+    Aes256(Cbc<aes::Aes256, NoPadding>),
+}
+
+impl CbcDecryptor {
+    /// Initialize a decryptor using a key derived from the provided password.
+    pub(crate) fn new(params: &Parameters<'_>, password: &[u8]) -> Result<Self, CryptoError> {
+        let key = EncryptionKey::derive_from_password(
+            password,
+            &params.kdf,
+            params.encryption.builtin_key_size().ok_or(CryptoError)?,
+        )?;
+
+        let key = key.as_slice();
+        let cipher = match params.encryption {
+            EncryptionScheme::Aes128Cbc { iv } => {
+                AesCbc::Aes128(Cbc::new_from_slices(key, iv).map_err(|_| CryptoError)?)
+            }
+            EncryptionScheme::Aes192Cbc { iv } => {
+                AesCbc::Aes192(Cbc::new_from_slices(key, iv).map_err(|_| CryptoError)?)
+            }
+            EncryptionScheme::Aes256Cbc { iv } => {
+                AesCbc::Aes256(Cbc::new_from_slices(key, iv).map_err(|_| CryptoError)?)
+            }
+            _ => return Err(CryptoError),
+        };
+
+        Ok(Self {
+            cipher,
+            buffer: [0u8; AES_BLOCK_SIZE],
+            buffer_len: 0,
+        })
+    }
+
+    /// Decrypt the next chunk of ciphertext, writing the plaintext of all
+    /// complete blocks except the last one into `output`.
+    ///
+    /// The final block is retained until [`CbcDecryptor::finish`] is called,
+    /// so `output` must have room for the length of the input rounded up to
+    /// the next block boundary. Returns the portion of `output` which contains
+    /// plaintext.
+    pub fn update<'o>(
+        &mut self,
+        input: &[u8],
+        output: &'o mut [u8],
+    ) -> Result<&'o [u8], CryptoError> {
+        let total_len = self.buffer_len + input.len();
+
+        if total_len <= AES_BLOCK_SIZE {
+            self.buffer[self.buffer_len..total_len].copy_from_slice(input);
+            self.buffer_len = total_len;
+            return Ok(&[]);
         }
-        EncryptionScheme::Aes256Cbc { iv } => {
-            let cipher = Aes256Cbc::new_from_slices(encryption_key.as_slice(), iv)
-                .map_err(|_| CryptoError)?;
-            cipher.decrypt(buffer).map_err(|_| CryptoError)
+
+        // Always retain between 1 and `AES_BLOCK_SIZE` bytes of ciphertext
+        let out_len = (total_len - 1) / AES_BLOCK_SIZE * AES_BLOCK_SIZE;
+        let output = output.get_mut(..out_len).ok_or(CryptoError)?;
+        let (head, tail) = input.split_at(out_len - self.buffer_len);
+
+        output[..self.buffer_len].copy_from_slice(&self.buffer[..self.buffer_len]);
+        output[self.buffer_len..].copy_from_slice(head);
+        self.buffer[..tail.len()].copy_from_slice(tail);
+        self.buffer_len = tail.len();
+
+        for block in output.chunks_mut(AES_BLOCK_SIZE) {
+            self.cipher.decrypt_block(block);
         }
-        #[cfg(feature = "3des")]
-        EncryptionScheme::DesEde3Cbc { iv } => {
-            let cipher = DesEde3Cbc::new_from_slices(encryption_key.as_slice(), iv)
-                .map_err(|_| CryptoError)?;
-            cipher.decrypt(buffer).map_err(|_| CryptoError)
+
+        Ok(output)
+    }
+
+    /// Decrypt the final block and remove its PKCS#7 padding, writing the
+    /// remaining plaintext (at most one block) into `output`.
+    ///
+    /// Returns an error if the total length of the ciphertext wasn't a
+    /// non-zero multiple of the block size or the padding is invalid, e.g.
+    /// because the password is wrong.
+    pub fn finish(mut self, output: &mut [u8]) -> Result<&[u8], CryptoError> {
+        if self.buffer_len != AES_BLOCK_SIZE {
+            return Err(CryptoError);
         }
-        #[cfg(feature = "des-insecure")]
-        EncryptionScheme::DesCbc { iv } => {
-            let cipher =
-                DesCbc::new_from_slices(encryption_key.as_slice(), iv).map_err(|_| CryptoError)?;
-            cipher.decrypt(buffer).map_err(|_| CryptoError)
+
+        let mut block = self.buffer;
+        self.cipher.decrypt_block(&mut block);
+        let pt_len = pkcs7_unpad(&block);
+        let result = match pt_len.and_then(move |pt_len| output.get_mut(..pt_len)) {
+            Some(output) => {
+                output.copy_from_slice(&block[..output.len()]);
+                Ok(&*output)
+            }
+            None => Err(CryptoError),
+        };
+
+        block.zeroize();
+        result
+    }
+}
+
+impl Drop for CbcDecryptor {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
+impl fmt::Debug for CbcDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CbcDecryptor").finish() // TODO: use `finish_non_exhaustive` when stable
+    }
+}
+
+impl AesCbc {
+    /// Decrypt a single block in-place, updating the CBC chaining state.
+    fn decrypt_block(&mut self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+
+        match self {
+            AesCbc::Aes128(cipher) => cipher.decrypt_blocks(slice::from_mut(block)),
+            AesCbc::Aes192(cipher) => cipher.decrypt_blocks(slice::from_mut(block)),
+            AesCbc::Aes256(cipher) => cipher.decrypt_blocks(slice::from_mut(block)),
         }
     }
 }
 
+/// Get the length of the plaintext in the given PKCS#7-padded final block.
+///
+/// The padding is checked without branching on the decrypted data, so the
+/// time taken doesn't reveal where the padding was malformed.
+fn pkcs7_unpad(block: &[u8; AES_BLOCK_SIZE]) -> Option<usize> {
+    let pad = block[AES_BLOCK_SIZE - 1];
+    let pad_len = usize::from(pad);
+
+    // Non-zero iff the padding length is outside `1..=AES_BLOCK_SIZE`
+    let mut invalid = usize::from(pad == 0) | usize::from(pad_len > AES_BLOCK_SIZE);
+
+    for (i, &byte) in block.iter().enumerate() {
+        let in_padding = usize::from(i >= AES_BLOCK_SIZE.wrapping_sub(pad_len));
+        invalid |= in_padding & usize::from(byte != pad);
+    }
+
+    if invalid == 0 {
+        Some(AES_BLOCK_SIZE - pad_len)
+    } else {
+        None
+    }
+}
+
+/// Encryption key derived from a password using a PBES2 key derivation
+/// function, which can be reused to decrypt several messages encrypted with
+/// the same password and KDF parameters.
+///
+/// The key material is zeroized on drop.
+#[derive(Clone)]
+pub struct DerivedKey<'a> {
+    /// Key derivation function (and parameters) used to derive the key.
+    kdf: Kdf<'a>,
+
+    /// Derived key material.
+    key: EncryptionKey,
+}
+
+impl<'a> DerivedKey<'a> {
+    /// Derive a key of the given size from the provided password.
+    pub(crate) fn derive(
+        password: &[u8],
+        kdf: &Kdf<'a>,
+        key_size: usize,
+    ) -> Result<Self, CryptoError> {
+        Ok(Self {
+            kdf: kdf.clone(),
+            key: EncryptionKey::derive_from_password(password, kdf, key_size)?,
+        })
+    }
+
+    /// Get the key derivation function used to derive this key.
+    pub fn kdf(&self) -> &Kdf<'a> {
+        &self.kdf
+    }
+}
+
+impl fmt::Debug for DerivedKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivedKey")
+            .field("kdf", &self.kdf)
+            .finish() // TODO: use `finish_non_exhaustive` when stable
+    }
+}
+
 /// Encryption key as derived by PBKDF2
-// TODO(tarcieri): zeroize?
+#[derive(Clone)]
 struct EncryptionKey {
     buffer: [u8; MAX_KEY_LEN],
     length: usize,
@@ -206,6 +413,12 @@ impl EncryptionKey {
     }
 }
 
+impl Drop for EncryptionKey {
+    fn drop(&mut self) {
+        self.buffer.zeroize();
+    }
+}
+
 /// Validate key length
 // TODO(tarcieri): move to `EncryptionKey::derive_from_password`?
 fn validate_key_length(requested_len: usize, params_len: Option<usize>) -> Result<(), CryptoError> {
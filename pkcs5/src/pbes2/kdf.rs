@@ -7,6 +7,9 @@ use der::{
     Decodable, Decoder, Encodable, Encoder, ErrorKind, Length, Message,
 };
 
+#[cfg(feature = "pbes2")]
+use super::DerivedKey;
+
 /// Password-Based Key Derivation Function (PBKDF2) OID.
 pub const PBKDF2_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.5.12");
 
@@ -33,6 +36,14 @@ pub const SCRYPT_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.4.1.1159
 /// Type used for expressing scrypt cost
 type ScryptCost = u16;
 
+/// Maximum permitted product of the scrypt cost parameter `N` and block
+/// size `r`.
+///
+/// scrypt allocates `128 * N * r` bytes of memory, so this caps a single key
+/// derivation at 1 GiB and prevents untrusted parameters from being used to
+/// exhaust memory.
+pub const SCRYPT_MAX_COST_TIMES_BLOCK_SIZE: u32 = 1 << 23;
+
 /// Password-based key derivation function.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -45,6 +56,18 @@ pub enum Kdf<'a> {
 }
 
 impl<'a> Kdf<'a> {
+    /// Derive an encryption key of the given size (in bytes) from the
+    /// provided password using this key derivation function.
+    #[cfg(feature = "pbes2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
+    pub fn derive_key(
+        &self,
+        password: impl AsRef<[u8]>,
+        key_size: usize,
+    ) -> Result<DerivedKey<'a>, CryptoError> {
+        DerivedKey::derive(password.as_ref(), self, key_size)
+    }
+
     /// Get the [`ObjectIdentifier`] (a.k.a OID) for this algorithm.
     pub fn oid(&self) -> ObjectIdentifier {
         match self {
@@ -201,6 +224,11 @@ impl<'a> TryFrom<Any<'a>> for Pbkdf2Params<'a> {
         any.sequence(|params| {
             // TODO(tarcieri): support salt `CHOICE` w\ `AlgorithmIdentifier`
             let salt = params.octet_string()?;
+
+            if salt.is_empty() {
+                return Err(der::Tag::OctetString.length_error());
+            }
+
             let iteration_count = params.decode()?;
             let key_length = params.optional()?;
             let prf: Option<AlgorithmIdentifier<'_>> = params.optional()?;
@@ -349,13 +377,45 @@ impl<'a> ScryptParams<'a> {
         params: scrypt::Params,
         salt: &'a [u8],
     ) -> Result<Self, CryptoError> {
-        Ok(Self {
+        let scrypt_params = Self {
             salt,
-            cost_parameter: 1 << params.log_n(),
+            cost_parameter: ScryptCost::checked_shl(1, params.log_n().into()).ok_or(CryptoError)?,
             block_size: params.r().try_into().map_err(|_| CryptoError)?,
             parallelization: params.p().try_into().map_err(|_| CryptoError)?,
             key_length: None,
-        })
+        };
+
+        scrypt_params.validate()?;
+        Ok(scrypt_params)
+    }
+
+    /// Check these parameters against the constraints of [RFC 7914]:
+    ///
+    /// - `N` must be a power of two greater than 1
+    /// - `r` and `p` must be non-zero, with `r * p < 2^30`
+    /// - `N * r` must not exceed [`SCRYPT_MAX_COST_TIMES_BLOCK_SIZE`]
+    ///
+    /// This is checked before running the KDF so that malicious parameters
+    /// are rejected instead of being computed.
+    ///
+    /// [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914#section-2
+    pub fn validate(&self) -> Result<(), CryptoError> {
+        let n = u32::from(self.cost_parameter);
+        let r = u32::from(self.block_size);
+        let p = u32::from(self.parallelization);
+
+        if n < 2 || !n.is_power_of_two() || r == 0 || p == 0 {
+            return Err(CryptoError);
+        }
+
+        if u64::from(r) * u64::from(p) >= 1 << 30 {
+            return Err(CryptoError);
+        }
+
+        match n.checked_mul(r) {
+            Some(cost) if cost <= SCRYPT_MAX_COST_TIMES_BLOCK_SIZE => Ok(()),
+            _ => Err(CryptoError),
+        }
     }
 }
 
@@ -386,6 +446,11 @@ impl<'a> TryFrom<Any<'a>> for ScryptParams<'a> {
     fn try_from(any: Any<'a>) -> der::Result<Self> {
         any.sequence(|params| {
             let salt = params.octet_string()?;
+
+            if salt.is_empty() {
+                return Err(der::Tag::OctetString.length_error());
+            }
+
             let cost_parameter = params.decode()?;
             let block_size = params.decode()?;
             let parallelization = params.decode()?;
@@ -418,14 +483,8 @@ impl<'a> TryFrom<&ScryptParams<'a>> for scrypt::Params {
     type Error = CryptoError;
 
     fn try_from(params: &ScryptParams<'a>) -> Result<scrypt::Params, CryptoError> {
-        let n = params.cost_parameter;
-
-        // Compute log2 and verify its correctness
-        let log_n = ((8 * core::mem::size_of::<ScryptCost>() as u32) - n.leading_zeros() - 1) as u8;
-
-        if 1 << log_n != n {
-            return Err(CryptoError);
-        }
+        params.validate()?;
+        let log_n = params.cost_parameter.trailing_zeros() as u8;
 
         scrypt::Params::new(
             log_n,
@@ -0,0 +1,146 @@
+//! Policy restricting which PBES2 algorithms may be used for decryption.
+
+use super::{Kdf, Parameters, Pbkdf2Prf};
+use crate::EncryptionScheme;
+use core::fmt;
+use der::asn1::ObjectIdentifier;
+
+/// Policy restricting the algorithms which may be used when decrypting
+/// PBES2-encrypted data, e.g. to only permit FIPS-approved algorithms.
+///
+/// Each of the encryption scheme (cipher), key derivation function and
+/// PBKDF2 pseudo-random function can be restricted to an allow-list.
+/// Algorithms which aren't restricted are permitted, so the default policy
+/// allows everything. Policies which restrict any algorithm reject all
+/// encryption schemes other than PBES2.
+///
+/// The following policy only permits AES-256-CBC with PBKDF2-HMAC-SHA256:
+///
+/// ```
+/// use pkcs5::pbes2::{DecryptPolicy, Pbkdf2Prf, AES_256_CBC_OID, PBKDF2_OID};
+///
+/// const POLICY: DecryptPolicy<'static> = DecryptPolicy::new()
+///     .with_ciphers(&[AES_256_CBC_OID])
+///     .with_kdfs(&[PBKDF2_OID])
+///     .with_prfs(&[Pbkdf2Prf::HmacWithSha256]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecryptPolicy<'p> {
+    /// Allowed encryption scheme OIDs, or `None` to allow any.
+    ciphers: Option<&'p [ObjectIdentifier]>,
+
+    /// Allowed key derivation function OIDs, or `None` to allow any.
+    kdfs: Option<&'p [ObjectIdentifier]>,
+
+    /// Allowed PBKDF2 pseudo-random functions, or `None` to allow any.
+    prfs: Option<&'p [Pbkdf2Prf]>,
+}
+
+impl<'p> DecryptPolicy<'p> {
+    /// Create a new permissive policy which allows all algorithms.
+    pub const fn new() -> Self {
+        Self {
+            ciphers: None,
+            kdfs: None,
+            prfs: None,
+        }
+    }
+
+    /// Only allow encryption schemes with the given OIDs.
+    pub const fn with_ciphers(self, ciphers: &'p [ObjectIdentifier]) -> Self {
+        Self {
+            ciphers: Some(ciphers),
+            ..self
+        }
+    }
+
+    /// Only allow key derivation functions with the given OIDs.
+    pub const fn with_kdfs(self, kdfs: &'p [ObjectIdentifier]) -> Self {
+        Self {
+            kdfs: Some(kdfs),
+            ..self
+        }
+    }
+
+    /// Only allow the given PBKDF2 pseudo-random functions.
+    ///
+    /// This has no effect on key derivation functions other than PBKDF2.
+    pub const fn with_prfs(self, prfs: &'p [Pbkdf2Prf]) -> Self {
+        Self {
+            prfs: Some(prfs),
+            ..self
+        }
+    }
+
+    /// Check that the given [`EncryptionScheme`] is allowed by this policy.
+    ///
+    /// Schemes other than PBES2 are only allowed by permissive policies which
+    /// don't restrict any algorithms.
+    pub fn check_scheme(&self, scheme: &EncryptionScheme<'_>) -> Result<(), PolicyViolation> {
+        match scheme {
+            EncryptionScheme::Pbes2(params) => self.check(params),
+            _ if *self == Self::new() => Ok(()),
+            _ => Err(PolicyViolation::Scheme(scheme.oid())),
+        }
+    }
+
+    /// Check that the algorithms used by the given PBES2 [`Parameters`] are
+    /// allowed by this policy.
+    pub fn check(&self, params: &Parameters<'_>) -> Result<(), PolicyViolation> {
+        let cipher = params.encryption.oid();
+
+        if !is_allowed(self.ciphers, &cipher) {
+            return Err(PolicyViolation::Cipher(cipher));
+        }
+
+        let kdf = params.kdf.oid();
+
+        if !is_allowed(self.kdfs, &kdf) {
+            return Err(PolicyViolation::Kdf(kdf));
+        }
+
+        if let Kdf::Pbkdf2(pbkdf2_params) = &params.kdf {
+            if !is_allowed(self.prfs, &pbkdf2_params.prf) {
+                return Err(PolicyViolation::Prf(pbkdf2_params.prf));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Is `value` contained in the given allow-list (if any)?
+fn is_allowed<T: PartialEq>(allowed: Option<&[T]>, value: &T) -> bool {
+    match allowed {
+        Some(allowed) => allowed.contains(value),
+        None => true,
+    }
+}
+
+/// Algorithm which isn't allowed by a [`DecryptPolicy`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PolicyViolation {
+    /// Encryption scheme other than PBES2 with the given OID isn't allowed.
+    Scheme(ObjectIdentifier),
+
+    /// Encryption scheme with the given OID isn't allowed.
+    Cipher(ObjectIdentifier),
+
+    /// Key derivation function with the given OID isn't allowed.
+    Kdf(ObjectIdentifier),
+
+    /// PBKDF2 pseudo-random function isn't allowed.
+    Prf(Pbkdf2Prf),
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scheme(oid) => write!(f, "encryption scheme not allowed by policy: {}", oid),
+            Self::Cipher(oid) => write!(f, "PBES2 cipher not allowed by policy: {}", oid),
+            Self::Kdf(oid) => write!(f, "PBES2 KDF not allowed by policy: {}", oid),
+            Self::Prf(prf) => write!(f, "PBKDF2 PRF not allowed by policy: {}", prf.oid()),
+        }
+    }
+}
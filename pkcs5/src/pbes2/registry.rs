@@ -0,0 +1,110 @@
+//! Registry of block ciphers used when decrypting PBES2-encrypted data.
+
+use super::{AES_128_CBC_OID, AES_192_CBC_OID, AES_256_CBC_OID};
+use crate::CryptoError;
+use block_modes::{
+    block_padding::Pkcs7,
+    cipher::{
+        generic_array::typenum::Unsigned, BlockCipher, BlockDecrypt, BlockEncrypt, NewBlockCipher,
+    },
+    BlockMode, Cbc,
+};
+use core::{fmt, marker::PhantomData};
+use der::asn1::ObjectIdentifier;
+
+#[cfg(feature = "des-insecure")]
+use super::DES_CBC_OID;
+#[cfg(feature = "3des")]
+use super::DES_EDE3_CBC_OID;
+
+/// Block cipher which can decrypt data encrypted using PBES2.
+///
+/// Implement this trait and add it to a [`CipherRegistry`] to decrypt data
+/// encrypted with a cipher which isn't built into this crate.
+pub trait BlockCipherDecryptor {
+    /// Size of the key used by this cipher in bytes.
+    fn key_size(&self) -> usize;
+
+    /// Decrypt the given ciphertext in-place using the provided key and
+    /// initialization vector, returning the plaintext with any padding removed.
+    fn decrypt_in_place<'b>(
+        &self,
+        key: &[u8],
+        iv: &[u8],
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], CryptoError>;
+}
+
+/// Ciphers which can be used to decrypt PBES2-encrypted data, keyed by the
+/// [`ObjectIdentifier`] of the encryption scheme.
+///
+/// The ciphers built into this crate (AES-CBC and, when enabled, DES-CBC and
+/// Triple DES-CBC) are always available and take precedence over any
+/// additional ciphers provided by the caller.
+#[derive(Copy, Clone, Default)]
+pub struct CipherRegistry<'r> {
+    /// Additional ciphers provided by the caller.
+    ciphers: &'r [(ObjectIdentifier, &'r dyn BlockCipherDecryptor)],
+}
+
+impl<'r> CipherRegistry<'r> {
+    /// Create a new registry containing the built-in ciphers along with the
+    /// given additional ciphers.
+    pub const fn new(ciphers: &'r [(ObjectIdentifier, &'r dyn BlockCipherDecryptor)]) -> Self {
+        Self { ciphers }
+    }
+
+    /// Look up the cipher for the given encryption scheme OID.
+    pub fn get(&self, oid: ObjectIdentifier) -> Option<&'r dyn BlockCipherDecryptor> {
+        builtin(oid).or_else(|| {
+            self.ciphers
+                .iter()
+                .find(|(cipher_oid, _)| *cipher_oid == oid)
+                .map(|(_, cipher)| *cipher)
+        })
+    }
+}
+
+impl fmt::Debug for CipherRegistry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.ciphers.iter().map(|(oid, _)| oid))
+            .finish()
+    }
+}
+
+/// Look up a built-in cipher.
+fn builtin(oid: ObjectIdentifier) -> Option<&'static dyn BlockCipherDecryptor> {
+    match oid {
+        AES_128_CBC_OID => Some(&CbcDecryptor::<aes::Aes128>(PhantomData)),
+        AES_192_CBC_OID => Some(&CbcDecryptor::<aes::Aes192>(PhantomData)),
+        AES_256_CBC_OID => Some(&CbcDecryptor::<aes::Aes256>(PhantomData)),
+        #[cfg(feature = "des-insecure")]
+        DES_CBC_OID => Some(&CbcDecryptor::<des::Des>(PhantomData)),
+        #[cfg(feature = "3des")]
+        DES_EDE3_CBC_OID => Some(&CbcDecryptor::<des::TdesEde3>(PhantomData)),
+        _ => None,
+    }
+}
+
+/// Built-in cipher operating in CBC mode with PKCS#7 padding.
+struct CbcDecryptor<C>(PhantomData<C>);
+
+impl<C> BlockCipherDecryptor for CbcDecryptor<C>
+where
+    C: BlockCipher + BlockEncrypt + BlockDecrypt + NewBlockCipher,
+{
+    fn key_size(&self) -> usize {
+        C::KeySize::USIZE
+    }
+
+    fn decrypt_in_place<'b>(
+        &self,
+        key: &[u8],
+        iv: &[u8],
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], CryptoError> {
+        let cipher = Cbc::<C, Pkcs7>::new_from_slices(key, iv).map_err(|_| CryptoError)?;
+        cipher.decrypt(buffer).map_err(|_| CryptoError)
+    }
+}
@@ -4,6 +4,7 @@
 
 use core::convert::TryFrom;
 use hex_literal::hex;
+use pkcs5::der::Encodable;
 
 /// PBES2 + PBKDF2-SHA256 + AES-256-CBC `AlgorithmIdentifier` example.
 ///
@@ -128,3 +129,192 @@ fn decrypt_pbes2_pbkdf2_sha256_descbc() {
     let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
+
+/// Replace the scrypt parameters of the PBES2 + scrypt example with the given
+/// `N`/`r`/`p` and check both decryption and encryption are rejected.
+fn assert_scrypt_params_rejected(cost_parameter: u16, block_size: u16, parallelization: u16) {
+    let mut scheme = pkcs5::EncryptionScheme::try_from(PBES2_SCRYPT_AES256CBC_ALG_ID).unwrap();
+
+    match &mut scheme {
+        pkcs5::EncryptionScheme::Pbes2(params) => match &mut params.kdf {
+            pkcs5::pbes2::Kdf::Scrypt(scrypt_params) => {
+                scrypt_params.cost_parameter = cost_parameter;
+                scrypt_params.block_size = block_size;
+                scrypt_params.parallelization = parallelization;
+                assert!(scrypt_params.validate().is_err());
+            }
+            other => panic!("unexpected KDF: {:?}", other),
+        },
+        other => panic!("unexpected scheme: {:?}", other),
+    }
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT);
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::CryptoError)
+    );
+
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    assert_eq!(
+        scheme.encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len()),
+        Err(pkcs5::CryptoError)
+    );
+}
+
+#[test]
+fn reject_scrypt_zero_cost() {
+    assert_scrypt_params_rejected(0, 8, 1);
+}
+
+#[test]
+fn reject_scrypt_non_power_of_two_cost() {
+    assert_scrypt_params_rejected(16383, 8, 1);
+}
+
+#[test]
+fn reject_scrypt_excessive_memory() {
+    assert_scrypt_params_rejected(32768, 65535, 1);
+}
+
+#[test]
+fn decrypt_with_derived_key() {
+    let salt = hex!("79D982E70DF91A88");
+    let iv1 = hex!("B2D02D78B2EFD9DFF694CF8E0AF40925");
+    let iv2 = hex!("000102030405060708090A0B0C0D0E0F");
+    let params1 = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &salt, &iv1).unwrap();
+    let params2 = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &salt, &iv2).unwrap();
+
+    let mut ct1 = [0u8; 64];
+    ct1[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ct1 = params1
+        .encrypt_in_place(PASSWORD, &mut ct1, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+
+    let mut ct2 = [0u8; 64];
+    ct2[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ct2 = params2
+        .encrypt_in_place(PASSWORD, &mut ct2, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+
+    assert_ne!(ct1, ct2);
+
+    let key = params1.derive_key(PASSWORD).unwrap();
+
+    let mut buffer = Vec::from(ct1);
+    let plaintext = params1
+        .decrypt_in_place_with_key(&key, &mut buffer)
+        .unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+    let mut buffer = Vec::from(ct2);
+    let plaintext = params2
+        .decrypt_in_place_with_key(&key, &mut buffer)
+        .unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn reject_derived_key_with_different_salt() {
+    let iv = hex!("B2D02D78B2EFD9DFF694CF8E0AF40925");
+    let params1 =
+        pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, b"saltsalt", &iv).unwrap();
+    let params2 =
+        pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, b"pepper!!", &iv).unwrap();
+    let key = params1.derive_key(PASSWORD).unwrap();
+
+    let mut buffer = [0u8; 32];
+    assert_eq!(
+        params2.decrypt_in_place_with_key(&key, &mut buffer),
+        Err(pkcs5::CryptoError)
+    );
+}
+
+/// Encrypt the Ed25519 key with the given parameters and check it decrypts.
+fn assert_round_trip(params: &pkcs5::pbes2::Parameters<'_>) {
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = params
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+
+    let mut buffer = Vec::from(ciphertext);
+    let plaintext = params.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn round_trip_with_and_without_explicit_key_length() {
+    let salt = hex!("79D982E70DF91A88");
+    let iv = hex!("B2D02D78B2EFD9DFF694CF8E0AF40925");
+    let params = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes128cbc(2048, &salt, &iv).unwrap();
+    assert_round_trip(&params);
+
+    let explicit = params.clone().explicit_key_length(true).unwrap();
+    assert_eq!(explicit.kdf.pbkdf2().unwrap().key_length, Some(16));
+    assert_round_trip(&explicit);
+
+    // Both encode the same key, so are interchangeable for decryption
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+    let ciphertext = explicit
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    let mut buffer = Vec::from(ciphertext);
+    let plaintext = params.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn reject_mismatched_explicit_key_length() {
+    let salt = hex!("79D982E70DF91A88");
+    let iv = hex!("B2D02D78B2EFD9DFF694CF8E0AF40925");
+    let mut params = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &salt, &iv).unwrap();
+
+    match &mut params.kdf {
+        pkcs5::pbes2::Kdf::Pbkdf2(pbkdf2_params) => pbkdf2_params.key_length = Some(16),
+        other => panic!("unexpected KDF: {:?}", other),
+    }
+
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_PBKDF2_SHA256);
+    assert_eq!(
+        params.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::CryptoError)
+    );
+}
+
+#[test]
+fn round_trip_pbkdf2_sha512() {
+    let salt = hex!("79D982E70DF91A88");
+    let iv = hex!("B2D02D78B2EFD9DFF694CF8E0AF40925");
+    let params = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &salt, &iv)
+        .unwrap()
+        .with_prf(pkcs5::pbes2::Pbkdf2Prf::HmacWithSha512)
+        .unwrap();
+    assert_round_trip(&params);
+
+    // The PRF survives an encode/decode cycle of the parameters
+    let mut buffer = [0u8; 128];
+    let alg_id = pkcs5::EncryptionScheme::from(params.clone());
+    let der = alg_id.encode_to_slice(&mut buffer).unwrap();
+    let decoded = pkcs5::EncryptionScheme::try_from(der).unwrap();
+    let decoded_params = decoded.pbes2().unwrap();
+    assert_eq!(
+        decoded_params.kdf.pbkdf2().unwrap().prf,
+        pkcs5::pbes2::Pbkdf2Prf::HmacWithSha512
+    );
+    assert_eq!(decoded_params, &params);
+}
+
+#[test]
+fn reject_prf_for_scrypt() {
+    let salt = hex!("E6211E2348AD69E0");
+    let iv = hex!("9BD0A6251F2254F9FD5963887C27CF01");
+    let params =
+        pkcs5::pbes2::Parameters::scrypt_aes256cbc(Default::default(), &salt, &iv).unwrap();
+
+    assert_eq!(
+        params.with_prf(pkcs5::pbes2::Pbkdf2Prf::HmacWithSha512),
+        Err(pkcs5::CryptoError)
+    );
+}
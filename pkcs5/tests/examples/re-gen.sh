@@ -51,3 +51,21 @@ do
         extract "$aes_mode" "$prf" 88
     done
 done
+
+# RSA-4096 key, large enough for its ciphertext to span many chunks when
+# decrypting incrementally
+openssl genrsa -out rsa4096_sk.pkcs1.pem 4096
+
+openssl pkcs8 -topk8 -in rsa4096_sk.pkcs1.pem -outform DER -out rsa4096_sk.pkcs8.der -nocrypt
+
+openssl pkcs8 \
+    -topk8 \
+    -in rsa4096_sk.pkcs1.pem \
+    -v2 aes-256-cbc \
+    -v2prf hmacWithSHA256 \
+    -iter 10 \
+    -passout "$passout" \
+    -outform DER -out rsa4096_sk_aes-256-cbc_hmacWithSHA256.pkcs8.der
+
+dd bs=1 skip=4 count=96 if=rsa4096_sk_aes-256-cbc_hmacWithSHA256.pkcs8.der of=pbes2_rsa4096_aes-256-cbc_hmacWithSHA256_algid.der
+dd bs=1 skip=104 if=rsa4096_sk_aes-256-cbc_hmacWithSHA256.pkcs8.der of=pbes2_rsa4096_aes-256-cbc_hmacWithSHA256_ciphertext.bin
@@ -1,7 +1,7 @@
 //! Password-Based Encryption Scheme 2 tests
 
 use core::convert::TryFrom;
-use der::Encodable;
+use der::{Decodable, Encodable};
 use hex_literal::hex;
 use pkcs5::pbes2;
 
@@ -57,6 +57,17 @@ const PBES2_PBKDF2_SHA256_DESCBC_ALG_ID: &[u8] = &hex!(
     2070408f4aaf206a18de7ad"
 );
 
+/// PBES2 + PBKDF2-SHA256 `AlgorithmIdentifier` example using a cipher with
+/// the fabricated OID `1.3.6.1.4.1.99999.1`.
+///
+/// Derived from [`PBES2_PBKDF2_SHA256_AES256CBC_ALG_ID`] by replacing the
+/// AES-256-CBC OID.
+const PBES2_PBKDF2_SHA256_UNKNOWN_CIPHER_ALG_ID: &[u8] = &hex!(
+    "305706092a864886f70d01050d304a302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301d06092b06
+     010401868d1f010410b2d02d78b2efd9dff694cf8e0af40925"
+);
+
 /// Decoding test for PBES2 + PBKDF2-SHA1 + AES-128-CBC `AlgorithmIdentifier`
 #[test]
 fn decode_pbes2_pbkdf2_sha1_aes128cbc() {
@@ -198,3 +209,110 @@ fn encode_pbes2_scrypt_aes256cbc() {
     let encoded_der = encoder.finish().unwrap();
     assert_eq!(encoded_der, PBES2_SCRYPT_AES256CBC_ALG_ID);
 }
+
+/// PBES2 + PBKDF2-SHA1 + AES-128-CBC `AlgorithmIdentifier` with a 15-byte IV.
+const PBES2_AES128CBC_SHORT_IV_ALG_ID: &[u8] = &hex!(
+    "304806092a864886f70d01050d303b301b06092a864886f70d01050c300e0408
+     e8765e01e43b6bad02020800301c0609608648016503040102040f223080a71b
+     cd2b9a256d876c924979"
+);
+
+/// PBES2 + PBKDF2-SHA1 + AES-128-CBC `AlgorithmIdentifier` with a 17-byte IV.
+const PBES2_AES128CBC_LONG_IV_ALG_ID: &[u8] = &hex!(
+    "304a06092a864886f70d01050d303d301b06092a864886f70d01050c300e0408
+     e8765e01e43b6bad02020800301e06096086480165030401020411223080a71b
+     cd2b9a256d876c924979d200"
+);
+
+/// PBES2 + PBKDF2-SHA1 + AES-128-CBC `AlgorithmIdentifier` with an empty salt.
+const PBES2_PBKDF2_EMPTY_SALT_ALG_ID: &[u8] = &hex!(
+    "304106092a864886f70d01050d3034301306092a864886f70d01050c30060400
+     02020800301d06096086480165030401020410223080a71bcd2b9a256d876c92
+     4979d2"
+);
+
+/// Wrong-length IVs for AES-CBC are rejected when decoding
+#[test]
+fn reject_pbes2_aes128cbc_wrong_iv_length() {
+    for alg_id in &[
+        PBES2_AES128CBC_SHORT_IV_ALG_ID,
+        PBES2_AES128CBC_LONG_IV_ALG_ID,
+    ] {
+        let err = pkcs5::EncryptionScheme::try_from(*alg_id).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            der::ErrorKind::Length {
+                tag: der::Tag::OctetString
+            }
+        );
+    }
+}
+
+/// Empty PBKDF2 salts are rejected when decoding
+#[test]
+fn reject_pbes2_pbkdf2_empty_salt() {
+    let err = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_EMPTY_SALT_ALG_ID).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        der::ErrorKind::Length {
+            tag: der::Tag::OctetString
+        }
+    );
+}
+
+/// Round-trip test for a PBES2 `AlgorithmIdentifier` with an unknown cipher
+#[test]
+fn round_trip_pbes2_unknown_cipher() {
+    let scheme =
+        pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_UNKNOWN_CIPHER_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Other { oid, parameters } => {
+            assert_eq!(oid, "1.3.6.1.4.1.99999.1".parse().unwrap());
+            assert_eq!(
+                parameters.unwrap().octet_string().unwrap().as_bytes(),
+                &hex!("b2d02d78b2efd9dff694cf8e0af40925")
+            );
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+
+    assert_eq!(params.encryption.key_size(), 0);
+
+    let mut buffer = [0u8; 1024];
+    let encoded = scheme.encode_to_slice(&mut buffer).unwrap();
+    assert_eq!(encoded, PBES2_PBKDF2_SHA256_UNKNOWN_CIPHER_ALG_ID);
+
+    #[cfg(feature = "pbes2")]
+    {
+        let mut ciphertext = [0u8; 16];
+        assert!(params
+            .decrypt_in_place(b"password", &mut ciphertext)
+            .is_err());
+    }
+}
+
+#[test]
+fn encode_pbes2_pbkdf2_sha256_aes256cbc_explicit_key_length() {
+    let salt = hex!("79d982e70df91a88");
+    let iv = hex!("b2d02d78b2efd9dff694cf8e0af40925");
+
+    let params = pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &salt, &iv).unwrap();
+    let explicit = params.clone().explicit_key_length(true).unwrap();
+    assert_eq!(explicit.kdf.pbkdf2().unwrap().key_length, Some(32));
+
+    let mut buffer = [0u8; 128];
+    let encoded = explicit.encode_to_slice(&mut buffer).unwrap();
+    let decoded = pbes2::Parameters::from_der(encoded).unwrap();
+    assert_eq!(decoded, explicit);
+    assert_eq!(decoded.explicit_key_length(false).unwrap(), params);
+}
+
+#[test]
+fn reject_explicit_key_length_for_unknown_cipher() {
+    let scheme =
+        pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_UNKNOWN_CIPHER_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap().clone();
+    assert!(params.explicit_key_length(true).is_err());
+}
@@ -57,3 +57,55 @@ fn all_combinations_with_sha2() {
 
     run_combinations(&prfs);
 }
+
+#[cfg(feature = "pbes2")]
+#[test]
+fn decrypt_incrementally() {
+    use core::convert::TryFrom;
+    use std::fs;
+
+    const PASSWORD: &[u8] = b"hunter2"; // Bad password; don't actually use outside tests!
+
+    // RSA-4096 key, so that the ciphertext spans many chunks of each size
+    let sk_bytes = fs::read("./tests/examples/rsa4096_sk.pkcs8.der").unwrap();
+    let algid_bytes =
+        fs::read("./tests/examples/pbes2_rsa4096_aes-256-cbc_hmacWithSHA256_algid.der").unwrap();
+    let scheme = pkcs5::EncryptionScheme::try_from(algid_bytes.as_slice()).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    let ciphertext =
+        fs::read("./tests/examples/pbes2_rsa4096_aes-256-cbc_hmacWithSHA256_ciphertext.bin")
+            .unwrap();
+    assert_eq!(2384, ciphertext.len());
+
+    let one_shot = params
+        .decrypt_in_place(PASSWORD, &mut ciphertext.clone())
+        .unwrap()
+        .to_vec();
+    assert_eq!(sk_bytes, one_shot);
+
+    let policy = pkcs5::pbes2::DecryptPolicy::default();
+
+    for &chunk_size in &[1, 7, 15, 16, 17, 33, 100, 512, 1000, 2384] {
+        let mut decryptor = params.decryptor(PASSWORD, &policy).unwrap();
+        let mut plaintext = Vec::new();
+        let mut buf = [0u8; 4096];
+
+        for chunk in ciphertext.chunks(chunk_size) {
+            plaintext.extend_from_slice(decryptor.update(chunk, &mut buf).unwrap());
+        }
+
+        plaintext.extend_from_slice(decryptor.finish(&mut buf).unwrap());
+        assert_eq!(plaintext, one_shot);
+    }
+
+    // Wrong password fails the final padding check
+    let mut decryptor = params.decryptor(b"hunter3", &policy).unwrap();
+    let mut buf = [0u8; 4096];
+    decryptor.update(&ciphertext, &mut buf).unwrap();
+    assert!(decryptor.finish(&mut buf).is_err());
+
+    // Algorithms not allowed by the policy are rejected before decrypting
+    let policy = pkcs5::pbes2::DecryptPolicy::new().with_ciphers(&[pkcs5::pbes2::AES_128_CBC_OID]);
+    assert!(params.decryptor(PASSWORD, &policy).is_err());
+}
@@ -5,3 +5,40 @@
 pub(crate) mod encrypted_private_key;
 pub(crate) mod private_key;
 pub(crate) mod public_key;
+
+#[cfg(feature = "pem")]
+use {
+    crate::{pem, Error, Result},
+    core::{convert::TryFrom, str},
+    der::{ErrorKind, Tag},
+};
+
+/// Pre-encapsulation boundary prefix which begins a PEM document.
+#[cfg(feature = "pem")]
+const PEM_BOUNDARY_PREFIX: &[u8] = b"-----BEGIN";
+
+/// Sniff whether `bytes` contain a PEM or an ASN.1 DER document.
+///
+/// Returns `Some` with the PEM text if `bytes` begin with a PEM
+/// pre-encapsulation boundary, `None` if they begin with a DER `SEQUENCE`
+/// tag, and an error otherwise.
+#[cfg(feature = "pem")]
+pub(crate) fn detect_pem(bytes: &[u8]) -> Result<Option<&str>> {
+    if bytes.starts_with(PEM_BOUNDARY_PREFIX) {
+        return str::from_utf8(bytes)
+            .map(Some)
+            .map_err(|_| pem::Error::CharacterEncoding.into());
+    }
+
+    match bytes.first() {
+        Some(&byte) if byte == Tag::Sequence.octet() => Ok(None),
+        Some(&byte) => Err(Error::Asn1(
+            ErrorKind::UnexpectedTag {
+                expected: Some(Tag::Sequence),
+                actual: Tag::try_from(byte)?,
+            }
+            .into(),
+        )),
+        None => Err(Error::Asn1(ErrorKind::Truncated.into())),
+    }
+}
@@ -1,6 +1,6 @@
 //! SPKI public key document.
 
-use crate::{error, Error, Result, SubjectPublicKeyInfo};
+use crate::{error, AlgorithmIdentifier, Error, Result, SubjectPublicKeyInfo};
 use alloc::{borrow::ToOwned, vec::Vec};
 use core::{
     convert::{TryFrom, TryInto},
@@ -13,7 +13,7 @@ use std::{fs, path::Path, str};
 
 #[cfg(feature = "pem")]
 use {
-    crate::{pem, LineEnding},
+    crate::{document, pem, LineEnding},
     alloc::string::String,
     core::str::FromStr,
 };
@@ -27,7 +27,7 @@ pub(crate) const PEM_TYPE_LABEL: &str = "PUBLIC KEY";
 /// This type provides storage for [`SubjectPublicKeyInfo`] encoded as ASN.1
 /// DER with the invariant that the contained-document is "well-formed", i.e.
 /// it will parse successfully according to this crate's parsing rules.
-#[derive(Clone)]
+#[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub struct PublicKeyDocument(Vec<u8>);
 
@@ -37,6 +37,21 @@ impl PublicKeyDocument {
         SubjectPublicKeyInfo::try_from(self.0.as_slice()).expect("malformed PublicKeyDocument")
     }
 
+    /// Create a [`PublicKeyDocument`] from an [`AlgorithmIdentifier`] and
+    /// raw public key bytes, which are encoded as the `subjectPublicKey`
+    /// `BIT STRING` of the resulting [`SubjectPublicKeyInfo`].
+    pub fn from_algorithm_and_key(
+        algorithm: AlgorithmIdentifier<'_>,
+        subject_public_key: &[u8],
+    ) -> Result<Self> {
+        SubjectPublicKeyInfo {
+            algorithm,
+            subject_public_key,
+        }
+        .to_vec()?
+        .try_into()
+    }
+
     /// Parse [`PublicKeyDocument`] from ASN.1 DER.
     pub fn from_der(bytes: &[u8]) -> Result<Self> {
         bytes.try_into()
@@ -61,6 +76,39 @@ impl PublicKeyDocument {
         Self::from_der(&*der_bytes)
     }
 
+    /// Parse [`PublicKeyDocument`] from PEM leniently, skipping a leading
+    /// UTF-8 byte order mark (BOM) and any data before the
+    /// `-----BEGIN PUBLIC KEY-----` header.
+    ///
+    /// The data before the header needn't be valid UTF-8, but the PEM
+    /// document itself, including its header, is still validated as with
+    /// [`PublicKeyDocument::from_pem`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem_lenient(pem: &[u8]) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec_lenient(pem)?;
+
+        if label != PEM_TYPE_LABEL {
+            return Err(pem::Error::Label.into());
+        }
+
+        Self::from_der(&der_bytes)
+    }
+
+    /// Parse [`PublicKeyDocument`] from either ASN.1 DER or PEM, detecting the
+    /// format from the leading bytes of the input.
+    ///
+    /// Input beginning with `-----BEGIN` is parsed as PEM, and input beginning
+    /// with a `SEQUENCE` tag (`0x30`) is parsed as DER.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match document::detect_pem(bytes)? {
+            Some(pem) => Self::from_pem(pem),
+            None => Self::from_der(bytes),
+        }
+    }
+
     /// Serialize [`PublicKeyDocument`] as PEM-encoded PKCS#8 (SPKI) string.
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
@@ -76,6 +124,35 @@ impl PublicKeyDocument {
         pem::encode_string(PEM_TYPE_LABEL, line_ending, &self.0).expect(error::PEM_ENCODING_MSG)
     }
 
+    /// Serialize [`PublicKeyDocument`] as PEM-encoded PKCS#8 (SPKI) with the given
+    /// [`LineEnding`], writing it into the provided buffer.
+    ///
+    /// The buffer must be at least [`pem_rfc7468::encoded_len`] of the
+    /// DER-encoded document in size.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem_slice<'o>(&self, buf: &'o mut [u8], line_ending: LineEnding) -> Result<&'o str> {
+        let pem = pem::encode(PEM_TYPE_LABEL, line_ending, &self.0, buf)?;
+        core::str::from_utf8(pem).map_err(|_| Error::Pem)
+    }
+
+    /// Serialize [`PublicKeyDocument`] as PEM-encoded PKCS#8 (SPKI) string
+    /// with the given [`LineEnding`], wrapping Base64 lines at `line_width`.
+    ///
+    /// See [`PrivateKeyDocument::to_pem_wrapped`] for more information.
+    ///
+    /// [`PrivateKeyDocument::to_pem_wrapped`]: crate::PrivateKeyDocument::to_pem_wrapped
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem_wrapped(&self, line_width: usize, line_ending: LineEnding) -> Result<String> {
+        Ok(pem::encode_string_wrapped(
+            PEM_TYPE_LABEL,
+            line_width,
+            line_ending,
+            &self.0,
+        )?)
+    }
+
     /// Load [`PublicKeyDocument`] from an ASN.1 DER-encoded file on the local
     /// filesystem (binary format).
     #[cfg(feature = "std")]
@@ -151,6 +228,15 @@ impl TryFrom<Vec<u8>> for PublicKeyDocument {
     }
 }
 
+/// Renders the document as PEM with [`LineEnding::LF`].
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl fmt::Display for PublicKeyDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_pem_with_le(LineEnding::LF))
+    }
+}
+
 impl fmt::Debug for PublicKeyDocument {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_tuple("PublicKeyDocument")
@@ -9,14 +9,20 @@ use pkcs5::EncryptionScheme;
 use crate::{EncryptedPrivateKeyDocument, PrivateKeyDocument};
 
 #[cfg(feature = "encryption")]
-use core::convert::TryInto;
-
-#[cfg(feature = "pem")]
 use {
-    crate::{error, pem, LineEnding},
-    zeroize::Zeroizing,
+    crate::PrivateKeyInfo,
+    alloc::{vec, vec::Vec},
+    core::convert::TryInto,
+    pkcs5::pbes2::{CipherRegistry, DecryptPolicy},
+    zeroize::Zeroize,
 };
 
+#[cfg(feature = "pem")]
+use crate::{error, pem, pem_encoder, LineEnding};
+
+#[cfg(any(feature = "encryption", feature = "pem"))]
+use zeroize::Zeroizing;
+
 /// Type label for PEM-encoded private keys.
 #[cfg(feature = "pem")]
 pub(crate) const PEM_TYPE_LABEL: &str = "ENCRYPTED PRIVATE KEY";
@@ -54,13 +60,170 @@ pub struct EncryptedPrivateKeyInfo<'a> {
 impl<'a> EncryptedPrivateKeyInfo<'a> {
     /// Attempt to decrypt this encrypted private key using the provided
     /// password to derive an encryption key.
+    ///
+    /// Returns [`der::ErrorKind::UnknownOid`] if the PBES2 cipher is not
+    /// supported by this crate.
+    ///
+    /// The password is only borrowed, and zeroizing it after use is up to
+    /// the caller (see [`EncryptedPrivateKeyInfo::decrypt_secret`]). The
+    /// encryption key derived from it is zeroized internally.
     #[cfg(feature = "encryption")]
     #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
     pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<PrivateKeyDocument> {
-        self.encryption_algorithm
-            .decrypt(password, self.encrypted_data)
+        self.decrypt_with_registry(password, &CipherRegistry::default())
+    }
+
+    /// Attempt to decrypt this encrypted private key using a password held
+    /// in a [`Zeroizing`] container, which wipes it from memory when dropped.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_secret(&self, password: &Zeroizing<Vec<u8>>) -> Result<PrivateKeyDocument> {
+        self.decrypt(password.as_slice())
+    }
+
+    /// Attempt to decrypt this encrypted private key using the provided
+    /// password to derive an encryption key.
+    ///
+    /// Ciphers which aren't built into [`pkcs5`] are looked up in the provided
+    /// [`CipherRegistry`], and [`der::ErrorKind::UnknownOid`] is returned if
+    /// none match.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_with_registry(
+        &self,
+        password: impl AsRef<[u8]>,
+        registry: &CipherRegistry<'_>,
+    ) -> Result<PrivateKeyDocument> {
+        let mut buffer = Zeroizing::new(vec![0u8; self.encrypted_data.len()]);
+        let plaintext = self.decrypt_in_place(
+            password.as_ref(),
+            &mut buffer,
+            registry,
+            &DecryptPolicy::default(),
+        )?;
+
+        plaintext.try_into()
+    }
+
+    /// Attempt to decrypt this encrypted private key using the provided
+    /// password to derive an encryption key, after checking that its
+    /// algorithms are allowed by the given [`DecryptPolicy`].
+    ///
+    /// Returns [`Error::Policy`] before attempting decryption if they aren't,
+    /// including for encryption schemes other than PBES2 unless the policy
+    /// allows all algorithms.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_with_policy(
+        &self,
+        password: impl AsRef<[u8]>,
+        policy: &DecryptPolicy<'_>,
+    ) -> Result<PrivateKeyDocument> {
+        let mut buffer = Zeroizing::new(vec![0u8; self.encrypted_data.len()]);
+        let plaintext = self.decrypt_in_place(
+            password.as_ref(),
+            &mut buffer,
+            &CipherRegistry::default(),
+            policy,
+        )?;
+
+        plaintext.try_into()
+    }
+
+    /// Attempt to decrypt this encrypted private key into the provided
+    /// buffer, returning the portion of it containing the DER-encoded
+    /// [`PrivateKeyInfo`].
+    ///
+    /// Unlike [`EncryptedPrivateKeyInfo::decrypt`] this doesn't allocate, so
+    /// the caller is responsible for zeroizing the buffer once it's no longer
+    /// needed. The buffer is zeroized if decryption fails.
+    ///
+    /// Returns [`der::ErrorKind::Overlength`] if the buffer is smaller than
+    /// the encrypted data.
+    ///
+    /// [`PrivateKeyInfo`]: crate::PrivateKeyInfo
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_into<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8]> {
+        self.decrypt_into_with_policy(password, buffer, &DecryptPolicy::default())
+    }
+
+    /// Attempt to decrypt this encrypted private key into the provided
+    /// buffer as in [`EncryptedPrivateKeyInfo::decrypt_into`], after checking
+    /// that its algorithms are allowed by the given [`DecryptPolicy`].
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt_into_with_policy<'b>(
+        &self,
+        password: impl AsRef<[u8]>,
+        buffer: &'b mut [u8],
+        policy: &DecryptPolicy<'_>,
+    ) -> Result<&'b [u8]> {
+        let buffer = buffer
+            .get_mut(..self.encrypted_data.len())
+            .ok_or(der::ErrorKind::Overlength)?;
+
+        self.decrypt_in_place(
+            password.as_ref(),
+            buffer,
+            &CipherRegistry::default(),
+            policy,
+        )
+    }
+
+    /// Decrypt this encrypted private key into the provided buffer, which
+    /// must be the same size as the encrypted data.
+    ///
+    /// All other decryption methods go through this one, so the policy is
+    /// checked before any cryptographic operations regardless of which of
+    /// them is used. The buffer is zeroized if decryption fails.
+    #[cfg(feature = "encryption")]
+    fn decrypt_in_place<'b>(
+        &self,
+        password: &[u8],
+        buffer: &'b mut [u8],
+        registry: &CipherRegistry<'_>,
+        policy: &DecryptPolicy<'_>,
+    ) -> Result<&'b [u8]> {
+        policy.check_scheme(&self.encryption_algorithm)?;
+        self.check_cipher(registry)?;
+        buffer.copy_from_slice(self.encrypted_data);
+
+        let result = self
+            .encryption_algorithm
+            .decrypt_in_place_with_registry(password, buffer, registry)
             .map_err(|_| Error::Crypto)
-            .and_then(TryInto::try_into)
+            .and_then(|plaintext| {
+                // Ensure the plaintext is well-formed
+                PrivateKeyInfo::try_from(plaintext)?;
+                Ok(plaintext.len())
+            });
+
+        match result {
+            Ok(len) => Ok(&buffer[..len]),
+            Err(err) => {
+                buffer.zeroize();
+                Err(err)
+            }
+        }
+    }
+
+    /// Ensure the PBES2 cipher (if any) can be found in the given registry.
+    #[cfg(feature = "encryption")]
+    fn check_cipher(&self, registry: &CipherRegistry<'_>) -> Result<()> {
+        if let Some(params) = self.encryption_algorithm.pbes2() {
+            let oid = params.encryption.oid();
+
+            if registry.get(oid).is_none() {
+                return Err(der::ErrorKind::UnknownOid { oid }.into());
+            }
+        }
+
+        Ok(())
     }
 
     /// Encode this [`EncryptedPrivateKeyInfo`] as ASN.1 DER.
@@ -87,6 +250,17 @@ impl<'a> EncryptedPrivateKeyInfo<'a> {
                 .expect(error::PEM_ENCODING_MSG),
         )
     }
+
+    /// Encode this [`EncryptedPrivateKeyInfo`] as PEM-encoded ASN.1 DER with
+    /// the given [`LineEnding`], writing it into the provided buffer.
+    ///
+    /// The buffer must be at least [`pem_rfc7468::encoded_len`] of the DER
+    /// encoding in size.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem_slice<'o>(&self, buf: &'o mut [u8], line_ending: LineEnding) -> Result<&'o str> {
+        pem_encoder::encode_to_slice(PEM_TYPE_LABEL, self, line_ending, buf)
+    }
 }
 
 impl<'a> Decodable<'a> for EncryptedPrivateKeyInfo<'a> {
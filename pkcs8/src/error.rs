@@ -57,6 +57,16 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     PermissionDenied,
 
+    /// Public key in a PKCS#8 v2 document doesn't match the private key.
+    ///
+    /// See [`PrivateKeyInfo::verify_keypair`][`crate::PrivateKeyInfo::verify_keypair`].
+    PublicKeyMismatch,
+
+    /// Encryption algorithm not allowed by a [`DecryptPolicy`][`pkcs5::pbes2::DecryptPolicy`].
+    #[cfg(feature = "pkcs5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs5")))]
+    Policy(pkcs5::pbes2::PolicyViolation),
+
     /// PKCS#1 errors.
     #[cfg(feature = "pkcs1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pkcs1")))]
@@ -78,6 +88,9 @@ impl fmt::Display for Error {
             Error::Pem => f.write_str("PKCS#8 PEM error"),
             #[cfg(feature = "std")]
             Error::PermissionDenied => f.write_str("permission denied"),
+            Error::PublicKeyMismatch => f.write_str("PKCS#8 public key does not match private key"),
+            #[cfg(feature = "pkcs5")]
+            Error::Policy(violation) => write!(f, "{}", violation),
             #[cfg(feature = "pkcs1")]
             Error::Pkcs1(err) => write!(f, "{}", err),
         }
@@ -114,6 +127,13 @@ impl From<pkcs1::Error> for Error {
     }
 }
 
+#[cfg(feature = "pkcs5")]
+impl From<pkcs5::pbes2::PolicyViolation> for Error {
+    fn from(violation: pkcs5::pbes2::PolicyViolation) -> Error {
+        Error::Policy(violation)
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Error {
@@ -99,6 +99,12 @@
 //! a blanket impl of PKCS#8 support for types which impl the traits from the
 //! [`pkcs1`] crate (e.g. `FromRsaPrivateKey`, `ToRsaPrivateKey`).
 //!
+//! # SEC1 support (optional)
+//! When the `sec1` feature of this crate is enabled,
+//! [`PrivateKeyInfo::ec_private_key`] can be used to decode the SEC1
+//! `ECPrivateKey` contained in an elliptic curve private key, checking
+//! the curve OID it contains is consistent with the [`AlgorithmIdentifier`].
+//!
 //! # Minimum Supported Rust Version
 //! This crate requires **Rust 1.51** at a minimum.
 //!
@@ -130,13 +136,15 @@ mod version;
 
 #[cfg(feature = "alloc")]
 mod document;
+#[cfg(feature = "pem")]
+mod pem_encoder;
 
 #[cfg(feature = "pkcs5")]
 pub(crate) mod encrypted_private_key_info;
 
 pub use crate::{
     error::{Error, Result},
-    private_key_info::PrivateKeyInfo,
+    private_key_info::{PrivateKeyInfo, PrivateKeyInfoBuilder},
     traits::{FromPrivateKey, FromPublicKey},
     version::Version,
 };
@@ -162,6 +170,9 @@ pub use pkcs1;
 #[cfg(feature = "pkcs5")]
 pub use pkcs5;
 
+#[cfg(feature = "sec1")]
+pub use sec1;
+
 #[cfg(all(feature = "alloc", feature = "pkcs5"))]
 pub use crate::document::encrypted_private_key::EncryptedPrivateKeyDocument;
 
@@ -0,0 +1,39 @@
+//! PEM encoding into fixed-size buffers.
+
+use crate::{pem, Error, LineEnding, Result};
+use core::str;
+use der::{Encodable, Encoder, Writer};
+
+/// Encode the given message as PEM with the given type label, writing it
+/// into the provided buffer without an intermediate DER-encoded copy.
+pub(crate) fn encode_to_slice<'o>(
+    label: &str,
+    message: &dyn Encodable,
+    line_ending: LineEnding,
+    buf: &'o mut [u8],
+) -> Result<&'o str> {
+    let mut writer = PemWriter(pem::Encoder::new(label, line_ending, buf)?);
+    let mut encoder = Encoder::from_writer(&mut writer);
+
+    message
+        .encode(&mut encoder)
+        .and_then(|_| encoder.finish_len())
+        .map_err(|e| match e.kind() {
+            der::ErrorKind::Pem(err) => Error::from(err),
+            _ => Error::from(e),
+        })?;
+
+    let pem = writer.0.finish()?;
+    str::from_utf8(pem).map_err(|_| Error::Pem)
+}
+
+/// [`Writer`] which PEM-encodes everything written to it.
+struct PemWriter<'l, 'o>(pem::Encoder<'l, 'o>);
+
+impl Writer for PemWriter<'_, '_> {
+    fn write(&mut self, slice: &[u8]) -> der::Result<()> {
+        self.0
+            .encode(slice)
+            .map_err(|e| der::ErrorKind::Pem(e).into())
+    }
+}
@@ -1,11 +1,12 @@
 //! PKCS#8 `PrivateKeyInfo`.
 
-use crate::{AlgorithmIdentifier, Error, Result, Version};
+use crate::{AlgorithmIdentifier, Error, ObjectIdentifier, Result, Version};
 use core::{convert::TryFrom, fmt};
 use der::{
-    asn1::{Any, BitString, ContextSpecific, OctetString},
-    Decodable, Decoder, Encodable, Message, TagMode, TagNumber,
+    asn1::{Any, BitString, ContextSpecific, OctetString, Sequence},
+    Decodable, Decoder, Encodable, Message, Tag, TagMode, TagNumber,
 };
+use spki::CurveAlgorithm;
 
 #[cfg(feature = "alloc")]
 use crate::PrivateKeyDocument;
@@ -13,22 +14,36 @@ use crate::PrivateKeyDocument;
 #[cfg(feature = "encryption")]
 use {
     crate::EncryptedPrivateKeyDocument,
+    alloc::vec,
+    pkcs5::pbes2,
     rand_core::{CryptoRng, RngCore},
 };
 
 #[cfg(feature = "pem")]
 use {
-    crate::{error, pem, LineEnding},
+    crate::{error, pem, pem_encoder, LineEnding},
     alloc::string::String,
     zeroize::Zeroizing,
 };
 
+#[cfg(feature = "sec1")]
+use sec1::EcPrivateKey;
+
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+/// Context-specific tag number for the attributes.
+const ATTRIBUTES_TAG: TagNumber = TagNumber::new(0);
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
 
+/// Elliptic curve public key algorithm (`id-ecPublicKey`) [`ObjectIdentifier`].
+///
+/// <http://oid-info.com/get/1.2.840.10045.2.1>
+#[cfg(feature = "sec1")]
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
 /// Type label for PEM-encoded private keys.
 #[cfg(feature = "pem")]
 pub(crate) const PEM_TYPE_LABEL: &str = "PRIVATE KEY";
@@ -36,8 +51,10 @@ pub(crate) const PEM_TYPE_LABEL: &str = "PRIVATE KEY";
 /// PKCS#8 `PrivateKeyInfo`.
 ///
 /// ASN.1 structure containing an [`AlgorithmIdentifier`], private key
-/// data in an algorithm specific format, and optional attributes
-/// (ignored by this implementation).
+/// data in an algorithm specific format, and optional attributes.
+///
+/// Use [`PrivateKeyInfoBuilder`] to assemble a [`PrivateKeyInfo`] field by
+/// field while checking the fields are consistent with each other.
 ///
 /// Supports PKCS#8 v1 as described in [RFC 5208] and PKCS#8 v2 as described
 /// in [RFC 5958]. PKCS#8 v2 keys include an additional public key field.
@@ -96,6 +113,10 @@ pub(crate) const PEM_TYPE_LABEL: &str = "PRIVATE KEY";
 /// [RFC 5958 Section 2]: https://datatracker.ietf.org/doc/html/rfc5958#section-2
 #[derive(Clone)]
 pub struct PrivateKeyInfo<'a> {
+    /// PKCS#8 [`Version`] this structure was decoded as, which is only
+    /// consulted when there's no public key: see [`PrivateKeyInfo::version`].
+    version: Version,
+
     /// X.509 [`AlgorithmIdentifier`] for the private key type.
     pub algorithm: AlgorithmIdentifier<'a>,
 
@@ -104,6 +125,9 @@ pub struct PrivateKeyInfo<'a> {
 
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
+
+    /// DER-encoded attributes: see [`PrivateKeyInfo::attributes`].
+    attributes: Option<&'a [u8]>,
 }
 
 impl<'a> PrivateKeyInfo<'a> {
@@ -113,23 +137,153 @@ impl<'a> PrivateKeyInfo<'a> {
     /// to `None`, helpful if you aren't using those.
     pub fn new(algorithm: AlgorithmIdentifier<'a>, private_key: &'a [u8]) -> Self {
         Self {
+            version: Version::V1,
             algorithm,
             private_key,
             public_key: None,
+            attributes: None,
         }
     }
 
+    /// Create a new [`PrivateKeyInfoBuilder`].
+    pub fn builder() -> PrivateKeyInfoBuilder<'a> {
+        PrivateKeyInfoBuilder::default()
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this private key.
+    pub fn algorithm(&self) -> &AlgorithmIdentifier<'a> {
+        &self.algorithm
+    }
+
+    /// Get the algorithm [`ObjectIdentifier`] for this private key.
+    pub fn algorithm_oid(&self) -> ObjectIdentifier {
+        self.algorithm.oid
+    }
+
+    /// Get the DER-encoded attributes, i.e. the contents of the
+    /// `SET OF Attribute` sans its tag and length, if present.
+    ///
+    /// Use [`PrivateKeyInfo::builder`] to construct a key with attributes.
+    pub fn attributes(&self) -> Option<&'a [u8]> {
+        self.attributes
+    }
+
     /// Get the PKCS#8 [`Version`] for this structure.
     ///
-    /// [`Version::V1`] if `public_key` is `None`, [`Version::V2`] if `Some`.
+    /// [`Version::V2`] if `public_key` is `Some`. Otherwise [`Version::V1`],
+    /// unless this structure was decoded from a [`Version::V2`] document
+    /// which omits the public key.
     pub fn version(&self) -> Version {
         if self.public_key.is_some() {
             Version::V2
         } else {
-            Version::V1
+            self.version
         }
     }
 
+    /// Get the raw private key material for this key's algorithm, suitable
+    /// for handing to a cryptographic backend.
+    ///
+    /// This is the contents of the `privateKey` `OCTET STRING`, except for
+    /// the [RFC 8410] algorithms (X25519, X448, Ed25519 and Ed448) whose
+    /// `CurvePrivateKey` is itself an `OCTET STRING`, which is unwrapped.
+    /// Their raw keys are additionally checked to be of the expected length
+    /// (32 bytes for X25519 and Ed25519, 56 for X448 and 57 for Ed448).
+    ///
+    /// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-7
+    pub fn raw_private_key(&self) -> Result<&'a [u8]> {
+        let key_len = CurveAlgorithm::try_from(self.algorithm.oid)
+            .ok()
+            .map(CurveAlgorithm::key_len);
+
+        match key_len {
+            Some(key_len) => {
+                let raw_key = OctetString::from_der(self.private_key)?.as_bytes();
+
+                if raw_key.len() == key_len {
+                    Ok(raw_key)
+                } else {
+                    Err(Tag::OctetString.length_error().into())
+                }
+            }
+            None => Ok(self.private_key),
+        }
+    }
+
+    /// Get the raw private key material as in [`PrivateKeyInfo::raw_private_key`],
+    /// after checking the algorithm [`ObjectIdentifier`] is the expected one.
+    pub fn raw_private_key_for(&self, expected_oid: ObjectIdentifier) -> Result<&'a [u8]> {
+        self.algorithm.assert_algorithm_oid(expected_oid)?;
+        self.raw_private_key()
+    }
+
+    /// Verify the optional `publicKey` is consistent with the private key,
+    /// using a `verifier` provided by a crate implementing the algorithm.
+    ///
+    /// The `verifier` is called with the [`PrivateKeyInfo::raw_private_key`]
+    /// and the public key, and should return `true` if they form a valid key
+    /// pair. Returns [`Error::PublicKeyMismatch`] if it returns `false`.
+    ///
+    /// Succeeds without calling `verifier` if there is no public key.
+    pub fn verify_keypair<F>(&self, verifier: F) -> Result<()>
+    where
+        F: FnOnce(&[u8], &[u8]) -> bool,
+    {
+        let public_key = match self.public_key {
+            Some(public_key) => public_key,
+            None => return Ok(()),
+        };
+
+        if verifier(self.raw_private_key()?, public_key) {
+            Ok(())
+        } else {
+            Err(Error::PublicKeyMismatch)
+        }
+    }
+
+    /// Convert this [`PrivateKeyInfo`] into a PKCS#8 v1 structure by removing
+    /// the public key, for interop with consumers which don't support v2.
+    ///
+    /// Any attributes are omitted from the encoding as well.
+    pub fn to_v1(&self) -> Self {
+        Self {
+            version: Version::V1,
+            public_key: None,
+            attributes: None,
+            ..self.clone()
+        }
+    }
+
+    /// Decode the SEC1 [`EcPrivateKey`] contained in an elliptic curve
+    /// (`id-ecPublicKey`) private key.
+    ///
+    /// Some encoders (notably OpenSSL) redundantly include the curve OID in
+    /// the `parameters` of the inner `ECPrivateKey` as well as in the outer
+    /// [`AlgorithmIdentifier`]. If present, the inner curve OID must match
+    /// the outer one, otherwise [`Error::ParametersMalformed`] is returned.
+    /// If absent, it is populated from the [`AlgorithmIdentifier`].
+    #[cfg(feature = "sec1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sec1")))]
+    pub fn ec_private_key(&self) -> Result<EcPrivateKey<'a>> {
+        self.algorithm.assert_algorithm_oid(EC_PUBLIC_KEY_OID)?;
+
+        let curve = self
+            .algorithm
+            .parameters_oid()
+            .map_err(|_| Error::ParametersMalformed)?;
+
+        let mut ec_private_key = EcPrivateKey::from_der(self.private_key)?;
+
+        if let Some(params) = ec_private_key.parameters {
+            if params.named_curve() != Some(curve) {
+                return Err(Error::ParametersMalformed);
+            }
+        }
+
+        ec_private_key.parameters = Some(curve.into());
+        Ok(ec_private_key)
+    }
+
     /// Encrypt this private key using a symmetric encryption key derived
     /// from the provided password.
     ///
@@ -144,6 +298,67 @@ impl<'a> PrivateKeyInfo<'a> {
         PrivateKeyDocument::from(self).encrypt(rng, password)
     }
 
+    /// Encrypt this private key using a symmetric encryption key derived
+    /// from the provided password, with the key derivation function and
+    /// cipher given by `params`.
+    ///
+    /// The salt and IV in `params` are replaced with fresh ones of the same
+    /// length generated using `rng`, so `params` only selects the algorithms
+    /// and their settings (e.g. the PBKDF2 iteration count or the scrypt
+    /// cost). Only the AES-CBC ciphers are supported: [`Error::Crypto`] is
+    /// returned for any others.
+    ///
+    /// The intermediate DER encoding of this private key is zeroized once
+    /// it has been encrypted.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn to_encrypted(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+        params: pbes2::Parameters<'_>,
+    ) -> Result<EncryptedPrivateKeyDocument> {
+        let salt_len = match &params.kdf {
+            pbes2::Kdf::Pbkdf2(kdf_params) => kdf_params.salt.len(),
+            pbes2::Kdf::Scrypt(kdf_params) => kdf_params.salt.len(),
+            _ => return Err(Error::Crypto),
+        };
+
+        let mut salt = vec![0u8; salt_len];
+        rng.fill_bytes(&mut salt);
+
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let kdf = match params.kdf {
+            pbes2::Kdf::Pbkdf2(kdf_params) => pbes2::Kdf::Pbkdf2(pbes2::Pbkdf2Params {
+                salt: &salt,
+                ..kdf_params
+            }),
+            pbes2::Kdf::Scrypt(kdf_params) => pbes2::Kdf::Scrypt(pbes2::ScryptParams {
+                salt: &salt,
+                ..kdf_params
+            }),
+            _ => return Err(Error::Crypto),
+        };
+
+        let encryption = match params.encryption {
+            pbes2::EncryptionScheme::Aes128Cbc { .. } => {
+                pbes2::EncryptionScheme::Aes128Cbc { iv: &iv }
+            }
+            pbes2::EncryptionScheme::Aes192Cbc { .. } => {
+                pbes2::EncryptionScheme::Aes192Cbc { iv: &iv }
+            }
+            pbes2::EncryptionScheme::Aes256Cbc { .. } => {
+                pbes2::EncryptionScheme::Aes256Cbc { iv: &iv }
+            }
+            _ => return Err(Error::Crypto),
+        };
+
+        PrivateKeyDocument::from(self)
+            .encrypt_with_params(pbes2::Parameters { kdf, encryption }, password)
+    }
+
     /// Encode this [`PrivateKeyInfo`] as ASN.1 DER.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -168,6 +383,17 @@ impl<'a> PrivateKeyInfo<'a> {
                 .expect(error::PEM_ENCODING_MSG),
         )
     }
+
+    /// Encode this [`PrivateKeyInfo`] as PEM-encoded ASN.1 DER with the given
+    /// [`LineEnding`], writing it into the provided buffer.
+    ///
+    /// The buffer must be at least [`pem_rfc7468::encoded_len`] of the DER
+    /// encoding in size, and should be zeroized by the caller after use.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem_slice<'o>(&self, buf: &'o mut [u8], line_ending: LineEnding) -> Result<&'o str> {
+        pem_encoder::encode_to_slice(PEM_TYPE_LABEL, self, line_ending, buf)
+    }
 }
 
 impl<'a> Decodable<'a> for PrivateKeyInfo<'a> {
@@ -177,26 +403,39 @@ impl<'a> Decodable<'a> for PrivateKeyInfo<'a> {
             let version = Version::decode(decoder)?;
             let algorithm = decoder.decode()?;
             let private_key = decoder.octet_string()?.into();
+            let attributes = match decoder.peek().map(Tag::try_from).transpose()? {
+                Some(tag) if tag == attributes_tag() => Some(decoder.any()?.value()),
+                _ => None,
+            };
             let public_key = decoder
                 .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, TagMode::Implicit)?
                 .map(|bs| bs.as_bytes());
 
-            if version.has_public_key() != public_key.is_some() {
-                return Err(decoder.value_error(der::Tag::ContextSpecific {
+            // RFC 5958 only requires v2 when a public key is present, so
+            // v2 documents with the public key omitted are also accepted.
+            if public_key.is_some() && !version.has_public_key() {
+                return Err(decoder.value_error(Tag::ContextSpecific {
                     constructed: true,
                     number: PUBLIC_KEY_TAG,
                 }));
             }
 
-            // Ignore any remaining extension fields
-            while !decoder.is_finished() {
+            // Ignore any remaining extension fields unless decoding strictly
+            while let Some(octet) = decoder.peek() {
+                if decoder.is_strict() {
+                    let tag = Tag::try_from(octet)?;
+                    return Err(decoder.error(tag.unexpected_error(None).kind()));
+                }
+
                 decoder.decode::<ContextSpecific<Any<'_>>>()?;
             }
 
             Ok(Self {
+                version,
                 algorithm,
                 private_key,
                 public_key,
+                attributes,
             })
         })
     }
@@ -211,6 +450,10 @@ impl<'a> Message<'a> for PrivateKeyInfo<'a> {
             &u8::from(self.version()),
             &self.algorithm,
             &OctetString::new(self.private_key)?,
+            &self
+                .attributes
+                .map(|attrs| Any::new(attributes_tag(), attrs))
+                .transpose()?,
             &self
                 .public_key
                 .map(|pk| {
@@ -239,17 +482,117 @@ impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
             .field("version", &self.version())
             .field("algorithm", &self.algorithm)
             .field("public_key", &self.public_key)
+            .field("attributes", &self.attributes)
+            .finish() // TODO: use `finish_non_exhaustive` when stable
+    }
+}
+
+/// Builder for [`PrivateKeyInfo`] which assembles it field by field.
+///
+/// The PKCS#8 [`Version`] is determined by the fields which are set: keys
+/// with a public key are encoded as [`Version::V2`], and all others as
+/// [`Version::V1`].
+#[derive(Clone, Default)]
+pub struct PrivateKeyInfoBuilder<'a> {
+    /// X.509 [`AlgorithmIdentifier`] for the private key type.
+    algorithm: Option<AlgorithmIdentifier<'a>>,
+
+    /// Private key data.
+    private_key: Option<&'a [u8]>,
+
+    /// Public key data.
+    public_key: Option<&'a [u8]>,
+
+    /// DER-encoded attributes.
+    attributes: Option<&'a [u8]>,
+}
+
+impl<'a> PrivateKeyInfoBuilder<'a> {
+    /// Set the [`AlgorithmIdentifier`] for the private key type.
+    pub fn algorithm(mut self, algorithm: AlgorithmIdentifier<'a>) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Set the private key data, in the format specific to the algorithm.
+    pub fn private_key(mut self, private_key: &'a [u8]) -> Self {
+        self.private_key = Some(private_key);
+        self
+    }
+
+    /// Set the public key data, which makes this a PKCS#8 v2 key if `Some`.
+    pub fn public_key(mut self, public_key: Option<&'a [u8]>) -> Self {
+        self.public_key = public_key;
+        self
+    }
+
+    /// Set the DER-encoded attributes, i.e. the concatenated DER encodings of
+    /// each `Attribute` in the `SET OF Attribute`.
+    pub fn attributes(mut self, attributes: &'a [u8]) -> Self {
+        self.attributes = Some(attributes);
+        self
+    }
+
+    /// Build the [`PrivateKeyInfo`].
+    ///
+    /// Returns [`Error::KeyMalformed`] if the algorithm or a non-empty
+    /// private key haven't been set, or an ASN.1 error if the attributes
+    /// aren't a series of DER-encoded `Attribute` sequences or the result
+    /// can't be encoded as DER.
+    pub fn build(self) -> Result<PrivateKeyInfo<'a>> {
+        let algorithm = self.algorithm.ok_or(Error::KeyMalformed)?;
+        let private_key = self
+            .private_key
+            .filter(|private_key| !private_key.is_empty())
+            .ok_or(Error::KeyMalformed)?;
+
+        if let Some(attributes) = self.attributes {
+            let mut decoder = Decoder::new(attributes);
+
+            while !decoder.is_finished() {
+                decoder.decode::<Sequence<'_>>()?;
+            }
+        }
+
+        let private_key_info = PrivateKeyInfo {
+            version: Version::V1,
+            algorithm,
+            private_key,
+            public_key: self.public_key,
+            attributes: self.attributes,
+        };
+
+        private_key_info.encoded_len()?;
+        Ok(private_key_info)
+    }
+}
+
+impl<'a> fmt::Debug for PrivateKeyInfoBuilder<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKeyInfoBuilder")
+            .field("algorithm", &self.algorithm)
+            .field("public_key", &self.public_key)
+            .field("attributes", &self.attributes)
             .finish() // TODO: use `finish_non_exhaustive` when stable
     }
 }
 
+/// [`Tag`] of the attributes field.
+fn attributes_tag() -> Tag {
+    Tag::ContextSpecific {
+        constructed: true,
+        number: ATTRIBUTES_TAG,
+    }
+}
+
 #[cfg(feature = "subtle")]
 #[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
 impl<'a> ConstantTimeEq for PrivateKeyInfo<'a> {
     fn ct_eq(&self, other: &Self) -> Choice {
         // NOTE: public fields are not compared in constant time
-        let public_fields_eq =
-            self.algorithm == other.algorithm && self.public_key == other.public_key;
+        let public_fields_eq = self.algorithm == other.algorithm
+            && self.public_key == other.public_key
+            && self.attributes == other.attributes;
 
         self.private_key.ct_eq(other.private_key) & Choice::from(public_fields_eq as u8)
     }
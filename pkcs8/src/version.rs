@@ -1,6 +1,6 @@
 //! PKCS#8 version identifier.
 
-use core::convert::TryFrom;
+use core::{convert::TryFrom, fmt, str::FromStr};
 use der::{Decodable, Decoder, Encodable, Encoder, Tag, Tagged};
 
 use crate::Error;
@@ -43,6 +43,29 @@ impl Encodable for Version {
     }
 }
 
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Version::V1 => f.write_str("v1"),
+            Version::V2 => f.write_str("v2"),
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    /// Parse a version from either its name (`v1`, `v2`) or its numeric
+    /// value as encoded in a PKCS#8 document (`0`, `1`).
+    fn from_str(s: &str) -> Result<Version, Error> {
+        match s {
+            "v1" | "0" => Ok(Version::V1),
+            "v2" | "1" => Ok(Version::V2),
+            _ => Err(Self::TAG.value_error().into()),
+        }
+    }
+}
+
 impl From<Version> for u8 {
     fn from(version: Version) -> Self {
         version as u8
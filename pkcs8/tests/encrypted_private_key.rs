@@ -7,9 +7,12 @@ use hex_literal::hex;
 use pkcs8::{pkcs5::pbes2, EncryptedPrivateKeyInfo};
 
 #[cfg(feature = "encryption")]
-use pkcs8::PrivateKeyDocument;
+use {
+    pkcs8::{PrivateKeyDocument, PrivateKeyInfo},
+    zeroize::Zeroizing,
+};
 
-#[cfg(feature = "pem")]
+#[cfg(feature = "alloc")]
 use pkcs8::EncryptedPrivateKeyDocument;
 
 /// Ed25519 PKCS#8 private key plaintext encoded as ASN.1 DER
@@ -26,6 +29,17 @@ const ED25519_DER_PLAINTEXT_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pr
 const ED25519_DER_AES128_PBKDF2_SHA1_EXAMPLE: &[u8] =
     include_bytes!("examples/ed25519-encpriv-aes128-pbkdf2-sha1.der");
 
+/// Ed25519 PKCS#8 private key encrypted with PBES1 using DES-CBC and SHA-1
+/// (`pbeWithSHA1AndDES-CBC`).
+///
+/// Generated using:
+///
+/// ```
+/// $ openssl pkcs8 -v1 PBE-SHA1-DES -provider legacy -provider default -topk8 -inform der -in ed25519-priv.der -outform der -out ed25519-encpriv-pbes1-sha1-des.der
+/// ```
+const ED25519_DER_PBES1_SHA1_DES_EXAMPLE: &[u8] =
+    include_bytes!("examples/ed25519-encpriv-pbes1-sha1-des.der");
+
 /// Ed25519 PKCS#8 encrypted private key (PBES2 + AES-256-CBC + PBKDF2-SHA256) encoded as ASN.1 DER.
 ///
 /// Generated using:
@@ -157,6 +171,17 @@ fn decode_ed25519_encpriv_aes256_pbkdf2_sha256_pem() {
     );
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_encpriv_aes256_pbkdf2_sha256_pem_lenient() {
+    let mut pem = Vec::from(&b"\xEF\xBB\xBFjunk \xFF\n"[..]);
+    pem.extend_from_slice(ED25519_PEM_AES256_PBKDF2_SHA256_EXAMPLE.as_bytes());
+
+    let pkcs8_doc = EncryptedPrivateKeyDocument::from_pem_lenient(&pem).unwrap();
+    assert_eq!(pkcs8_doc.as_ref(), ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE);
+    assert!(EncryptedPrivateKeyDocument::from_bytes(&pem).is_err());
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn decrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
@@ -166,6 +191,19 @@ fn decrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
     assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_with_zeroizing_password() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let password = Zeroizing::new(PASSWORD.to_vec());
+    let pk = enc_pk.decrypt_secret(&password).unwrap();
+    assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
+
+    let wrong_password = Zeroizing::new(b"wrong password".to_vec());
+    assert!(enc_pk.decrypt_secret(&wrong_password).is_err());
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn decrypt_ed25519_der_encpriv_aes256_scrypt() {
@@ -174,6 +212,215 @@ fn decrypt_ed25519_der_encpriv_aes256_scrypt() {
     assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_into_buffer() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let mut buf = [0u8; 64];
+    let pk = enc_pk.decrypt_into(PASSWORD, &mut buf).unwrap();
+    assert_eq!(pk, ED25519_DER_PLAINTEXT_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_into_short_buffer() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let mut buf = [0u8; 32];
+    let err = enc_pk.decrypt_into(PASSWORD, &mut buf).unwrap_err();
+    assert_eq!(err, pkcs8::der::ErrorKind::Overlength.into());
+}
+
+/// Policy only allowing AES-256-CBC with PBKDF2-HMAC-SHA256.
+#[cfg(feature = "encryption")]
+const AES256_PBKDF2_SHA256_POLICY: pbes2::DecryptPolicy<'static> = pbes2::DecryptPolicy::new()
+    .with_ciphers(&[pbes2::AES_256_CBC_OID])
+    .with_kdfs(&[pbes2::PBKDF2_OID])
+    .with_prfs(&[pbes2::Pbkdf2Prf::HmacWithSha256]);
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_allowed_by_policy() {
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let pk = enc_pk
+        .decrypt_with_policy(PASSWORD, &AES256_PBKDF2_SHA256_POLICY)
+        .unwrap();
+    assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
+
+    let pk = enc_pk
+        .decrypt_with_policy(PASSWORD, &pbes2::DecryptPolicy::default())
+        .unwrap();
+    assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn reject_ed25519_der_encpriv_disallowed_by_policy() {
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_SCRYPT_EXAMPLE).unwrap();
+    let err = enc_pk
+        .decrypt_with_policy(PASSWORD, &AES256_PBKDF2_SHA256_POLICY)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        pkcs8::Error::Policy(pbes2::PolicyViolation::Kdf(pbes2::SCRYPT_OID))
+    );
+
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES128_PBKDF2_SHA1_EXAMPLE).unwrap();
+    let err = enc_pk
+        .decrypt_with_policy(PASSWORD, &AES256_PBKDF2_SHA256_POLICY)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        pkcs8::Error::Policy(pbes2::PolicyViolation::Cipher(pbes2::AES_128_CBC_OID))
+    );
+
+    let mut buf = [0u8; 128];
+    let err = enc_pk
+        .decrypt_into_with_policy(PASSWORD, &mut buf, &AES256_PBKDF2_SHA256_POLICY)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        pkcs8::Error::Policy(pbes2::PolicyViolation::Cipher(pbes2::AES_128_CBC_OID))
+    );
+
+    let policy = pbes2::DecryptPolicy::new().with_prfs(&[pbes2::Pbkdf2Prf::HmacWithSha512]);
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let err = enc_pk.decrypt_with_policy(PASSWORD, &policy).unwrap_err();
+    assert_eq!(
+        err,
+        pkcs8::Error::Policy(pbes2::PolicyViolation::Prf(
+            pbes2::Pbkdf2Prf::HmacWithSha256
+        ))
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn reject_ed25519_der_encpriv_pbes1_by_policy() {
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(ED25519_DER_PBES1_SHA1_DES_EXAMPLE).unwrap();
+    let err = enc_pk
+        .decrypt_with_policy(PASSWORD, &AES256_PBKDF2_SHA256_POLICY)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        pkcs8::Error::Policy(pbes2::PolicyViolation::Scheme(
+            pkcs8::pkcs5::pbes1::PBE_WITH_SHA1_AND_DES_CBC_OID
+        ))
+    );
+
+    // PBES1 decryption isn't supported, but is allowed by the default policy
+    let err = enc_pk
+        .decrypt_with_policy(PASSWORD, &pbes2::DecryptPolicy::default())
+        .unwrap_err();
+    assert_eq!(err, pkcs8::Error::Crypto);
+}
+
+/// Dummy cipher which XORs the ciphertext with the IV.
+#[cfg(feature = "encryption")]
+struct XorCipher;
+
+#[cfg(feature = "encryption")]
+impl pbes2::BlockCipherDecryptor for XorCipher {
+    fn key_size(&self) -> usize {
+        16
+    }
+
+    fn decrypt_in_place<'b>(
+        &self,
+        key: &[u8],
+        iv: &[u8],
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], pkcs5::CryptoError> {
+        assert_eq!(key.len(), self.key_size());
+
+        for (byte, mask) in buffer.iter_mut().zip(iv.iter().cycle()) {
+            *byte ^= mask;
+        }
+
+        Ok(buffer)
+    }
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_der_encpriv_with_registered_cipher() {
+    use pkcs8::{der::asn1::OctetString, ObjectIdentifier};
+
+    const XOR_CIPHER_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.6.1.4.1.99999.1");
+    let iv = hex!("0123456789abcdef");
+
+    let ciphertext = ED25519_DER_PLAINTEXT_EXAMPLE
+        .iter()
+        .zip(iv.iter().cycle())
+        .map(|(byte, mask)| byte ^ mask)
+        .collect::<Vec<u8>>();
+
+    let enc_pk = EncryptedPrivateKeyInfo {
+        encryption_algorithm: pbes2::Parameters {
+            kdf: pbes2::Pbkdf2Params::hmac_with_sha256(2048, &hex!("79d982e70df91a88"))
+                .unwrap()
+                .into(),
+            encryption: pbes2::EncryptionScheme::Other {
+                oid: XOR_CIPHER_OID,
+                parameters: Some(OctetString::new(&iv).unwrap().into()),
+            },
+        }
+        .into(),
+        encrypted_data: &ciphertext,
+    };
+
+    assert!(enc_pk.decrypt(PASSWORD).is_err());
+
+    let registry = pbes2::CipherRegistry::new(&[(XOR_CIPHER_OID, &XorCipher)]);
+    let pk = enc_pk.decrypt_with_registry(PASSWORD, &registry).unwrap();
+    assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
+
+    // Built-in ciphers remain available alongside registered ones
+    let enc_pk =
+        EncryptedPrivateKeyInfo::try_from(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let pk = enc_pk.decrypt_with_registry(PASSWORD, &registry).unwrap();
+    assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn round_trip_ed25519_der_encpriv_unknown_cipher() {
+    use pkcs8::{
+        der::{Encodable, ErrorKind},
+        Error, ObjectIdentifier,
+    };
+
+    // Replace the AES-256-CBC OID with the fabricated OID 1.3.6.1.4.1.99999.1
+    let aes256_cbc_oid = hex!("060960864801650304012a");
+    let mut der = ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE.to_vec();
+    let pos = der
+        .windows(aes256_cbc_oid.len())
+        .position(|window| window == aes256_cbc_oid)
+        .unwrap();
+    der[pos..][..aes256_cbc_oid.len()].copy_from_slice(&hex!("06092b06010401868d1f01"));
+
+    let enc_pk = EncryptedPrivateKeyInfo::try_from(der.as_slice()).unwrap();
+    let oid = "1.3.6.1.4.1.99999.1".parse::<ObjectIdentifier>().unwrap();
+    assert_eq!(
+        enc_pk
+            .encryption_algorithm
+            .pbes2()
+            .unwrap()
+            .encryption
+            .oid(),
+        oid
+    );
+    assert_eq!(enc_pk.to_vec().unwrap(), der);
+
+    assert_eq!(
+        enc_pk.decrypt(PASSWORD).unwrap_err(),
+        Error::Asn1(ErrorKind::UnknownOid { oid }.into())
+    );
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn encrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
@@ -195,6 +442,93 @@ fn encrypt_ed25519_der_encpriv_aes256_pbkdf2_sha256() {
     );
 }
 
+/// Deterministic (and insecure) RNG for tests, which returns a counter.
+#[cfg(feature = "encryption")]
+struct CounterRng(u8);
+
+#[cfg(feature = "encryption")]
+impl rand_core::RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            self.0 = self.0.wrapping_add(1);
+            *byte = self.0;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl rand_core::CryptoRng for CounterRng {}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn private_key_info_to_encrypted_round_trip() {
+    let pbes2_params = pbes2::Parameters::pbkdf2_sha256_aes256cbc(
+        2048,
+        &hex!("79d982e70df91a88"),
+        &hex!("b2d02d78b2efd9dff694cf8e0af40925"),
+    )
+    .unwrap();
+
+    let pk_info = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_info
+        .to_encrypted(CounterRng(0), PASSWORD, pbes2_params)
+        .unwrap();
+
+    // The KDF and cipher are kept, but with a fresh salt and IV
+    let enc_pk_info = pk_encrypted.encrypted_private_key_info();
+    let params = enc_pk_info.encryption_algorithm.pbes2().unwrap();
+    match &params.kdf {
+        pbes2::Kdf::Pbkdf2(kdf_params) => {
+            assert_eq!(kdf_params.iteration_count, 2048);
+            assert_eq!(kdf_params.salt, &hex!("0102030405060708"));
+        }
+        other => panic!("unexpected KDF: {:?}", other),
+    }
+    assert_eq!(
+        params.encryption,
+        pbes2::EncryptionScheme::Aes256Cbc {
+            iv: &hex!("090a0b0c0d0e0f101112131415161718")
+        }
+    );
+
+    let pk_decrypted = pk_encrypted.decrypt(PASSWORD).unwrap();
+    assert_eq!(pk_decrypted.private_key_info(), pk_info);
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn private_key_info_to_encrypted_unsupported_cipher() {
+    let mut pbes2_params = pbes2::Parameters::pbkdf2_sha256_aes256cbc(
+        2048,
+        &hex!("79d982e70df91a88"),
+        &hex!("b2d02d78b2efd9dff694cf8e0af40925"),
+    )
+    .unwrap();
+    pbes2_params.encryption = pbes2::EncryptionScheme::Other {
+        oid: "1.3.6.1.4.1.99999.1".parse().unwrap(),
+        parameters: None,
+    };
+
+    let pk_info = PrivateKeyInfo::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let err = pk_info
+        .to_encrypted(CounterRng(0), PASSWORD, pbes2_params)
+        .unwrap_err();
+    assert_eq!(err, pkcs8::Error::Crypto);
+}
+
 #[cfg(feature = "encryption")]
 #[test]
 fn encrypt_ed25519_der_encpriv_aes256_scrypt() {
@@ -213,6 +547,46 @@ fn encrypt_ed25519_der_encpriv_aes256_scrypt() {
     assert_eq!(pk_encrypted.as_ref(), ED25519_DER_AES256_SCRYPT_EXAMPLE);
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_verify_roundtrip_aes256_scrypt() {
+    let scrypt_params = pbes2::Parameters::scrypt_aes256cbc(
+        Default::default(),
+        &hex!("E6211E2348AD69E0"),
+        &hex!("9BD0A6251F2254F9FD5963887C27CF01"),
+    )
+    .unwrap();
+
+    let pk_plaintext = PrivateKeyDocument::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_plaintext
+        .encrypt_with_params(scrypt_params, PASSWORD)
+        .unwrap();
+
+    pk_encrypted
+        .verify_roundtrip(PASSWORD, &pk_plaintext)
+        .unwrap();
+
+    let pk_info = pk_plaintext.private_key_info();
+    let other_plaintext = PrivateKeyInfo::new(pk_info.algorithm, &[0u8; 34]).to_der();
+    assert_eq!(
+        pk_encrypted
+            .verify_roundtrip(PASSWORD, &other_plaintext)
+            .unwrap_err(),
+        pkcs8::Error::Crypto
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn document_clone_eq() {
+    let doc =
+        EncryptedPrivateKeyDocument::from_der(ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE).unwrap();
+    let other =
+        EncryptedPrivateKeyDocument::from_der(ED25519_DER_AES128_PBKDF2_SHA1_EXAMPLE).unwrap();
+    assert_eq!(doc.clone(), doc);
+    assert_ne!(doc, other);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_ed25519_encpriv_aes256_pbkdf2_sha256_der() {
@@ -265,3 +639,20 @@ fn decrypt_ed25519_der_encpriv_des_pbkdf2_sha256() {
     let pk = enc_pk.decrypt(PASSWORD).unwrap();
     assert_eq!(pk.as_ref(), ED25519_DER_PLAINTEXT_EXAMPLE);
 }
+
+#[test]
+fn round_trip_encrypted_private_key_info() {
+    use pkcs8::der::Encodable;
+
+    for &example in &[
+        ED25519_DER_AES128_PBKDF2_SHA1_EXAMPLE,
+        ED25519_DER_AES256_PBKDF2_SHA256_EXAMPLE,
+        include_bytes!("examples/ed25519-encpriv-aes256-scrypt.der"),
+        include_bytes!("examples/ed25519-encpriv-des3-pbkdf2-sha256.der"),
+        include_bytes!("examples/ed25519-encpriv-des-pbkdf2-sha256.der"),
+    ] {
+        let pk = EncryptedPrivateKeyInfo::try_from(example).unwrap();
+        let mut buf = [0u8; 256];
+        assert_eq!(pk.encode_to_slice(&mut buf).unwrap(), example);
+    }
+}
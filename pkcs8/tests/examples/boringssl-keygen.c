@@ -0,0 +1,83 @@
+/*
+ * Generate PKCS#8 private keys and `SubjectPublicKeyInfo` public keys with
+ * BoringSSL, writing them as `<alg>-{priv,pub}-boringssl.der` into the
+ * directory given as the only argument.
+ *
+ * See `gen-interop.sh` for how to build and run this.
+ */
+
+#include <openssl/bytestring.h>
+#include <openssl/evp.h>
+#include <openssl/mem.h>
+#include <openssl/nid.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+static void write_der(const char *dir, const char *alg, const char *kind,
+                      int (*marshal)(CBB *, const EVP_PKEY *),
+                      const EVP_PKEY *pkey) {
+  char path[256];
+  CBB cbb;
+  uint8_t *der;
+  size_t len;
+
+  snprintf(path, sizeof(path), "%s/%s-%s-boringssl.der", dir, alg, kind);
+
+  if (!CBB_init(&cbb, 0) || !marshal(&cbb, pkey) ||
+      !CBB_finish(&cbb, &der, &len)) {
+    fprintf(stderr, "failed to encode %s\n", path);
+    exit(1);
+  }
+
+  FILE *file = fopen(path, "wb");
+  if (file == NULL || fwrite(der, 1, len, file) != len || fclose(file) != 0) {
+    fprintf(stderr, "failed to write %s\n", path);
+    exit(1);
+  }
+
+  OPENSSL_free(der);
+}
+
+static EVP_PKEY *keygen(int type, int param) {
+  EVP_PKEY_CTX *ctx = EVP_PKEY_CTX_new_id(type, NULL);
+  EVP_PKEY *pkey = NULL;
+
+  if (ctx == NULL || !EVP_PKEY_keygen_init(ctx) ||
+      (type == EVP_PKEY_EC &&
+       !EVP_PKEY_CTX_set_ec_paramgen_curve_nid(ctx, param)) ||
+      (type == EVP_PKEY_RSA && !EVP_PKEY_CTX_set_rsa_keygen_bits(ctx, param)) ||
+      !EVP_PKEY_keygen(ctx, &pkey)) {
+    fprintf(stderr, "failed to generate key of type %d\n", type);
+    exit(1);
+  }
+
+  EVP_PKEY_CTX_free(ctx);
+  return pkey;
+}
+
+int main(int argc, char **argv) {
+  static const struct {
+    const char *alg;
+    int type;
+    int param;
+  } keys[] = {
+      {"ed25519", EVP_PKEY_ED25519, 0},
+      {"p256", EVP_PKEY_EC, NID_X9_62_prime256v1},
+      {"rsa2048", EVP_PKEY_RSA, 2048},
+      {"x25519", EVP_PKEY_X25519, 0},
+  };
+
+  if (argc != 2) {
+    fprintf(stderr, "usage: %s <output directory>\n", argv[0]);
+    return 1;
+  }
+
+  for (size_t i = 0; i < sizeof(keys) / sizeof(keys[0]); i++) {
+    EVP_PKEY *pkey = keygen(keys[i].type, keys[i].param);
+    write_der(argv[1], keys[i].alg, "priv", EVP_marshal_private_key, pkey);
+    write_der(argv[1], keys[i].alg, "pub", EVP_marshal_public_key, pkey);
+    EVP_PKEY_free(pkey);
+  }
+
+  return 0;
+}
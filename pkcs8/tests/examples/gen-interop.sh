@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+#
+# Regenerate the BoringSSL and mbedTLS interop vectors in this directory.
+#
+# Expects the following environment variables:
+#
+# - BORINGSSL_INCLUDE: BoringSSL's `include` directory
+# - BORINGSSL_LIBCRYPTO: BoringSSL's built `libcrypto.a`
+# - MBEDTLS_DIR: mbedTLS 2.x source tree, after running `make`
+#
+# mbedTLS can't write PKCS#8 private keys (only SEC1 and PKCS#1 ones), so
+# only its `SubjectPublicKeyInfo` output is kept.
+
+# http://redsymbol.net/articles/unofficial-bash-strict-mode/
+set -euo pipefail
+IFS=$'\n\t'
+
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+# BoringSSL: `EVP_marshal_private_key` and `EVP_marshal_public_key`
+cc -I"${BORINGSSL_INCLUDE:?}" boringssl-keygen.c "${BORINGSSL_LIBCRYPTO:?}" -lpthread -o "$tmp/boringssl-keygen"
+"$tmp/boringssl-keygen" .
+
+# mbedTLS: `gen_key` followed by `key_app_writer output_mode=public`
+pkey="${MBEDTLS_DIR:?}/programs/pkey"
+
+"$pkey/gen_key" type=ec ec_curve=secp256r1 format=der filename="$tmp/p256.der"
+"$pkey/key_app_writer" mode=private filename="$tmp/p256.der" \
+    output_mode=public output_format=der output_file=p256-pub-mbedtls.der
+
+"$pkey/gen_key" type=rsa rsa_keysize=2048 format=der filename="$tmp/rsa2048.der"
+"$pkey/key_app_writer" mode=private filename="$tmp/rsa2048.der" \
+    output_mode=public output_format=der output_file=rsa2048-pub-mbedtls.der
@@ -0,0 +1,129 @@
+//! Interoperability tests with keys generated by other implementations.
+//!
+//! See `examples/gen-interop.sh` for how the keys were generated. Both
+//! BoringSSL and mbedTLS only ever emit PKCS#8 v1 documents.
+
+use core::convert::TryFrom;
+use pkcs8::{der::Encodable, ObjectIdentifier, PrivateKeyInfo, SubjectPublicKeyInfo, Version};
+
+/// Ed25519 (RFC 8410) algorithm OID
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.101.112");
+
+/// Elliptic curve public key (RFC 5480) algorithm OID
+const EC_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// NIST P-256 named curve OID
+const P256_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+
+/// RSA encryption (PKCS#1) algorithm OID
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// X25519 (RFC 8410) algorithm OID
+const X25519_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.101.110");
+
+/// PKCS#8 private keys written by BoringSSL's `EVP_marshal_private_key`,
+/// along with their algorithm OIDs.
+const PRIVATE_KEYS: &[(&str, &[u8], ObjectIdentifier)] = &[
+    (
+        "ed25519-priv-boringssl",
+        include_bytes!("examples/ed25519-priv-boringssl.der"),
+        ED25519_OID,
+    ),
+    (
+        "p256-priv-boringssl",
+        include_bytes!("examples/p256-priv-boringssl.der"),
+        EC_OID,
+    ),
+    (
+        "rsa2048-priv-boringssl",
+        include_bytes!("examples/rsa2048-priv-boringssl.der"),
+        RSA_OID,
+    ),
+    (
+        "x25519-priv-boringssl",
+        include_bytes!("examples/x25519-priv-boringssl.der"),
+        X25519_OID,
+    ),
+];
+
+/// `SubjectPublicKeyInfo` public keys written by BoringSSL's
+/// `EVP_marshal_public_key` and mbedTLS's `mbedtls_pk_write_pubkey_der`,
+/// along with their algorithm OIDs.
+const PUBLIC_KEYS: &[(&str, &[u8], ObjectIdentifier)] = &[
+    (
+        "ed25519-pub-boringssl",
+        include_bytes!("examples/ed25519-pub-boringssl.der"),
+        ED25519_OID,
+    ),
+    (
+        "p256-pub-boringssl",
+        include_bytes!("examples/p256-pub-boringssl.der"),
+        EC_OID,
+    ),
+    (
+        "p256-pub-mbedtls",
+        include_bytes!("examples/p256-pub-mbedtls.der"),
+        EC_OID,
+    ),
+    (
+        "rsa2048-pub-boringssl",
+        include_bytes!("examples/rsa2048-pub-boringssl.der"),
+        RSA_OID,
+    ),
+    (
+        "rsa2048-pub-mbedtls",
+        include_bytes!("examples/rsa2048-pub-mbedtls.der"),
+        RSA_OID,
+    ),
+    (
+        "x25519-pub-boringssl",
+        include_bytes!("examples/x25519-pub-boringssl.der"),
+        X25519_OID,
+    ),
+];
+
+#[test]
+fn round_trip_private_keys() {
+    for &(name, der, oid) in PRIVATE_KEYS {
+        let pk = PrivateKeyInfo::try_from(der).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(pk.version(), Version::V1, "{}", name);
+        assert_eq!(pk.algorithm.oid, oid, "{}", name);
+        assert_eq!(pk.public_key, None, "{}", name);
+
+        if oid == EC_OID {
+            pk.algorithm.assert_oids(EC_OID, P256_OID).unwrap();
+        }
+
+        let mut buf = [0u8; 2048];
+        assert_eq!(pk.encode_to_slice(&mut buf).unwrap(), der, "{}", name);
+    }
+}
+
+#[test]
+fn round_trip_public_keys() {
+    for &(name, der, oid) in PUBLIC_KEYS {
+        let spki =
+            SubjectPublicKeyInfo::try_from(der).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(spki.algorithm.oid, oid, "{}", name);
+
+        if oid == EC_OID {
+            spki.algorithm.assert_oids(EC_OID, P256_OID).unwrap();
+        }
+
+        let mut buf = [0u8; 512];
+        assert_eq!(spki.encode_to_slice(&mut buf).unwrap(), der, "{}", name);
+    }
+}
+
+#[test]
+#[cfg(feature = "sec1")]
+fn ec_p256_public_key_matches_boringssl() {
+    let pk =
+        PrivateKeyInfo::try_from(&include_bytes!("examples/p256-priv-boringssl.der")[..]).unwrap();
+    let spki =
+        SubjectPublicKeyInfo::try_from(&include_bytes!("examples/p256-pub-boringssl.der")[..])
+            .unwrap();
+
+    let ec_key = pk.ec_private_key().unwrap();
+    assert_eq!(ec_key.public_key, Some(spki.subject_public_key));
+}
@@ -7,18 +7,45 @@ use pkcs8::SubjectPublicKeyInfo;
 #[cfg(feature = "alloc")]
 use der::Encodable;
 
-#[cfg(any(feature = "pem", feature = "std"))]
+#[cfg(any(feature = "alloc", feature = "pem", feature = "std"))]
 use pkcs8::PublicKeyDocument;
 
 /// Elliptic Curve (P-256) `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub.der");
 
+/// Elliptic Curve (P-256) `SubjectPublicKeyInfo` with a compressed point.
+///
+/// Generated with:
+///
+/// ```text
+/// $ openssl ec -pubin -inform der -in tests/examples/p256-pub.der -conv_form compressed -outform der
+/// ```
+const EC_P256_COMPRESSED_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-pub-compressed.der");
+
 /// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
 
+/// Ed448 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated with:
+///
+/// ```text
+/// $ openssl pkey -inform der -in tests/examples/ed448-priv.der -pubout -outform der
+/// ```
+const ED448_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed448-pub.der");
+
 /// RSA-2048 `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pub.der");
 
+/// X448 `SubjectPublicKeyInfo` encoded as ASN.1 DER
+///
+/// Generated with:
+///
+/// ```text
+/// $ openssl pkey -inform der -in tests/examples/x448-priv.der -pubout -outform der
+/// ```
+const X448_DER_EXAMPLE: &[u8] = include_bytes!("examples/x448-pub.der");
+
 /// Elliptic Curve (P-256) public key encoded as PEM
 #[cfg(feature = "pem")]
 const EC_P256_PEM_EXAMPLE: &str = include_str!("examples/p256-pub.pem");
@@ -45,6 +72,23 @@ fn decode_ec_p256_der() {
     assert_eq!(spki.subject_public_key, &hex!("041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F")[..]);
 }
 
+#[test]
+fn decode_ec_p256_compressed_der() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_COMPRESSED_DER_EXAMPLE).unwrap();
+
+    assert_eq!(spki.algorithm.oid, "1.2.840.10045.2.1".parse().unwrap());
+
+    assert_eq!(
+        spki.algorithm.parameters.unwrap().oid().unwrap(),
+        "1.2.840.10045.3.1.7".parse().unwrap()
+    );
+
+    assert_eq!(
+        spki.subject_public_key,
+        &hex!("031CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7F")[..]
+    );
+}
+
 #[test]
 fn decode_ed25519_der() {
     let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
@@ -57,6 +101,26 @@ fn decode_ed25519_der() {
     );
 }
 
+#[test]
+fn decode_ed448_der() {
+    let spki = SubjectPublicKeyInfo::try_from(ED448_DER_EXAMPLE).unwrap();
+
+    assert_eq!(spki.algorithm.oid, "1.3.101.113".parse().unwrap());
+    assert_eq!(spki.algorithm.parameters, None);
+    assert_eq!(spki.raw_public_key().unwrap(), &ED448_DER_EXAMPLE[12..]);
+    assert_eq!(spki.raw_public_key().unwrap().len(), 57);
+}
+
+#[test]
+fn decode_x448_der() {
+    let spki = SubjectPublicKeyInfo::try_from(X448_DER_EXAMPLE).unwrap();
+
+    assert_eq!(spki.algorithm.oid, "1.3.101.111".parse().unwrap());
+    assert_eq!(spki.algorithm.parameters, None);
+    assert_eq!(spki.raw_public_key().unwrap(), &X448_DER_EXAMPLE[12..]);
+    assert_eq!(spki.raw_public_key().unwrap().len(), 56);
+}
+
 #[test]
 fn decode_rsa_2048_der() {
     let spki = SubjectPublicKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -88,6 +152,17 @@ fn decode_ed25519_pem() {
     assert_eq!(doc.spki(), spki);
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_pem_lenient() {
+    let mut pem = Vec::from(&b"\xEF\xBB\xBFjunk \xFF\n"[..]);
+    pem.extend_from_slice(ED25519_PEM_EXAMPLE.as_bytes());
+
+    let doc = PublicKeyDocument::from_pem_lenient(&pem).unwrap();
+    assert_eq!(doc.as_ref(), ED25519_DER_EXAMPLE);
+    assert!(PublicKeyDocument::from_bytes(&pem).is_err());
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn decode_rsa_2048_pem() {
@@ -99,6 +174,32 @@ fn decode_rsa_2048_pem() {
     assert_eq!(doc.spki(), spki);
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn display_ed25519_pem() {
+    let doc: PublicKeyDocument = ED25519_PEM_EXAMPLE.parse().unwrap();
+    assert_eq!(doc.to_string(), doc.to_pem_with_le(pkcs8::LineEnding::LF));
+    assert_eq!(doc.to_string(), ED25519_PEM_EXAMPLE);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_ed25519_from_bytes() {
+    let from_der = PublicKeyDocument::from_bytes(ED25519_DER_EXAMPLE).unwrap();
+    let from_pem = PublicKeyDocument::from_bytes(ED25519_PEM_EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(from_der.as_ref(), ED25519_DER_EXAMPLE);
+    assert_eq!(from_pem.as_ref(), ED25519_DER_EXAMPLE);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn document_clone_eq() {
+    let doc = PublicKeyDocument::from_der(ED25519_DER_EXAMPLE).unwrap();
+    let other = PublicKeyDocument::from_der(EC_P256_DER_EXAMPLE).unwrap();
+    assert_eq!(doc.clone(), doc);
+    assert_ne!(doc, other);
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_ec_p256_der() {
@@ -115,6 +216,32 @@ fn encode_ed25519_der() {
     assert_eq!(ED25519_DER_EXAMPLE, pk_encoded.as_slice());
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_from_algorithm_and_key() {
+    let algorithm = pkcs8::AlgorithmIdentifier {
+        oid: "1.3.101.112".parse().unwrap(),
+        parameters: None,
+    };
+
+    let doc = PublicKeyDocument::from_algorithm_and_key(
+        algorithm,
+        &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D"),
+    )
+    .unwrap();
+
+    assert_eq!(ED25519_DER_EXAMPLE, doc.as_ref());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed448_and_x448_der() {
+    for der in &[ED448_DER_EXAMPLE, X448_DER_EXAMPLE] {
+        let pk = SubjectPublicKeyInfo::try_from(*der).unwrap();
+        assert_eq!(*der, pk.to_vec().unwrap().as_slice());
+    }
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_rsa_2048_der() {
@@ -160,3 +287,14 @@ fn read_pem_file() {
     let pkcs8_doc = PublicKeyDocument::read_pem_file("tests/examples/p256-pub.pem").unwrap();
     assert_eq!(pkcs8_doc.as_ref(), EC_P256_DER_EXAMPLE);
 }
+
+#[test]
+fn algorithm_oid() {
+    let ed25519 = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(ed25519.algorithm_oid(), "1.3.101.112".parse().unwrap());
+    assert_eq!(ed25519.algorithm(), &ed25519.algorithm);
+
+    let rsa = SubjectPublicKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
+    assert_eq!(rsa.algorithm_oid(), "1.2.840.113549.1.1.1".parse().unwrap());
+    assert!(rsa.algorithm().parameters.unwrap().is_null());
+}
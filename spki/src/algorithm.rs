@@ -1,9 +1,10 @@
 //! X.509 `AlgorithmIdentifier`
 
+use crate::CurveAlgorithm;
 use core::convert::{TryFrom, TryInto};
 use der::{
     asn1::{Any, ObjectIdentifier},
-    Decodable, Decoder, Encodable, Error, ErrorKind, Message, Result,
+    Decodable, Decoder, Encodable, Error, ErrorKind, Length, Message, Result, Tag,
 };
 
 /// X.509 `AlgorithmIdentifier` as defined in [RFC 5280 Section 4.1.1.2].
@@ -73,15 +74,59 @@ impl<'a> AlgorithmIdentifier<'a> {
     pub fn parameters_oid(&self) -> Result<ObjectIdentifier> {
         self.parameters_any().and_then(TryInto::try_into)
     }
+
+    /// Iterate depth-first over this [`AlgorithmIdentifier`] followed by
+    /// those nested within its `parameters`, e.g. the key derivation
+    /// function, its pseudo-random function and the cipher of a PBES2
+    /// encryption scheme.
+    ///
+    /// Only the `parameters` of algorithms known to carry nested
+    /// `AlgorithmIdentifier`s are searched: PBES2, PBMAC1, PBKDF2,
+    /// RSASSA-PSS, RSAES-OAEP and MGF1. If these are malformed, or nested
+    /// more than 8 levels deep, an error is yielded and iteration ends.
+    pub fn chain(&self) -> AlgorithmIdentifierIter<'a> {
+        AlgorithmIdentifierIter {
+            next: Some(*self),
+            stack: [&[]; MAX_NESTING_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Decode the fields of an `AlgorithmIdentifier` from the body of its
+    /// `SEQUENCE`.
+    fn decode_fields(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let oid = decoder.decode()?;
+        let mut parameters: Option<Any<'a>> = decoder.decode()?;
+
+        // Some tools emit a `NULL` for algorithms which require absent
+        // parameters. Accept (and drop) it unless decoding strictly.
+        if CurveAlgorithm::try_from(oid).is_ok()
+            && parameters.map(|params| params.tag()) == Some(Tag::Null)
+        {
+            if decoder.is_strict() {
+                return Err(decoder.error(ErrorKind::UnexpectedTag {
+                    expected: None,
+                    actual: Tag::Null,
+                }));
+            }
+
+            parameters = None;
+        }
+
+        Ok(Self { oid, parameters })
+    }
 }
 
+/// The [RFC 8410] algorithms (X25519, X448, Ed25519, Ed448) require absent
+/// `parameters`, but some buggy encoders emit a `NULL` instead. Non-strict
+/// decoders accept it and normalize it to absent parameters, so it is
+/// omitted when re-encoding, whereas decoders created with
+/// [`Decoder::new_strict`] reject it.
+///
+/// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
 impl<'a> Decodable<'a> for AlgorithmIdentifier<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
-        decoder.sequence(|decoder| {
-            let oid = decoder.decode()?;
-            let parameters = decoder.decode()?;
-            Ok(Self { oid, parameters })
-        })
+        decoder.sequence(Self::decode_fields)
     }
 }
 
@@ -101,3 +146,173 @@ impl<'a> TryFrom<&'a [u8]> for AlgorithmIdentifier<'a> {
         Self::from_der(bytes)
     }
 }
+
+/// Maximum number of nested algorithms whose `parameters`
+/// [`AlgorithmIdentifierIter`] searches at once.
+const MAX_NESTING_DEPTH: usize = 8;
+
+/// Algorithms whose `parameters` carry nested `AlgorithmIdentifier`s, and
+/// where to find them.
+const NESTED_ALGORITHMS: [(ObjectIdentifier, Nesting); 6] = [
+    // RSAES-OAEP (RFC 8017)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.1.7"),
+        Nesting::Fields,
+    ),
+    // MGF1 (RFC 8017)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.1.8"),
+        Nesting::Parameters,
+    ),
+    // RSASSA-PSS (RFC 8017)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.1.10"),
+        Nesting::Fields,
+    ),
+    // PBKDF2 (RFC 8018)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.5.12"),
+        Nesting::Fields,
+    ),
+    // PBES2 (RFC 8018)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.5.13"),
+        Nesting::Fields,
+    ),
+    // PBMAC1 (RFC 8018)
+    (
+        ObjectIdentifier::new("1.2.840.113549.1.5.14"),
+        Nesting::Fields,
+    ),
+];
+
+/// Location of the `AlgorithmIdentifier`s nested within `parameters`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Nesting {
+    /// The `parameters` are themselves an `AlgorithmIdentifier`.
+    Parameters,
+
+    /// The `parameters` are a `SEQUENCE`, some of whose fields (possibly
+    /// `EXPLICIT`ly tagged) are `AlgorithmIdentifier`s.
+    Fields,
+}
+
+/// Depth-first iterator over an [`AlgorithmIdentifier`] and those nested
+/// within its `parameters`.
+///
+/// Returned by [`AlgorithmIdentifier::chain`].
+#[derive(Clone, Debug)]
+pub struct AlgorithmIdentifierIter<'a> {
+    /// Algorithm to yield next, before searching the stack.
+    next: Option<AlgorithmIdentifier<'a>>,
+
+    /// Remaining fields of the `parameters` being searched for nested
+    /// algorithms, innermost last.
+    stack: [&'a [u8]; MAX_NESTING_DEPTH],
+
+    /// Number of entries of `stack` in use.
+    depth: usize,
+}
+
+impl<'a> AlgorithmIdentifierIter<'a> {
+    /// Queue the algorithms nested within the `parameters` of the given
+    /// algorithm, if it is known to have any.
+    fn descend(&mut self, alg: &AlgorithmIdentifier<'a>) -> Result<()> {
+        let nesting = NESTED_ALGORITHMS
+            .iter()
+            .find(|(oid, _)| *oid == alg.oid)
+            .map(|(_, nesting)| *nesting);
+
+        let (nesting, parameters) = match (nesting, alg.parameters) {
+            (Some(nesting), Some(parameters)) => (nesting, parameters),
+            _ => return Ok(()),
+        };
+
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(ErrorKind::Overlength.into());
+        }
+
+        // A nested algorithm in place of `parameters` is yielded next, and
+        // still counts towards the nesting depth while its own `parameters`
+        // are searched
+        let fields = match nesting {
+            Nesting::Parameters => {
+                self.next = Some(parameters.sequence(AlgorithmIdentifier::decode_fields)?);
+                &[]
+            }
+            Nesting::Fields => {
+                parameters.tag().assert_eq(Tag::Sequence)?;
+                parameters.value()
+            }
+        };
+
+        self.stack[self.depth] = fields;
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Find the next algorithm in the fields on the stack.
+    fn search(&mut self) -> Result<Option<AlgorithmIdentifier<'a>>> {
+        while self.depth > 0 {
+            let remaining = self.stack[self.depth - 1];
+
+            if remaining.is_empty() {
+                self.depth -= 1;
+                continue;
+            }
+
+            let mut decoder = Decoder::new(remaining);
+            let mut field = decoder.decode::<Any<'a>>()?;
+            self.advance(decoder.position());
+
+            // Fields of RSASSA-PSS and RSAES-OAEP parameters are `EXPLICIT`
+            if field.tag().is_context_specific() && field.tag().is_constructed() {
+                field = Any::from_der(field.value())?;
+            }
+
+            if field.tag() == Tag::Sequence {
+                return field.sequence(AlgorithmIdentifier::decode_fields).map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Skip past the first `len` bytes of the innermost fields being searched.
+    fn advance(&mut self, len: Length) {
+        let remaining = &mut self.stack[self.depth - 1];
+        *remaining = usize::try_from(len)
+            .ok()
+            .and_then(|len| remaining.get(len..))
+            .unwrap_or_default();
+    }
+}
+
+impl<'a> Iterator for AlgorithmIdentifierIter<'a> {
+    type Item = Result<AlgorithmIdentifier<'a>>;
+
+    fn next(&mut self) -> Option<Result<AlgorithmIdentifier<'a>>> {
+        let result = match self.next.take() {
+            Some(alg) => Ok(Some(alg)),
+            None => self.search(),
+        };
+
+        let result = result.and_then(|alg| {
+            if let Some(alg) = &alg {
+                self.descend(alg)?;
+            }
+
+            Ok(alg)
+        });
+
+        match result {
+            Ok(alg) => alg.map(Ok),
+            Err(err) => {
+                // Stop iterating after an error
+                self.next = None;
+                self.depth = 0;
+                Some(Err(err))
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Algorithm identifiers for the curves from RFC 8410.
+
+use crate::AlgorithmIdentifier;
+use core::convert::TryFrom;
+use der::{asn1::ObjectIdentifier, Error, ErrorKind, Result};
+
+/// Algorithms from [RFC 8410] for X25519, X448, Ed25519 and Ed448 keys.
+///
+/// The `parameters` of their [`AlgorithmIdentifier`]s MUST be absent, and
+/// both their public and private keys are raw octet strings of a fixed
+/// length, given by [`CurveAlgorithm::key_len`].
+///
+/// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum CurveAlgorithm {
+    /// X25519 (`id-X25519`).
+    X25519,
+
+    /// X448 (`id-X448`).
+    X448,
+
+    /// Ed25519 (`id-Ed25519`).
+    Ed25519,
+
+    /// Ed448 (`id-Ed448`).
+    Ed448,
+}
+
+/// [RFC 8410] algorithms along with their OID, name and raw key length.
+///
+/// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+const CURVE_ALGORITHMS: [(CurveAlgorithm, ObjectIdentifier, &str, usize); 4] = [
+    (
+        CurveAlgorithm::X25519,
+        ObjectIdentifier::new("1.3.101.110"),
+        "X25519",
+        32,
+    ),
+    (
+        CurveAlgorithm::X448,
+        ObjectIdentifier::new("1.3.101.111"),
+        "X448",
+        56,
+    ),
+    (
+        CurveAlgorithm::Ed25519,
+        ObjectIdentifier::new("1.3.101.112"),
+        "Ed25519",
+        32,
+    ),
+    (
+        CurveAlgorithm::Ed448,
+        ObjectIdentifier::new("1.3.101.113"),
+        "Ed448",
+        57,
+    ),
+];
+
+impl CurveAlgorithm {
+    /// Get the [`ObjectIdentifier`] for this algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        self.entry().1
+    }
+
+    /// Get the name of this algorithm, e.g. `Ed25519`, which is also its
+    /// `crv` in JSON Web Keys as described in [RFC 8037].
+    ///
+    /// [RFC 8037]: https://datatracker.ietf.org/doc/html/rfc8037#section-2
+    pub fn name(self) -> &'static str {
+        self.entry().2
+    }
+
+    /// Get the length of the raw public and private keys of this algorithm
+    /// in bytes: 32 for X25519 and Ed25519, 56 for X448 and 57 for Ed448.
+    pub fn key_len(self) -> usize {
+        self.entry().3
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this algorithm, with absent
+    /// `parameters`.
+    pub fn algorithm_identifier(self) -> AlgorithmIdentifier<'static> {
+        AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters: None,
+        }
+    }
+
+    /// Get the entry of [`CURVE_ALGORITHMS`] for this algorithm, which is
+    /// listed in the order of the variants.
+    fn entry(self) -> &'static (CurveAlgorithm, ObjectIdentifier, &'static str, usize) {
+        &CURVE_ALGORITHMS[self as usize]
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for CurveAlgorithm {
+    type Error = Error;
+
+    fn try_from(oid: ObjectIdentifier) -> Result<Self> {
+        CURVE_ALGORITHMS
+            .iter()
+            .find(|(_, curve_oid, _, _)| *curve_oid == oid)
+            .map(|(curve, _, _, _)| *curve)
+            .ok_or_else(|| ErrorKind::UnknownOid { oid }.into())
+    }
+}
+
+impl TryFrom<&AlgorithmIdentifier<'_>> for CurveAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: &AlgorithmIdentifier<'_>) -> Result<Self> {
+        let curve = Self::try_from(alg.oid)?;
+
+        match alg.parameters {
+            Some(parameters) => Err(parameters.tag().unexpected_error(None)),
+            None => Ok(curve),
+        }
+    }
+}
+
+impl TryFrom<AlgorithmIdentifier<'_>> for CurveAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'_>) -> Result<Self> {
+        Self::try_from(&alg)
+    }
+}
+
+impl From<CurveAlgorithm> for AlgorithmIdentifier<'static> {
+    fn from(curve: CurveAlgorithm) -> AlgorithmIdentifier<'static> {
+        curve.algorithm_identifier()
+    }
+}
+
+impl From<CurveAlgorithm> for ObjectIdentifier {
+    fn from(curve: CurveAlgorithm) -> ObjectIdentifier {
+        curve.oid()
+    }
+}
@@ -0,0 +1,179 @@
+//! Digest algorithm identifiers.
+
+use crate::AlgorithmIdentifier;
+use core::convert::TryFrom;
+use der::{
+    asn1::{Null, ObjectIdentifier},
+    Error, ErrorKind, Result,
+};
+
+/// Digest algorithms commonly identified by an [`AlgorithmIdentifier`], e.g.
+/// in signature and key derivation function parameters.
+///
+/// Per [RFC 4055 Section 2.1], the parameters of the SHA-1 and SHA-2 digest
+/// algorithm identifiers may be either absent or `NULL`, and both are
+/// accepted when decoding. When encoding, they are `NULL` for SHA-1 and
+/// SHA-2, and absent for SHA-3 as specified in [RFC 8702 Section 2].
+///
+/// [RFC 4055 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-2.1
+/// [RFC 8702 Section 2]: https://datatracker.ietf.org/doc/html/rfc8702#section-2
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum DigestAlgorithm {
+    /// SHA-1 (`id-sha1`).
+    Sha1,
+
+    /// SHA-224 (`id-sha224`).
+    Sha224,
+
+    /// SHA-256 (`id-sha256`).
+    Sha256,
+
+    /// SHA-384 (`id-sha384`).
+    Sha384,
+
+    /// SHA-512 (`id-sha512`).
+    Sha512,
+
+    /// SHA-512/224 (`id-sha512-224`).
+    Sha512_224,
+
+    /// SHA-512/256 (`id-sha512-256`).
+    Sha512_256,
+
+    /// SHA3-224 (`id-sha3-224`).
+    Sha3_224,
+
+    /// SHA3-256 (`id-sha3-256`).
+    Sha3_256,
+
+    /// SHA3-384 (`id-sha3-384`).
+    Sha3_384,
+
+    /// SHA3-512 (`id-sha3-512`).
+    Sha3_512,
+}
+
+impl DigestAlgorithm {
+    /// Get the [`ObjectIdentifier`] for this digest algorithm.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            DigestAlgorithm::Sha1 => SHA_1_OID,
+            DigestAlgorithm::Sha224 => SHA_224_OID,
+            DigestAlgorithm::Sha256 => SHA_256_OID,
+            DigestAlgorithm::Sha384 => SHA_384_OID,
+            DigestAlgorithm::Sha512 => SHA_512_OID,
+            DigestAlgorithm::Sha512_224 => SHA_512_224_OID,
+            DigestAlgorithm::Sha512_256 => SHA_512_256_OID,
+            DigestAlgorithm::Sha3_224 => SHA3_224_OID,
+            DigestAlgorithm::Sha3_256 => SHA3_256_OID,
+            DigestAlgorithm::Sha3_384 => SHA3_384_OID,
+            DigestAlgorithm::Sha3_512 => SHA3_512_OID,
+        }
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this digest algorithm, with
+    /// `NULL` or absent parameters as described in the type-level docs.
+    pub fn algorithm_identifier(self) -> AlgorithmIdentifier<'static> {
+        let parameters = match self {
+            DigestAlgorithm::Sha3_224
+            | DigestAlgorithm::Sha3_256
+            | DigestAlgorithm::Sha3_384
+            | DigestAlgorithm::Sha3_512 => None,
+            _ => Some(Null::new().into()),
+        };
+
+        AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters,
+        }
+    }
+}
+
+impl TryFrom<ObjectIdentifier> for DigestAlgorithm {
+    type Error = Error;
+
+    fn try_from(oid: ObjectIdentifier) -> Result<Self> {
+        match oid {
+            SHA_1_OID => Ok(DigestAlgorithm::Sha1),
+            SHA_224_OID => Ok(DigestAlgorithm::Sha224),
+            SHA_256_OID => Ok(DigestAlgorithm::Sha256),
+            SHA_384_OID => Ok(DigestAlgorithm::Sha384),
+            SHA_512_OID => Ok(DigestAlgorithm::Sha512),
+            SHA_512_224_OID => Ok(DigestAlgorithm::Sha512_224),
+            SHA_512_256_OID => Ok(DigestAlgorithm::Sha512_256),
+            SHA3_224_OID => Ok(DigestAlgorithm::Sha3_224),
+            SHA3_256_OID => Ok(DigestAlgorithm::Sha3_256),
+            SHA3_384_OID => Ok(DigestAlgorithm::Sha3_384),
+            SHA3_512_OID => Ok(DigestAlgorithm::Sha3_512),
+            _ => Err(ErrorKind::UnknownOid { oid }.into()),
+        }
+    }
+}
+
+impl TryFrom<&AlgorithmIdentifier<'_>> for DigestAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: &AlgorithmIdentifier<'_>) -> Result<Self> {
+        let digest = Self::try_from(alg.oid)?;
+
+        if let Some(parameters) = alg.parameters {
+            Null::try_from(parameters)?;
+        }
+
+        Ok(digest)
+    }
+}
+
+impl TryFrom<AlgorithmIdentifier<'_>> for DigestAlgorithm {
+    type Error = Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'_>) -> Result<Self> {
+        Self::try_from(&alg)
+    }
+}
+
+impl From<DigestAlgorithm> for AlgorithmIdentifier<'static> {
+    fn from(digest: DigestAlgorithm) -> AlgorithmIdentifier<'static> {
+        digest.algorithm_identifier()
+    }
+}
+
+impl From<DigestAlgorithm> for ObjectIdentifier {
+    fn from(digest: DigestAlgorithm) -> ObjectIdentifier {
+        digest.oid()
+    }
+}
+
+/// OID for SHA-1 (`id-sha1`).
+const SHA_1_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.14.3.2.26");
+
+/// OID for SHA-224 (`id-sha224`).
+const SHA_224_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.4");
+
+/// OID for SHA-256 (`id-sha256`).
+const SHA_256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.1");
+
+/// OID for SHA-384 (`id-sha384`).
+const SHA_384_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.2");
+
+/// OID for SHA-512 (`id-sha512`).
+const SHA_512_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.3");
+
+/// OID for SHA-512/224 (`id-sha512-224`).
+const SHA_512_224_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.5");
+
+/// OID for SHA-512/256 (`id-sha512-256`).
+const SHA_512_256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.6");
+
+/// OID for SHA3-224 (`id-sha3-224`).
+const SHA3_224_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.7");
+
+/// OID for SHA3-256 (`id-sha3-256`).
+const SHA3_256_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.8");
+
+/// OID for SHA3-384 (`id-sha3-384`).
+const SHA3_384_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.9");
+
+/// OID for SHA3-512 (`id-sha3-512`).
+const SHA3_512_OID: ObjectIdentifier = ObjectIdentifier::new("2.16.840.1.101.3.4.2.10");
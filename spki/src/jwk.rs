@@ -0,0 +1,156 @@
+//! JSON Web Key (JWK) support.
+
+use crate::{CurveAlgorithm, ObjectIdentifier, SubjectPublicKeyInfo};
+use alloc::string::{String, ToString};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use core::convert::TryFrom;
+use der::{asn1::UIntBytes, Decoder, Error, ErrorKind, Result, Tag};
+use serde::{Deserialize, Serialize};
+
+/// Algorithm OID for RSA keys (`rsaEncryption`).
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// Algorithm OID for elliptic curve keys (`id-ecPublicKey`).
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.2.1");
+
+/// Named curve OID for NIST P-256.
+const SECP256R1_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.10045.3.1.7");
+
+/// Named curve OID for NIST P-384.
+const SECP384R1_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.132.0.34");
+
+/// Named curve OID for NIST P-521.
+const SECP521R1_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.132.0.35");
+
+/// Named curve OID for secp256k1.
+const SECP256K1_OID: ObjectIdentifier = ObjectIdentifier::new("1.3.132.0.10");
+
+/// Public key in the JSON Web Key (JWK) format described in [RFC 7517].
+///
+/// Supports the following key types:
+///
+/// - `RSA` ([RFC 7518 Section 6.3]): `n` and `e`
+/// - `EC` ([RFC 7518 Section 6.2]): `crv`, `x` and `y`
+/// - `OKP` ([RFC 8037]): `crv` and `x`
+///
+/// All binary fields are Base64url-encoded without padding.
+///
+/// [RFC 7517]: https://datatracker.ietf.org/doc/html/rfc7517
+/// [RFC 7518 Section 6.2]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.2
+/// [RFC 7518 Section 6.3]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3
+/// [RFC 8037]: https://datatracker.ietf.org/doc/html/rfc8037
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Jwk {
+    /// Key type (`RSA`, `EC` or `OKP`).
+    pub kty: String,
+
+    /// Curve name (`EC` and `OKP` keys).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+
+    /// RSA modulus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+
+    /// RSA public exponent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+
+    /// Public key (`OKP` keys) or x-coordinate of the public point (`EC` keys).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+
+    /// y-coordinate of the public point (`EC` keys).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+}
+
+impl Jwk {
+    /// Create an empty JWK with the given key type.
+    fn new(kty: &str) -> Self {
+        Self {
+            kty: kty.to_string(),
+            crv: None,
+            n: None,
+            e: None,
+            x: None,
+            y: None,
+        }
+    }
+
+    /// Convert an RSA `SubjectPublicKeyInfo`.
+    fn rsa(public_key: &[u8]) -> Result<Self> {
+        let mut decoder = Decoder::new(public_key);
+        let (n, e) = decoder.sequence(|decoder| {
+            let n = decoder.decode::<UIntBytes<'_>>()?;
+            let e = decoder.decode::<UIntBytes<'_>>()?;
+            Ok((n, e))
+        })?;
+        decoder.finish(())?;
+
+        let mut jwk = Self::new("RSA");
+        jwk.n = Some(Base64UrlUnpadded::encode_string(n.as_bytes()));
+        jwk.e = Some(Base64UrlUnpadded::encode_string(e.as_bytes()));
+        Ok(jwk)
+    }
+
+    /// Convert an elliptic curve `SubjectPublicKeyInfo`.
+    fn ec(curve: ObjectIdentifier, public_key: &[u8]) -> Result<Self> {
+        let (crv, field_size) = match curve {
+            SECP256R1_OID => ("P-256", 32),
+            SECP384R1_OID => ("P-384", 48),
+            SECP521R1_OID => ("P-521", 66),
+            SECP256K1_OID => ("secp256k1", 32),
+            _ => return Err(ErrorKind::UnknownOid { oid: curve }.into()),
+        };
+
+        // Only uncompressed points can be represented without curve arithmetic
+        let (x, y) = match public_key.split_first() {
+            Some((0x04, coordinates)) if coordinates.len() == field_size * 2 => {
+                coordinates.split_at(field_size)
+            }
+            _ => return Err(Tag::BitString.value_error()),
+        };
+
+        let mut jwk = Self::new("EC");
+        jwk.crv = Some(crv.to_string());
+        jwk.x = Some(Base64UrlUnpadded::encode_string(x));
+        jwk.y = Some(Base64UrlUnpadded::encode_string(y));
+        Ok(jwk)
+    }
+
+    /// Convert an octet key pair `SubjectPublicKeyInfo`.
+    fn okp(curve: CurveAlgorithm, public_key: &[u8]) -> Result<Self> {
+        if public_key.len() != curve.key_len() {
+            return Err(Tag::BitString.length_error());
+        }
+
+        let mut jwk = Self::new("OKP");
+        jwk.crv = Some(curve.name().to_string());
+        jwk.x = Some(Base64UrlUnpadded::encode_string(public_key));
+        Ok(jwk)
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfo<'_>> for Jwk {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfo<'_>) -> Result<Jwk> {
+        match spki.algorithm.oid {
+            RSA_ENCRYPTION_OID => Self::rsa(spki.subject_public_key),
+            EC_PUBLIC_KEY_OID => {
+                Self::ec(spki.algorithm.parameters_oid()?, spki.subject_public_key)
+            }
+            oid => Self::okp(CurveAlgorithm::try_from(oid)?, spki.subject_public_key),
+        }
+    }
+}
+
+impl TryFrom<&SubjectPublicKeyInfo<'_>> for Jwk {
+    type Error = Error;
+
+    fn try_from(spki: &SubjectPublicKeyInfo<'_>) -> Result<Jwk> {
+        Jwk::try_from(*spki)
+    }
+}
@@ -43,7 +43,22 @@
 extern crate alloc;
 
 mod algorithm;
+mod curve;
+mod digest;
+mod rsa;
 mod spki;
 
-pub use crate::{algorithm::AlgorithmIdentifier, spki::SubjectPublicKeyInfo};
+#[cfg(feature = "jwk")]
+mod jwk;
+
+pub use crate::{
+    algorithm::{AlgorithmIdentifier, AlgorithmIdentifierIter},
+    curve::CurveAlgorithm,
+    digest::DigestAlgorithm,
+    rsa::RsaAlgorithm,
+    spki::SubjectPublicKeyInfo,
+};
 pub use der::{self, asn1::ObjectIdentifier};
+
+#[cfg(feature = "jwk")]
+pub use crate::jwk::Jwk;
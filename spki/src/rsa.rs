@@ -0,0 +1,127 @@
+//! RSA algorithm identifiers.
+
+use crate::AlgorithmIdentifier;
+use core::convert::TryFrom;
+use der::{
+    asn1::{Any, Null, ObjectIdentifier},
+    Error, ErrorKind, Result, Tag,
+};
+
+/// RSA public key algorithms identified by an [`AlgorithmIdentifier`].
+///
+/// Keys using either algorithm share the same PKCS#1 `RSAPublicKey` key
+/// format, but keys identified as `id-RSASSA-PSS` are restricted to
+/// RSASSA-PSS signatures as described in [RFC 4055 Section 1.2], and must
+/// not be treated as plain `rsaEncryption` keys.
+///
+/// [RFC 4055 Section 1.2]: https://datatracker.ietf.org/doc/html/rfc4055#section-1.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RsaAlgorithm<'a> {
+    /// RSA (`rsaEncryption`), whose parameters MUST be `NULL`.
+    RsaEncryption,
+
+    /// RSASSA-PSS (`id-RSASSA-PSS`).
+    ///
+    /// The `RSASSA-PSS-params` restricting the key's use are absent when the
+    /// key may be used with any RSASSA-PSS parameters, and otherwise a
+    /// `SEQUENCE` as described in [RFC 4055 Section 3.1].
+    ///
+    /// [RFC 4055 Section 3.1]: https://datatracker.ietf.org/doc/html/rfc4055#section-3.1
+    RsassaPss {
+        /// `RSASSA-PSS-params` for this key (if present).
+        parameters: Option<Any<'a>>,
+    },
+}
+
+impl<'a> RsaAlgorithm<'a> {
+    /// Get the [`ObjectIdentifier`] for this RSA algorithm.
+    pub fn oid(&self) -> ObjectIdentifier {
+        match self {
+            RsaAlgorithm::RsaEncryption => RSA_ENCRYPTION_OID,
+            RsaAlgorithm::RsassaPss { .. } => RSASSA_PSS_OID,
+        }
+    }
+
+    /// Is this an `id-RSASSA-PSS` key?
+    pub fn is_pss(&self) -> bool {
+        matches!(self, RsaAlgorithm::RsassaPss { .. })
+    }
+
+    /// Get the `RSASSA-PSS-params` of an `id-RSASSA-PSS` key, if present.
+    pub fn pss_parameters(&self) -> Option<Any<'a>> {
+        match self {
+            RsaAlgorithm::RsassaPss { parameters } => *parameters,
+            RsaAlgorithm::RsaEncryption => None,
+        }
+    }
+
+    /// Get the [`AlgorithmIdentifier`] for this RSA algorithm.
+    pub fn algorithm_identifier(&self) -> AlgorithmIdentifier<'a> {
+        let parameters = match self {
+            RsaAlgorithm::RsaEncryption => Some(Null::new().into()),
+            RsaAlgorithm::RsassaPss { parameters } => *parameters,
+        };
+
+        AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters,
+        }
+    }
+}
+
+impl<'a> TryFrom<&AlgorithmIdentifier<'a>> for RsaAlgorithm<'a> {
+    type Error = Error;
+
+    fn try_from(alg: &AlgorithmIdentifier<'a>) -> Result<Self> {
+        match alg.oid {
+            RSA_ENCRYPTION_OID => {
+                Null::try_from(alg.parameters_any()?)?;
+                Ok(RsaAlgorithm::RsaEncryption)
+            }
+            RSASSA_PSS_OID => {
+                if let Some(parameters) = alg.parameters {
+                    parameters.tag().assert_eq(Tag::Sequence)?;
+                }
+
+                Ok(RsaAlgorithm::RsassaPss {
+                    parameters: alg.parameters,
+                })
+            }
+            oid => Err(ErrorKind::UnknownOid { oid }.into()),
+        }
+    }
+}
+
+impl<'a> TryFrom<AlgorithmIdentifier<'a>> for RsaAlgorithm<'a> {
+    type Error = Error;
+
+    fn try_from(alg: AlgorithmIdentifier<'a>) -> Result<Self> {
+        Self::try_from(&alg)
+    }
+}
+
+impl<'a> From<RsaAlgorithm<'a>> for AlgorithmIdentifier<'a> {
+    fn from(alg: RsaAlgorithm<'a>) -> AlgorithmIdentifier<'a> {
+        alg.algorithm_identifier()
+    }
+}
+
+/// Is the given [`AlgorithmIdentifier`] for `rsaEncryption`, with either
+/// `NULL` or absent `parameters`?
+///
+/// [RFC 3279 Section 2.3.1] requires the `NULL`, but some encoders omit it.
+///
+/// [RFC 3279 Section 2.3.1]: https://datatracker.ietf.org/doc/html/rfc3279#section-2.3.1
+pub(crate) fn is_rsa_encryption_lenient(alg: &AlgorithmIdentifier<'_>) -> bool {
+    match alg.parameters {
+        Some(params) => alg.oid == RSA_ENCRYPTION_OID && Null::try_from(params).is_ok(),
+        None => alg.oid == RSA_ENCRYPTION_OID,
+    }
+}
+
+/// OID for RSA (`rsaEncryption`).
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
+/// OID for RSASSA-PSS (`id-RSASSA-PSS`).
+const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.10");
@@ -1,12 +1,18 @@
 //! X.509 `SubjectPublicKeyInfo`
 
-use crate::AlgorithmIdentifier;
+use crate::{rsa, AlgorithmIdentifier, CurveAlgorithm, ObjectIdentifier, RsaAlgorithm};
 use core::convert::TryFrom;
-use der::{asn1::BitString, Decodable, Decoder, Encodable, Error, Message, Result};
+use der::{
+    asn1::{Any, BitString},
+    Decodable, Decoder, Encodable, Error, Message, Result, Tag,
+};
 
-#[cfg(feature = "alloc")]
+#[cfg(all(feature = "fingerprint", feature = "alloc"))]
 use alloc::string::String;
 
+#[cfg(feature = "jwk")]
+use crate::Jwk;
+
 #[cfg(feature = "fingerprint")]
 use sha2::{digest, Digest, Sha256};
 
@@ -35,6 +41,63 @@ pub struct SubjectPublicKeyInfo<'a> {
 }
 
 impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Get the [`AlgorithmIdentifier`] for this public key.
+    pub fn algorithm(&self) -> &AlgorithmIdentifier<'a> {
+        &self.algorithm
+    }
+
+    /// Get the algorithm [`ObjectIdentifier`] for this public key.
+    pub fn algorithm_oid(&self) -> ObjectIdentifier {
+        self.algorithm.oid
+    }
+
+    /// Get the [`RsaAlgorithm`] for this public key, distinguishing plain
+    /// `rsaEncryption` keys from `id-RSASSA-PSS` keys.
+    ///
+    /// Returns an error if this isn't an RSA public key.
+    pub fn rsa_algorithm(&self) -> Result<RsaAlgorithm<'a>> {
+        RsaAlgorithm::try_from(&self.algorithm)
+    }
+
+    /// Compare this public key with another, treating `rsaEncryption` keys
+    /// whose `parameters` are an explicit `NULL` as equal to those whose
+    /// `parameters` are absent.
+    ///
+    /// This avoids spuriously reporting a key as changed when comparing the
+    /// same RSA key from different producers. The [`PartialEq`] impl for
+    /// this type is byte-exact and distinguishes the two forms.
+    pub fn matches(&self, other: &Self) -> bool {
+        self.subject_public_key == other.subject_public_key
+            && (self.algorithm == other.algorithm
+                || (rsa::is_rsa_encryption_lenient(&self.algorithm)
+                    && rsa::is_rsa_encryption_lenient(&other.algorithm)))
+    }
+
+    /// Get the raw public key bytes, i.e. the contents of the
+    /// `subjectPublicKey` BIT STRING.
+    ///
+    /// Key material must consist of whole octets, so BIT STRINGs with
+    /// non-zero unused bits are rejected with [`der::ErrorKind::Noncanonical`]
+    /// when decoding a [`SubjectPublicKeyInfo`]. This additionally returns
+    /// [`der::ErrorKind::Length`] if the public key is empty, or for the
+    /// [RFC 8410] algorithms if it isn't of the expected length (32 bytes for
+    /// X25519 and Ed25519, 56 for X448 and 57 for Ed448).
+    ///
+    /// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-4
+    pub fn raw_public_key(&self) -> Result<&'a [u8]> {
+        let key_len = CurveAlgorithm::try_from(self.algorithm.oid)
+            .ok()
+            .map(CurveAlgorithm::key_len);
+
+        match key_len {
+            Some(key_len) if self.subject_public_key.len() != key_len => {
+                Err(Tag::BitString.length_error())
+            }
+            _ if self.subject_public_key.is_empty() => Err(Tag::BitString.length_error()),
+            _ => Ok(self.subject_public_key),
+        }
+    }
+
     #[cfg(feature = "fingerprint")]
     #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
     /// Calculate the SHA-256 fingerprint of this SubjectPublicKeyInfo
@@ -49,6 +112,17 @@ impl<'a> SubjectPublicKeyInfo<'a> {
     pub fn fingerprint_base64(&self) -> Result<String> {
         Ok(Base64::encode_string(self.fingerprint()?.as_slice()))
     }
+
+    /// Convert this public key to a JSON Web Key (JWK).
+    ///
+    /// Supports RSA, elliptic curve (P-256, P-384, P-521 and secp256k1) and
+    /// X25519, X448, Ed25519 and Ed448 keys. Returns [`der::ErrorKind::UnknownOid`] for other
+    /// algorithms.
+    #[cfg(feature = "jwk")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+    pub fn to_jwk(&self) -> Result<Jwk> {
+        Jwk::try_from(self)
+    }
 }
 
 impl<'a> Decodable<'a> for SubjectPublicKeyInfo<'a> {
@@ -71,6 +145,43 @@ impl<'a> Message<'a> for SubjectPublicKeyInfo<'a> {
     }
 }
 
+/// Construct a [`SubjectPublicKeyInfo`] from its algorithm OID, algorithm
+/// parameters and raw public key bytes.
+///
+/// The key bytes form the contents of a `subjectPublicKey` BIT STRING with
+/// no unused bits, and must be non-empty.
+impl<'a> TryFrom<(ObjectIdentifier, Option<Any<'a>>, &'a [u8])> for SubjectPublicKeyInfo<'a> {
+    type Error = Error;
+
+    fn try_from(
+        (oid, parameters, subject_public_key): (ObjectIdentifier, Option<Any<'a>>, &'a [u8]),
+    ) -> Result<Self> {
+        let spki = Self {
+            algorithm: AlgorithmIdentifier { oid, parameters },
+            subject_public_key,
+        };
+
+        BitString::new(spki.raw_public_key()?)?;
+        Ok(spki)
+    }
+}
+
+/// Decompose a [`SubjectPublicKeyInfo`] into its algorithm OID, algorithm
+/// parameters and raw public key bytes (see
+/// [`SubjectPublicKeyInfo::raw_public_key`]).
+impl<'a> TryFrom<SubjectPublicKeyInfo<'a>> for (ObjectIdentifier, Option<Any<'a>>, &'a [u8]) {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfo<'a>) -> Result<Self> {
+        let raw_public_key = spki.raw_public_key()?;
+        Ok((
+            spki.algorithm.oid,
+            spki.algorithm.parameters,
+            raw_public_key,
+        ))
+    }
+}
+
 impl<'a> TryFrom<&'a [u8]> for SubjectPublicKeyInfo<'a> {
     type Error = Error;
 
@@ -0,0 +1,215 @@
+//! AlgorithmIdentifier tests
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use spki::{
+    der::{Decoder, Encodable, ErrorKind, Tag},
+    AlgorithmIdentifier,
+};
+
+/// `AlgorithmIdentifier` for `id-ecPublicKey` whose `parameters` are an
+/// empty `SEQUENCE`.
+const EMPTY_SEQUENCE_PARAMS: &[u8] = &hex!("300B06072A8648CE3D02013000");
+
+/// `AlgorithmIdentifier` for `id-ecPublicKey` whose `parameters` are a nested
+/// `SEQUENCE` (truncated explicit curve parameters: a version and a
+/// `fieldID` containing the `prime-field` OID and a prime).
+const NESTED_SEQUENCE_PARAMS: &[u8] =
+    &hex!("301C06072A8648CE3D02013011020101300C06072A8648CE3D0101020107");
+
+#[test]
+fn empty_sequence_parameters_round_trip() {
+    let alg_id = AlgorithmIdentifier::try_from(EMPTY_SEQUENCE_PARAMS).unwrap();
+    let params = alg_id.parameters_any().unwrap();
+    assert_eq!(params.tag(), Tag::Sequence);
+    assert!(params.value().is_empty());
+
+    let mut buf = [0u8; 32];
+    assert_eq!(
+        alg_id.encode_to_slice(&mut buf).unwrap(),
+        EMPTY_SEQUENCE_PARAMS
+    );
+}
+
+#[test]
+fn nested_sequence_parameters_round_trip() {
+    let alg_id = AlgorithmIdentifier::try_from(NESTED_SEQUENCE_PARAMS).unwrap();
+    let params = alg_id.parameters_any().unwrap();
+    assert_eq!(params.tag(), Tag::Sequence);
+    assert_eq!(params.value(), &NESTED_SEQUENCE_PARAMS[13..]);
+
+    let mut buf = [0u8; 64];
+    assert_eq!(
+        alg_id.encode_to_slice(&mut buf).unwrap(),
+        NESTED_SEQUENCE_PARAMS
+    );
+}
+
+/// `AlgorithmIdentifier` for `id-Ed25519` with absent `parameters`.
+const ED25519_ABSENT_PARAMS: &[u8] = &hex!("300506032B6570");
+
+/// `AlgorithmIdentifier` for `id-Ed25519` with (invalid) `NULL` parameters.
+const ED25519_NULL_PARAMS: &[u8] = &hex!("300706032B65700500");
+
+#[test]
+fn ed25519_absent_parameters() {
+    for decoder in &mut [
+        Decoder::new(ED25519_ABSENT_PARAMS),
+        Decoder::new_strict(ED25519_ABSENT_PARAMS),
+    ] {
+        let alg_id = decoder.decode::<AlgorithmIdentifier<'_>>().unwrap();
+        assert_eq!(alg_id.oid, "1.3.101.112".parse().unwrap());
+        assert!(alg_id.parameters.is_none());
+    }
+}
+
+#[test]
+fn ed25519_null_parameters_normalized() {
+    let alg_id = AlgorithmIdentifier::try_from(ED25519_NULL_PARAMS).unwrap();
+    assert!(alg_id.parameters.is_none());
+
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        alg_id.encode_to_slice(&mut buf).unwrap(),
+        ED25519_ABSENT_PARAMS
+    );
+}
+
+#[test]
+fn ed25519_null_parameters_rejected_when_strict() {
+    let err = Decoder::new_strict(ED25519_NULL_PARAMS)
+        .decode::<AlgorithmIdentifier<'_>>()
+        .unwrap_err();
+
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: None,
+            actual: Tag::Null
+        }
+    );
+}
+
+/// PBES2 `AlgorithmIdentifier` using PBKDF2 with HMAC-SHA256 and AES-256-CBC.
+const PBES2_PBKDF2_SHA256_AES256CBC: &[u8] = &hex!(
+    "305706092A864886F70D01050D304A302906092A864886F70D01050C301C040879D982E70DF91A8802020800"
+    "300C06082A864886F70D02090500301D060960864801650304012A0410B2D02D78B2EFD9DFF694CF8E0AF40925"
+);
+
+#[test]
+fn chain_pbes2() {
+    let alg_id = AlgorithmIdentifier::try_from(PBES2_PBKDF2_SHA256_AES256CBC).unwrap();
+    let oids = alg_id
+        .chain()
+        .map(|alg| alg.unwrap().oid.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        oids,
+        [
+            "1.2.840.113549.1.5.13",   // PBES2
+            "1.2.840.113549.1.5.12",   // PBKDF2
+            "1.2.840.113549.2.9",      // HMAC-SHA256
+            "2.16.840.1.101.3.4.1.42", // AES-256-CBC
+        ]
+    );
+
+    let cipher = alg_id.chain().last().unwrap().unwrap();
+    assert_eq!(cipher.parameters.unwrap().tag(), Tag::OctetString);
+}
+
+#[test]
+fn chain_rsassa_pss() {
+    // Explicitly tagged `hashAlgorithm` and `maskGenAlgorithm` with nested MGF1 hash
+    let spki = spki::SubjectPublicKeyInfo::try_from(
+        &include_bytes!("examples/rsa2048-pss-sha256-pub.der")[..],
+    )
+    .unwrap();
+    let oids = spki
+        .algorithm
+        .chain()
+        .map(|alg| alg.unwrap().oid.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        oids,
+        [
+            "1.2.840.113549.1.1.10",  // RSASSA-PSS
+            "2.16.840.1.101.3.4.2.1", // SHA-256
+            "1.2.840.113549.1.1.8",   // MGF1
+            "2.16.840.1.101.3.4.2.1", // SHA-256
+        ]
+    );
+}
+
+#[test]
+fn chain_only_searches_known_parameters() {
+    let alg_id = AlgorithmIdentifier::try_from(EMPTY_SEQUENCE_PARAMS).unwrap();
+    assert_eq!(alg_id.chain().collect::<Vec<_>>(), [Ok(alg_id)]);
+
+    // The `fieldID` in the explicit curve parameters is shaped like an
+    // `AlgorithmIdentifier`, but isn't one
+    let alg_id = AlgorithmIdentifier::try_from(NESTED_SEQUENCE_PARAMS).unwrap();
+    assert_eq!(alg_id.chain().collect::<Vec<_>>(), [Ok(alg_id)]);
+}
+
+#[test]
+fn chain_malformed_parameters() {
+    // PBES2 with an `OCTET STRING` in place of its `parameters`
+    let alg_id =
+        AlgorithmIdentifier::try_from(&hex!("300F06092A864886F70D01050D0402AAAA")[..]).unwrap();
+    let mut chain = alg_id.chain();
+
+    assert_eq!(
+        chain.next().unwrap().unwrap_err().kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::Sequence),
+            actual: Tag::OctetString
+        }
+    );
+    assert!(chain.next().is_none());
+}
+
+/// Encode an MGF1 `AlgorithmIdentifier` whose hash is nested `depth` levels
+/// deep within further MGF1 algorithms.
+fn nested_mgf1(depth: usize) -> Vec<u8> {
+    // SHA-256
+    let mut der = Vec::from(&hex!("300D06096086480165030402010500")[..]);
+
+    for _ in 0..depth {
+        let len = der.len() + 11;
+        let mut outer = if len < 0x80 {
+            vec![0x30, len as u8]
+        } else {
+            vec![0x30, 0x81, len as u8]
+        };
+
+        outer.extend_from_slice(&hex!("06092A864886F70D010108"));
+        outer.extend_from_slice(&der);
+        der = outer;
+    }
+
+    der
+}
+
+#[test]
+fn chain_nesting_limit() {
+    let der = nested_mgf1(8);
+    let alg_id = AlgorithmIdentifier::try_from(der.as_slice()).unwrap();
+    let chain = alg_id.chain().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(chain.len(), 9);
+
+    let der = nested_mgf1(9);
+    let alg_id = AlgorithmIdentifier::try_from(der.as_slice()).unwrap();
+    let mut chain = alg_id.chain();
+
+    for _ in 0..8 {
+        assert!(chain.next().unwrap().is_ok());
+    }
+
+    assert_eq!(
+        chain.next().unwrap().unwrap_err().kind(),
+        ErrorKind::Overlength
+    );
+    assert!(chain.next().is_none());
+}
@@ -0,0 +1,72 @@
+//! CurveAlgorithm tests
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use spki::{
+    der::{asn1::Any, Encodable, ErrorKind, Tag},
+    AlgorithmIdentifier, CurveAlgorithm, ObjectIdentifier,
+};
+
+/// RFC 8410 algorithms along with their `AlgorithmIdentifier` encoded as DER,
+/// name and key length.
+const CURVE_ALGORITHMS: &[(CurveAlgorithm, &[u8], &str, usize)] = &[
+    (
+        CurveAlgorithm::X25519,
+        &hex!("300506032B656E"),
+        "X25519",
+        32,
+    ),
+    (CurveAlgorithm::X448, &hex!("300506032B656F"), "X448", 56),
+    (
+        CurveAlgorithm::Ed25519,
+        &hex!("300506032B6570"),
+        "Ed25519",
+        32,
+    ),
+    (CurveAlgorithm::Ed448, &hex!("300506032B6571"), "Ed448", 57),
+];
+
+#[test]
+fn round_trip() {
+    for &(curve, der, name, key_len) in CURVE_ALGORITHMS {
+        let mut buf = [0u8; 16];
+        let alg = curve.algorithm_identifier();
+        assert_eq!(alg.encode_to_slice(&mut buf).unwrap(), der);
+
+        let decoded = AlgorithmIdentifier::try_from(der).unwrap();
+        assert_eq!(decoded, alg);
+        assert_eq!(CurveAlgorithm::try_from(&decoded).unwrap(), curve);
+        assert_eq!(CurveAlgorithm::try_from(curve.oid()).unwrap(), curve);
+        assert_eq!(curve.name(), name);
+        assert_eq!(curve.key_len(), key_len);
+    }
+}
+
+#[test]
+fn invalid_parameters() {
+    let alg = AlgorithmIdentifier {
+        oid: CurveAlgorithm::Ed25519.oid(),
+        parameters: Some(Any::new(Tag::Null, &[]).unwrap()),
+    };
+
+    let err = CurveAlgorithm::try_from(&alg).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: None,
+            actual: Tag::Null
+        }
+    );
+}
+
+#[test]
+fn unknown_oid() {
+    let oid = ObjectIdentifier::new("1.2.840.10045.2.1");
+    let alg = AlgorithmIdentifier {
+        oid,
+        parameters: None,
+    };
+
+    let err = CurveAlgorithm::try_from(&alg).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownOid { oid });
+}
@@ -0,0 +1,106 @@
+//! DigestAlgorithm tests
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use spki::{
+    der::{asn1::Any, Encodable, ErrorKind, Tag},
+    AlgorithmIdentifier, DigestAlgorithm, ObjectIdentifier,
+};
+
+/// Digest algorithms along with their `AlgorithmIdentifier` encoded as DER.
+const DIGEST_ALGORITHMS: &[(DigestAlgorithm, &[u8])] = &[
+    (DigestAlgorithm::Sha1, &hex!("300906052B0E03021A0500")),
+    (
+        DigestAlgorithm::Sha224,
+        &hex!("300D06096086480165030402040500"),
+    ),
+    (
+        DigestAlgorithm::Sha256,
+        &hex!("300D06096086480165030402010500"),
+    ),
+    (
+        DigestAlgorithm::Sha384,
+        &hex!("300D06096086480165030402020500"),
+    ),
+    (
+        DigestAlgorithm::Sha512,
+        &hex!("300D06096086480165030402030500"),
+    ),
+    (
+        DigestAlgorithm::Sha512_224,
+        &hex!("300D06096086480165030402050500"),
+    ),
+    (
+        DigestAlgorithm::Sha512_256,
+        &hex!("300D06096086480165030402060500"),
+    ),
+    (
+        DigestAlgorithm::Sha3_224,
+        &hex!("300B0609608648016503040207"),
+    ),
+    (
+        DigestAlgorithm::Sha3_256,
+        &hex!("300B0609608648016503040208"),
+    ),
+    (
+        DigestAlgorithm::Sha3_384,
+        &hex!("300B0609608648016503040209"),
+    ),
+    (
+        DigestAlgorithm::Sha3_512,
+        &hex!("300B060960864801650304020A"),
+    ),
+];
+
+#[test]
+fn round_trip() {
+    for &(digest, der) in DIGEST_ALGORITHMS {
+        let mut buf = [0u8; 16];
+        let alg = digest.algorithm_identifier();
+        assert_eq!(alg.encode_to_slice(&mut buf).unwrap(), der);
+
+        let decoded = AlgorithmIdentifier::try_from(der).unwrap();
+        assert_eq!(decoded, alg);
+        assert_eq!(DigestAlgorithm::try_from(&decoded).unwrap(), digest);
+        assert_eq!(DigestAlgorithm::try_from(digest.oid()).unwrap(), digest);
+    }
+}
+
+#[test]
+fn absent_parameters() {
+    // SHA-256 with absent rather than `NULL` parameters
+    let alg = AlgorithmIdentifier::try_from(&hex!("300B0609608648016503040201")[..]).unwrap();
+    assert_eq!(
+        DigestAlgorithm::try_from(&alg).unwrap(),
+        DigestAlgorithm::Sha256
+    );
+}
+
+#[test]
+fn invalid_parameters() {
+    let alg = AlgorithmIdentifier {
+        oid: DigestAlgorithm::Sha256.oid(),
+        parameters: Some(Any::new(Tag::OctetString, &[]).unwrap()),
+    };
+
+    let err = DigestAlgorithm::try_from(&alg).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnexpectedTag {
+            expected: Some(Tag::Null),
+            actual: Tag::OctetString
+        }
+    );
+}
+
+#[test]
+fn unknown_oid() {
+    let oid = ObjectIdentifier::new("1.2.840.113549.2.5");
+    let alg = AlgorithmIdentifier {
+        oid,
+        parameters: None,
+    };
+
+    let err = DigestAlgorithm::try_from(&alg).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownOid { oid });
+}
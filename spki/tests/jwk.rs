@@ -0,0 +1,131 @@
+//! JSON Web Key (JWK) tests
+
+#![cfg(feature = "jwk")]
+
+use core::convert::TryFrom;
+use hex_literal::hex;
+use serde_json::json;
+use spki::{der::ErrorKind, Jwk, SubjectPublicKeyInfo};
+
+/// RSA-2048 public key from RFC 7517 Appendix A.1, encoded as ASN.1 DER
+const RSA_2048_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-rfc7517-pub.der");
+
+/// P-256 public key from RFC 7517 Appendix A.1, encoded as ASN.1 DER
+const EC_P256_DER_EXAMPLE: &[u8] = &hex!(
+    "3059301306072a8648ce3d020106082a8648ce3d03010703420004"
+    "30a0424cd21c2944838a2d75c92b37e76ea20d9f00893a3b4eee8a3c0aafec3e"
+    "e04b65e92456d9888b52b379bdfbd51ee869ef1f0fc65b6659695b6cce081723"
+);
+
+/// Ed25519 public key from RFC 8037 Appendix A.2, encoded as ASN.1 DER
+const ED25519_DER_EXAMPLE: &[u8] = &hex!(
+    "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+);
+
+/// X25519 public key from RFC 8037 Appendix A.6, encoded as ASN.1 DER
+const X25519_DER_EXAMPLE: &[u8] = &hex!(
+    "302a300506032b656e0321008520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+);
+
+fn to_json(der: &[u8]) -> serde_json::Value {
+    let spki = SubjectPublicKeyInfo::try_from(der).unwrap();
+    serde_json::to_value(spki.to_jwk().unwrap()).unwrap()
+}
+
+#[test]
+fn rsa_to_jwk() {
+    assert_eq!(
+        to_json(RSA_2048_DER_EXAMPLE),
+        json!({
+            "kty": "RSA",
+            "n": "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw",
+            "e": "AQAB"
+        })
+    );
+}
+
+#[test]
+fn ec_p256_to_jwk() {
+    assert_eq!(
+        to_json(EC_P256_DER_EXAMPLE),
+        json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "x": "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4",
+            "y": "4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM"
+        })
+    );
+}
+
+#[test]
+fn ed25519_to_jwk() {
+    assert_eq!(
+        to_json(ED25519_DER_EXAMPLE),
+        json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        })
+    );
+}
+
+#[test]
+fn x25519_to_jwk() {
+    assert_eq!(
+        to_json(X25519_DER_EXAMPLE),
+        json!({
+            "kty": "OKP",
+            "crv": "X25519",
+            "x": "hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo"
+        })
+    );
+}
+
+#[test]
+fn jwk_round_trip() {
+    let spki = SubjectPublicKeyInfo::try_from(EC_P256_DER_EXAMPLE).unwrap();
+    let jwk = spki.to_jwk().unwrap();
+    let json = serde_json::to_string(&jwk).unwrap();
+    assert_eq!(serde_json::from_str::<Jwk>(&json).unwrap(), jwk);
+}
+
+#[test]
+fn ed448_to_jwk() {
+    let der = hex!(
+        "3043300506032b6571033a00"
+        "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778"
+        "edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180"
+    );
+    assert_eq!(
+        to_json(&der),
+        json!({
+            "kty": "OKP",
+            "crv": "Ed448",
+            "x": "X9dEm1m0Yf0s54fsYWrUah2hNCSFpw4fig6nXYDpZ3jt8SR2m0bHBhvWeD3x5Q9s0foavq_oJWGA"
+        })
+    );
+}
+
+#[test]
+fn unsupported_algorithm() {
+    // DSA (`id-dsa`) public key without domain parameters
+    let der = hex!("300F300906072A8648CE38040103020002");
+    let spki = SubjectPublicKeyInfo::try_from(&der[..]).unwrap();
+    let err = spki.to_jwk().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::UnknownOid {
+            oid: "1.2.840.10040.4.1".parse().unwrap()
+        }
+    );
+}
+
+#[test]
+fn compressed_ec_point() {
+    let mut der = EC_P256_DER_EXAMPLE[..59].to_vec();
+    der[1] -= 32;
+    der[24] -= 32;
+    der[26] = 0x02;
+    let spki = SubjectPublicKeyInfo::try_from(der.as_slice()).unwrap();
+    assert!(spki.to_jwk().is_err());
+}
@@ -0,0 +1,132 @@
+//! RSA algorithm identifier tests
+
+use core::convert::TryFrom;
+use spki::{
+    der::{asn1::Any, Encodable, ErrorKind, Tag},
+    AlgorithmIdentifier, ObjectIdentifier, RsaAlgorithm, SubjectPublicKeyInfo,
+};
+
+/// RSA-2048 `rsaEncryption` `SubjectPublicKeyInfo` encoded as ASN.1 DER.
+const RSA_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-rfc7517-pub.der");
+
+/// RSA-2048 `id-RSASSA-PSS` `SubjectPublicKeyInfo` with SHA-256 parameters
+/// encoded as ASN.1 DER.
+///
+/// ```text
+/// $ openssl genpkey -algorithm RSA-PSS -pkeyopt rsa_keygen_bits:2048 -pkeyopt rsa_pss_keygen_md:sha256 -pkeyopt rsa_pss_keygen_mgf1_md:sha256 -pkeyopt rsa_pss_keygen_saltlen:32 -out rsa2048-pss-sha256-priv.pem
+/// $ openssl pkey -in rsa2048-pss-sha256-priv.pem -pubout -outform der -out rsa2048-pss-sha256-pub.der
+/// ```
+const RSA_PSS_DER_EXAMPLE: &[u8] = include_bytes!("examples/rsa2048-pss-sha256-pub.der");
+
+#[test]
+fn rsa_encryption_spki() {
+    let spki = SubjectPublicKeyInfo::try_from(RSA_DER_EXAMPLE).unwrap();
+    let alg = spki.rsa_algorithm().unwrap();
+    assert_eq!(alg, RsaAlgorithm::RsaEncryption);
+    assert_eq!(alg.oid(), "1.2.840.113549.1.1.1".parse().unwrap());
+    assert!(!alg.is_pss());
+    assert!(alg.pss_parameters().is_none());
+    assert_eq!(alg.algorithm_identifier(), spki.algorithm);
+}
+
+#[test]
+fn rsassa_pss_spki_with_parameters() {
+    let spki = SubjectPublicKeyInfo::try_from(RSA_PSS_DER_EXAMPLE).unwrap();
+    let alg = spki.rsa_algorithm().unwrap();
+    assert!(alg.is_pss());
+    assert_ne!(alg, RsaAlgorithm::RsaEncryption);
+    assert_eq!(alg.oid(), "1.2.840.113549.1.1.10".parse().unwrap());
+
+    let params = alg.pss_parameters().unwrap();
+    assert_eq!(params.tag(), Tag::Sequence);
+    assert_eq!(params.value(), &RSA_PSS_DER_EXAMPLE[19..71]);
+
+    let mut buf = [0u8; 512];
+    let alg_id = AlgorithmIdentifier::from(alg);
+    assert_eq!(
+        alg_id.encode_to_slice(&mut buf).unwrap(),
+        &RSA_PSS_DER_EXAMPLE[4..71]
+    );
+}
+
+#[test]
+fn rsassa_pss_without_parameters() {
+    let alg_id = AlgorithmIdentifier {
+        oid: "1.2.840.113549.1.1.10".parse().unwrap(),
+        parameters: None,
+    };
+
+    let alg = RsaAlgorithm::try_from(alg_id).unwrap();
+    assert_eq!(alg, RsaAlgorithm::RsassaPss { parameters: None });
+    assert!(alg.pss_parameters().is_none());
+}
+
+#[test]
+fn reject_non_rsa_algorithm() {
+    let oid = ObjectIdentifier::new("1.3.101.112");
+    let alg_id = AlgorithmIdentifier {
+        oid,
+        parameters: None,
+    };
+
+    assert_eq!(
+        RsaAlgorithm::try_from(alg_id).unwrap_err().kind(),
+        ErrorKind::UnknownOid { oid }
+    );
+}
+
+#[test]
+fn rsa_encryption_matches_absent_parameters() {
+    let with_null = SubjectPublicKeyInfo::try_from(RSA_DER_EXAMPLE).unwrap();
+    assert_eq!(with_null.algorithm.parameters.unwrap().tag(), Tag::Null);
+
+    // Re-encode the same key with the `NULL` parameters omitted
+    let mut buf = [0u8; 512];
+    let der = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            parameters: None,
+            ..with_null.algorithm
+        },
+        ..with_null
+    }
+    .encode_to_slice(&mut buf)
+    .unwrap();
+    assert_eq!(der.len(), RSA_DER_EXAMPLE.len() - 2);
+
+    let absent = SubjectPublicKeyInfo::try_from(der).unwrap();
+    assert!(absent.algorithm.parameters.is_none());
+
+    assert!(with_null.matches(&absent));
+    assert!(absent.matches(&with_null));
+    assert_ne!(with_null, absent);
+
+    // The same key material under a different algorithm doesn't match
+    let pss = SubjectPublicKeyInfo {
+        algorithm: RsaAlgorithm::RsassaPss { parameters: None }.into(),
+        ..with_null
+    };
+    assert!(!pss.matches(&absent));
+    assert!(!pss.matches(&with_null));
+
+    // Nor does a different key under the same algorithm
+    let other = SubjectPublicKeyInfo {
+        subject_public_key: &with_null.subject_public_key[1..],
+        ..absent
+    };
+    assert!(!other.matches(&with_null));
+}
+
+#[test]
+fn rsa_spki_into_tuple() {
+    let spki = SubjectPublicKeyInfo::try_from(RSA_DER_EXAMPLE).unwrap();
+    let (oid, parameters, key) =
+        <(ObjectIdentifier, Option<Any<'_>>, &[u8])>::try_from(spki).unwrap();
+
+    assert_eq!(oid, "1.2.840.113549.1.1.1".parse().unwrap());
+    assert_eq!(parameters.unwrap().tag(), Tag::Null);
+    assert_eq!(key, spki.subject_public_key);
+    assert_eq!(
+        SubjectPublicKeyInfo::try_from((oid, parameters, key)).unwrap(),
+        spki
+    );
+}
@@ -1,16 +1,149 @@
 //! SubjectPublicKeyInfo tests
-#[cfg(feature = "fingerprint")]
 use core::convert::TryFrom;
-#[cfg(feature = "fingerprint")]
 use hex_literal::hex;
-#[cfg(feature = "fingerprint")]
-use spki::SubjectPublicKeyInfo;
+use spki::{
+    der::{Encodable, ErrorKind, Tag},
+    AlgorithmIdentifier, ObjectIdentifier, SubjectPublicKeyInfo,
+};
 
-#[cfg(feature = "fingerprint")]
 // Taken from pkcs8/tests/public_key.rs
 /// Ed25519 `SubjectPublicKeyInfo` encoded as ASN.1 DER
 const ED25519_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed25519-pub.der");
 
+/// Ed448 `SubjectPublicKeyInfo` generated by OpenSSL, encoded as ASN.1 DER
+const ED448_DER_EXAMPLE: &[u8] = include_bytes!("examples/ed448-pub.der");
+
+/// X448 `SubjectPublicKeyInfo` generated by OpenSSL, encoded as ASN.1 DER
+const X448_DER_EXAMPLE: &[u8] = include_bytes!("examples/x448-pub.der");
+
+/// Ed25519 `SubjectPublicKeyInfo` with a non-zero number of unused bits in
+/// the `subjectPublicKey` BIT STRING
+const ED25519_DER_UNUSED_BITS: &[u8] = &hex!(
+    "302A300506032B65700321044D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D"
+);
+
+#[test]
+fn raw_public_key() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(
+        spki.raw_public_key().unwrap(),
+        &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D")[..]
+    );
+
+    let empty = SubjectPublicKeyInfo {
+        subject_public_key: &[],
+        ..spki
+    };
+    assert_eq!(
+        empty.raw_public_key().unwrap_err().kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+}
+
+#[test]
+fn raw_public_key_rfc8410_lengths() {
+    let ed448 = SubjectPublicKeyInfo::try_from(ED448_DER_EXAMPLE).unwrap();
+    assert_eq!(ed448.algorithm.oid, ObjectIdentifier::new("1.3.101.113"));
+    assert_eq!(ed448.raw_public_key().unwrap().len(), 57);
+
+    let x448 = SubjectPublicKeyInfo::try_from(X448_DER_EXAMPLE).unwrap();
+    assert_eq!(x448.algorithm.oid, ObjectIdentifier::new("1.3.101.111"));
+    assert_eq!(x448.raw_public_key().unwrap().len(), 56);
+
+    // Keys of the wrong length for their algorithm are rejected
+    for (oid, key) in &[
+        ("1.3.101.110", x448.subject_public_key),
+        ("1.3.101.111", ed448.subject_public_key),
+        ("1.3.101.112", ed448.subject_public_key),
+        ("1.3.101.113", &ed448.subject_public_key[..32]),
+    ] {
+        let spki = SubjectPublicKeyInfo {
+            algorithm: AlgorithmIdentifier {
+                oid: ObjectIdentifier::new(oid),
+                parameters: None,
+            },
+            subject_public_key: key,
+        };
+
+        assert_eq!(
+            spki.raw_public_key().unwrap_err().kind(),
+            ErrorKind::Length {
+                tag: Tag::BitString
+            }
+        );
+    }
+}
+
+#[test]
+fn raw_public_key_unused_bits() {
+    let err = SubjectPublicKeyInfo::try_from(ED25519_DER_UNUSED_BITS).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Noncanonical {
+            tag: Tag::BitString
+        }
+    );
+}
+
+#[test]
+fn raw_public_key_rfc8410_unused_bits() {
+    for der in &[ED25519_DER_EXAMPLE, ED448_DER_EXAMPLE, X448_DER_EXAMPLE] {
+        // Non-zero unused bits are rejected even when the trailing bits of
+        // the key happen to be zero, rather than yielding a corrupted key
+        for unused_bits in 1..8 {
+            let mut der = der.to_vec();
+            der[11] = unused_bits;
+            *der.last_mut().unwrap() = 0;
+
+            let err = SubjectPublicKeyInfo::try_from(der.as_slice()).unwrap_err();
+            assert_eq!(
+                err.kind(),
+                ErrorKind::Noncanonical {
+                    tag: Tag::BitString
+                }
+            );
+        }
+
+        // Truncated keys are rejected with a distinct error
+        let mut der = der[..der.len() - 1].to_vec();
+        der[1] -= 1;
+        der[10] -= 1;
+
+        let spki = SubjectPublicKeyInfo::try_from(der.as_slice()).unwrap();
+        assert_eq!(
+            spki.raw_public_key().unwrap_err().kind(),
+            ErrorKind::Length {
+                tag: Tag::BitString
+            }
+        );
+    }
+}
+
+#[test]
+fn ed25519_spki_from_tuple() {
+    let oid = ObjectIdentifier::new("1.3.101.112");
+    let key = hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D");
+    let spki = SubjectPublicKeyInfo::try_from((oid, None, &key[..])).unwrap();
+    assert_eq!(
+        spki,
+        SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap()
+    );
+
+    let mut buf = [0u8; 64];
+    assert_eq!(spki.encode_to_slice(&mut buf).unwrap(), ED25519_DER_EXAMPLE);
+
+    // Empty public keys are rejected
+    let err = SubjectPublicKeyInfo::try_from((oid, None, &[][..])).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Length {
+            tag: Tag::BitString
+        }
+    );
+}
+
 /// The SPKI fingerprint for `ED25519_SPKI_FINGERPRINT` as a Base64 string
 ///
 /// Generated using `cat ed25519-pub.der | openssl dgst -binary -sha256 | base64`
@@ -20,7 +153,7 @@ const ED25519_SPKI_FINGERPRINT_BASE64: &str = "Vd1MdLDkhTTi9OFzzs61DfjyenrCqomRz
 /// The SPKI fingerprint for `ED25519_SPKI_FINGERPRINT` as straight hash bytes
 ///
 /// Generated using `cat ed25519-pub.der | openssl dgst -sha256`
-#[cfg(all(feature = "fingerprint"))]
+#[cfg(feature = "fingerprint")]
 const ED25519_SPKI_FINGERPRINT: &[u8] =
     &hex!("55dd4c74b0e48534e2f4e173ceceb50df8f27a7ac2aa8991cc7ae914e030bced");
 
@@ -0,0 +1,130 @@
+//! X.509 [`DirectoryString`]
+
+use core::{convert::TryFrom, str};
+use der::{
+    asn1::{Any, Ia5String, PrintableString, Utf8String},
+    Choice, Decodable, Decoder, Encodable, Encoder, Error, Length, Result, Tag,
+};
+
+/// Borrowed string which may be encoded as any of several ASN.1 string types,
+/// as used in the attribute values of X.509 names.
+///
+/// Based on the `DirectoryString` definition from [RFC 5280 Appendix A]:
+///
+/// ```text
+/// DirectoryString ::= CHOICE {
+///       teletexString           TeletexString (SIZE (1..MAX)),
+///       printableString         PrintableString (SIZE (1..MAX)),
+///       universalString         UniversalString (SIZE (1..MAX)),
+///       utf8String              UTF8String (SIZE (1..MAX)),
+///       bmpString               BMPString (SIZE (1..MAX)) }
+/// ```
+///
+/// This type supports the alternatives which can be borrowed as a `str`,
+/// namely `UTF8String` and `PrintableString`, as well as `IA5String` and
+/// `VisibleString` which are commonly encountered in practice.
+///
+/// Values retain the tag they were decoded from, so they are re-encoded the
+/// same way. Newly created values are encoded as [`Self::DEFAULT_TAG`] unless
+/// another tag is selected with [`DirectoryString::new_with_tag`].
+///
+/// [RFC 5280 Appendix A]: https://tools.ietf.org/html/rfc5280#page-112
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct DirectoryString<'a> {
+    /// ASN.1 string type this value is encoded as.
+    tag: Tag,
+
+    /// Inner value.
+    value: &'a str,
+}
+
+impl<'a> DirectoryString<'a> {
+    /// Tag used when encoding values created with [`DirectoryString::new`].
+    pub const DEFAULT_TAG: Tag = Tag::Utf8String;
+
+    /// Create a new [`DirectoryString`] which is encoded as a `UTF8String`.
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            tag: Self::DEFAULT_TAG,
+            value,
+        }
+    }
+
+    /// Create a new [`DirectoryString`] which is encoded using the given
+    /// [`Tag`].
+    ///
+    /// Returns an error if the tag isn't one of the supported string types,
+    /// or if the value contains characters which aren't allowed by it.
+    pub fn new_with_tag(value: &'a str, tag: Tag) -> Result<Self> {
+        Self::from_bytes(value.as_bytes(), tag)
+    }
+
+    /// Validate the given bytes against the character set of `tag`.
+    fn from_bytes(bytes: &'a [u8], tag: Tag) -> Result<Self> {
+        let value = match tag {
+            Tag::Utf8String => Utf8String::new(bytes)?.as_str(),
+            Tag::PrintableString => PrintableString::new(bytes)?.as_str(),
+            Tag::Ia5String => Ia5String::new(bytes)?.as_str(),
+            Tag::VisibleString => {
+                // Validate all characters are within VisibleString's allowed set
+                if bytes.iter().any(|&c| !(0x20..=0x7E).contains(&c)) {
+                    return Err(tag.value_error());
+                }
+
+                str::from_utf8(bytes).map_err(|_| tag.value_error())?
+            }
+            _ => return Err(tag.unexpected_error(Some(Self::DEFAULT_TAG))),
+        };
+
+        Ok(Self { tag, value })
+    }
+
+    /// Borrow the string as a `str`.
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    /// Get the [`Tag`] this value is encoded as.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+impl AsRef<str> for DirectoryString<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for DirectoryString<'a> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<DirectoryString<'a>> {
+        Self::from_bytes(any.value(), any.tag())
+    }
+}
+
+impl<'a> Choice<'a> for DirectoryString<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(
+            tag,
+            Tag::Utf8String | Tag::PrintableString | Tag::Ia5String | Tag::VisibleString
+        )
+    }
+}
+
+impl<'a> Decodable<'a> for DirectoryString<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        decoder.any().and_then(Self::try_from)
+    }
+}
+
+impl Encodable for DirectoryString<'_> {
+    fn encoded_len(&self) -> Result<Length> {
+        Any::new(self.tag, self.value.as_bytes())?.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Any::new(self.tag, self.value.as_bytes())?.encode(encoder)
+    }
+}
@@ -19,10 +19,14 @@ extern crate alloc;
 extern crate std;
 
 mod attribute;
+mod directory_string;
 mod rdn;
 mod time;
 
-pub use crate::{attribute::AttributeTypeAndValue, rdn::RelativeDistinguishedName, time::Time};
+pub use crate::{
+    attribute::AttributeTypeAndValue, directory_string::DirectoryString,
+    rdn::RelativeDistinguishedName, time::Time,
+};
 pub use der::{self, asn1::ObjectIdentifier};
 pub use spki::{self, AlgorithmIdentifier, SubjectPublicKeyInfo};
 
@@ -0,0 +1,85 @@
+//! `DirectoryString` tests
+
+use x509::{
+    der::{Decodable, Encodable, ErrorKind, Tag},
+    DirectoryString,
+};
+
+#[test]
+fn decode_utf8_string() {
+    let der = b"\x0c\x06caf\xc3\xa9s";
+    let s = DirectoryString::from_der(der).unwrap();
+    assert_eq!(s.as_str(), "cafés");
+    assert_eq!(s.tag(), Tag::Utf8String);
+    assert_eq!(s.encode_to_slice(&mut [0u8; 16]).unwrap(), &der[..]);
+}
+
+#[test]
+fn decode_printable_string() {
+    let der = b"\x13\x0bTest User 1";
+    let s = DirectoryString::from_der(der).unwrap();
+    assert_eq!(s.as_str(), "Test User 1");
+    assert_eq!(s.tag(), Tag::PrintableString);
+    assert_eq!(s.encode_to_slice(&mut [0u8; 16]).unwrap(), &der[..]);
+}
+
+#[test]
+fn decode_ia5_string() {
+    let der = b"\x16\x0dtest1@rsa.com";
+    let s = DirectoryString::from_der(der).unwrap();
+    assert_eq!(s.as_str(), "test1@rsa.com");
+    assert_eq!(s.tag(), Tag::Ia5String);
+    assert_eq!(s.encode_to_slice(&mut [0u8; 16]).unwrap(), &der[..]);
+}
+
+#[test]
+fn decode_visible_string() {
+    let der = b"\x1a\x05Hello";
+    let s = DirectoryString::from_der(der).unwrap();
+    assert_eq!(s.as_str(), "Hello");
+    assert_eq!(s.tag(), Tag::VisibleString);
+    assert_eq!(s.encode_to_slice(&mut [0u8; 16]).unwrap(), &der[..]);
+}
+
+#[test]
+fn reject_invalid_charset() {
+    // `@` is not a valid PrintableString character
+    let err = DirectoryString::from_der(b"\x13\x01@").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::PrintableString
+        }
+    );
+
+    // Control characters are not valid in a VisibleString
+    let err = DirectoryString::from_der(b"\x1a\x01\n").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ErrorKind::Value {
+            tag: Tag::VisibleString
+        }
+    );
+
+    // Unsupported string type
+    assert!(DirectoryString::from_der(b"\x04\x01a").is_err());
+}
+
+#[test]
+fn encode_with_preferred_tag() {
+    let s = DirectoryString::new("Hello");
+    assert_eq!(s.tag(), DirectoryString::DEFAULT_TAG);
+    assert_eq!(
+        s.encode_to_slice(&mut [0u8; 16]).unwrap(),
+        &b"\x0c\x05Hello"[..]
+    );
+
+    let s = DirectoryString::new_with_tag("Hello", Tag::PrintableString).unwrap();
+    assert_eq!(
+        s.encode_to_slice(&mut [0u8; 16]).unwrap(),
+        &b"\x13\x05Hello"[..]
+    );
+
+    assert!(DirectoryString::new_with_tag("cafés", Tag::PrintableString).is_err());
+    assert!(DirectoryString::new_with_tag("Hello", Tag::OctetString).is_err());
+}